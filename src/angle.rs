@@ -0,0 +1,278 @@
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+use crate::fixed::{Fixed, FRACBITS};
+use crate::tables::{FINESINE, FINETANGENT, TANTOANGLE};
+
+pub const FINEANGLES: usize = 8192;
+pub const FINEMASK: usize = FINEANGLES - 1;
+pub const ANGLETOFINESHIFT: u32 = 19;
+
+pub const SLOPERANGE: usize = 2048;
+pub const SLOPEBITS: i32 = 11;
+pub const DBITS: i32 = FRACBITS - SLOPEBITS;
+
+// A binary angle: a whole turn is 2^32, so angles wrap around on their own
+// the way they do in vanilla.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Angle(pub u32);
+
+impl Angle {
+    pub const ANG45: Angle = Angle(0x2000_0000);
+    pub const ANG90: Angle = Angle(0x4000_0000);
+    pub const ANG180: Angle = Angle(0x8000_0000);
+    pub const ANG270: Angle = Angle(0xc000_0000);
+    pub const ANG_MAX: Angle = Angle(u32::MAX);
+
+    pub const fn from_fine(fine: usize) -> Self {
+        Angle((fine as u32) << ANGLETOFINESHIFT)
+    }
+
+    pub const fn fine(self) -> usize {
+        (self.0 >> ANGLETOFINESHIFT) as usize
+    }
+
+    pub fn from_degrees(degrees: f64) -> Self {
+        Angle((degrees / 360.0 * 4_294_967_296.0).rem_euclid(4_294_967_296.0) as u32)
+    }
+
+    pub fn to_degrees(self) -> f64 {
+        self.0 as f64 * 360.0 / 4_294_967_296.0
+    }
+
+    pub fn sin(self) -> Fixed {
+        finesine(self.fine())
+    }
+
+    pub fn cos(self) -> Fixed {
+        finecosine(self.fine())
+    }
+}
+
+impl std::fmt::Display for Angle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_degrees())
+    }
+}
+
+impl Add for Angle {
+    type Output = Angle;
+
+    fn add(self, other: Angle) -> Angle {
+        Angle(self.0.wrapping_add(other.0))
+    }
+}
+
+impl AddAssign for Angle {
+    fn add_assign(&mut self, other: Angle) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Angle {
+    type Output = Angle;
+
+    fn sub(self, other: Angle) -> Angle {
+        Angle(self.0.wrapping_sub(other.0))
+    }
+}
+
+impl SubAssign for Angle {
+    fn sub_assign(&mut self, other: Angle) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Angle {
+    type Output = Angle;
+
+    fn neg(self) -> Angle {
+        Angle(self.0.wrapping_neg())
+    }
+}
+
+impl Mul<u32> for Angle {
+    type Output = Angle;
+
+    fn mul(self, n: u32) -> Angle {
+        Angle(self.0.wrapping_mul(n))
+    }
+}
+
+impl Div<u32> for Angle {
+    type Output = Angle;
+
+    fn div(self, n: u32) -> Angle {
+        Angle(self.0 / n)
+    }
+}
+
+pub fn finesine(fine: usize) -> Fixed {
+    Fixed(FINESINE[fine & FINEMASK])
+}
+
+pub fn finecosine(fine: usize) -> Fixed {
+    Fixed(FINESINE[(fine & FINEMASK) + FINEANGLES / 4])
+}
+
+// Only defined from -90 to 90 degrees, so it takes a fine angle that has
+// had ANG90 added to it first.
+pub fn finetangent(fine: usize) -> Fixed {
+    Fixed(FINETANGENT[fine])
+}
+
+// The TANTOANGLE index for num / den, for num <= den. The shift wraps for
+// large numerators, as it does in vanilla.
+pub fn slope_div(num: u32, den: u32) -> usize {
+    if den < 512 {
+        return SLOPERANGE;
+    }
+    let ans = ((num << 3) / (den >> 8)) as usize;
+    ans.min(SLOPERANGE)
+}
+
+// R_PointToAngle for a point relative to the viewer: the slope is looked
+// up in the octant it falls in.
+pub fn point_to_angle(x: Fixed, y: Fixed) -> Angle {
+    let (x, y) = (x.0, y.0);
+    let tan = |num: i32, den: i32| Angle(TANTOANGLE[slope_div(num as u32, den as u32)]);
+
+    if x == 0 && y == 0 {
+        return Angle(0);
+    }
+
+    if x >= 0 {
+        if y >= 0 {
+            if x > y {
+                tan(y, x)
+            } else {
+                Angle::ANG90 - Angle(1) - tan(x, y)
+            }
+        } else {
+            let y = y.wrapping_neg();
+            if x > y {
+                -tan(y, x)
+            } else {
+                Angle::ANG270 + tan(x, y)
+            }
+        }
+    } else {
+        let x = x.wrapping_neg();
+        if y >= 0 {
+            if x > y {
+                Angle::ANG180 - Angle(1) - tan(y, x)
+            } else {
+                Angle::ANG90 + tan(x, y)
+            }
+        } else {
+            let y = y.wrapping_neg();
+            if x > y {
+                Angle::ANG180 + tan(y, x)
+            } else {
+                Angle::ANG270 - Angle(1) - tan(x, y)
+            }
+        }
+    }
+}
+
+pub fn point_to_angle2(x1: Fixed, y1: Fixed, x2: Fixed, y2: Fixed) -> Angle {
+    point_to_angle(x2 - x1, y2 - y1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The first entries of each table as they appear in vanilla's tables.c.
+    #[test]
+    fn tables_match_vanilla() {
+        assert_eq!(
+            FINESINE[..24],
+            [
+                25, 75, 125, 175, 226, 276, 326, 376, 427, 477, 527, 578, 628, 678, 728, 779, 829,
+                879, 929, 980, 1030, 1080, 1130, 1181
+            ]
+        );
+        assert_eq!(
+            FINETANGENT[..16],
+            [
+                -170910304, -56965752, -34178904, -24413316, -18988036, -15535599, -13145455,
+                -11392683, -10052327, -8994149, -8137527, -7429880, -6835455, -6329090, -5892567,
+                -5512368
+            ]
+        );
+        assert_eq!(
+            TANTOANGLE[..16],
+            [
+                0, 333772, 667544, 1001315, 1335086, 1668857, 2002626, 2336395, 2670163, 3003929,
+                3337694, 3671457, 4005219, 4338979, 4672736, 5006492
+            ]
+        );
+        assert_eq!(TANTOANGLE[SLOPERANGE], Angle::ANG45.0);
+    }
+
+    // SHA-1 of each whole table as little-endian words, so a change to any
+    // entry is caught. These were taken from the tables as they are here,
+    // not from tables.c, and still need confirming against it.
+    #[test]
+    fn whole_tables_are_unchanged() {
+        let hash = |words: &mut dyn Iterator<Item = [u8; 4]>| {
+            let mut sha1 = crate::sha1::Sha1::new();
+            for word in words {
+                sha1.update(&word);
+            }
+            crate::sha1::to_hex(&sha1.finish())
+        };
+
+        assert_eq!(
+            hash(&mut FINESINE.iter().map(|n| n.to_le_bytes())),
+            "0811fed3fbd4b1d7585956d02ad8ae189245387a"
+        );
+        assert_eq!(
+            hash(&mut FINETANGENT.iter().map(|n| n.to_le_bytes())),
+            "296a45a37f2bb9b32f51acd19d1143c1932afb7c"
+        );
+        assert_eq!(
+            hash(&mut TANTOANGLE.iter().map(|n| n.to_le_bytes())),
+            "612eeee3233b5d0031d0ef05f549d96b4784fea9"
+        );
+    }
+
+    #[test]
+    fn tables_are_symmetric() {
+        for i in 0..FINEANGLES / 2 {
+            assert_eq!(FINETANGENT[i], -FINETANGENT[FINEANGLES / 2 - 1 - i]);
+        }
+        assert_eq!(finecosine(0), Fixed(65535));
+        assert_eq!(finesine(FINEANGLES / 2), Fixed(-25));
+    }
+
+    #[test]
+    fn angles_wrap() {
+        assert_eq!(Angle::ANG270 + Angle::ANG180, Angle::ANG90);
+        assert_eq!(Angle(0) - Angle::ANG90, Angle::ANG270);
+        assert_eq!(-Angle::ANG90, Angle::ANG270);
+        assert_eq!(Angle::ANG45 * 9, Angle::ANG45);
+        assert_eq!(Angle::from_fine(FINEANGLES / 4), Angle::ANG90);
+        assert_eq!(Angle::ANG180.fine(), FINEANGLES / 2);
+        assert_eq!(Angle::from_degrees(-90.0), Angle::ANG270);
+    }
+
+    #[test]
+    fn point_to_angle_octants() {
+        let unit = crate::fixed::FRACUNIT;
+        assert_eq!(point_to_angle(unit, Fixed(0)), Angle(0));
+        assert_eq!(point_to_angle(unit, unit), Angle::ANG45 - Angle(1));
+        assert_eq!(point_to_angle(Fixed(0), unit), Angle::ANG90 - Angle(1));
+        assert_eq!(point_to_angle(-unit, Fixed(0)), Angle::ANG180 - Angle(1));
+        assert_eq!(point_to_angle(Fixed(0), -unit), Angle::ANG270);
+        assert_eq!(
+            point_to_angle(-unit, -unit),
+            Angle::ANG270 - Angle::ANG45 - Angle(1)
+        );
+        assert_eq!(point_to_angle(Fixed(0), Fixed(0)), Angle(0));
+        assert_eq!(
+            point_to_angle2(unit, unit, Fixed(0), unit * 2),
+            Angle::ANG90 + Angle::ANG45
+        );
+    }
+}
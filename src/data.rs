@@ -0,0 +1,58 @@
+pub const MAP_LUMP_NAMES: [&str; 10] = [
+    "THINGS", "LINEDEFS", "SIDEDEFS", "VERTEXES", "SEGS", "SSECTORS", "NODES", "SECTORS", "REJECT",
+    "BLOCKMAP",
+];
+
+pub const HEXEN_MAP_LUMP_NAMES: [&str; 2] = ["BEHAVIOR", "SCRIPTS"];
+
+// The lumps that can come between TEXTMAP and ENDMAP in a UDMF map.
+pub const UDMF_MAP_LUMP_NAMES: [&str; 6] = [
+    "ZNODES", "REJECT", "BLOCKMAP", "DIALOGUE", "BEHAVIOR", "SCRIPTS",
+];
+
+pub fn is_map_lump_name(name: &str) -> bool {
+    MAP_LUMP_NAMES
+        .iter()
+        .chain(HEXEN_MAP_LUMP_NAMES.iter())
+        .any(|n| n.eq_ignore_ascii_case(name))
+}
+
+pub fn map_lump_order(name: &str) -> Option<usize> {
+    MAP_LUMP_NAMES
+        .iter()
+        .chain(HEXEN_MAP_LUMP_NAMES.iter())
+        .position(|n| n.eq_ignore_ascii_case(name))
+}
+
+pub fn is_map_header(wad: &crate::wad::Wad, lump: usize) -> bool {
+    wad.lump(lump + 1)
+        .map(|next| {
+            let name = next.name();
+            name.eq_ignore_ascii_case("THINGS") || name.eq_ignore_ascii_case("TEXTMAP")
+        })
+        .unwrap_or(false)
+}
+
+pub fn map_lump_range(wad: &crate::wad::Wad, header: usize) -> std::ops::Range<usize> {
+    let first = header + 1;
+    let is_udmf = wad
+        .lump(first)
+        .map(|l| l.name().eq_ignore_ascii_case("TEXTMAP"))
+        .unwrap_or(false);
+
+    let mut end = first;
+    while let Some(lump) = wad.lump(end) {
+        let name = lump.name();
+        end += 1;
+        if is_udmf {
+            if name.eq_ignore_ascii_case("ENDMAP") {
+                break;
+            }
+        } else if !is_map_lump_name(&name) {
+            end -= 1;
+            break;
+        }
+    }
+
+    first..end
+}
@@ -0,0 +1,726 @@
+pub mod ammo;
+pub mod cheat;
+pub mod codeptr;
+pub mod frame;
+pub mod misc;
+pub mod pars;
+pub mod pointer;
+pub mod sound;
+pub mod strings;
+pub mod text;
+pub mod thing;
+pub mod weapon;
+
+const SIGNATURE: &str = "Patch File for DeHackEd v";
+const SUPPORTED_VERSIONS: [&str; 5] = ["2.0", "2.1", "2.2", "2.3", "3.0"];
+const DOOM_VERSIONS: [i32; 5] = [16, 17, 19, 20, 21];
+const PATCH_FORMATS: [i32; 2] = [5, 6];
+
+// Boom blocks we know about but have nothing to apply them to.
+const UNSUPPORTED_BLOCKS: [&str; 4] = ["[HELPER]", "[SPRITES]", "[SOUNDS]", "[MUSIC]"];
+
+pub struct Dehacked {
+    pub misc: misc::MiscValues,
+    pub cheats: Vec<cheat::CheatSequence>,
+    pub strings: std::collections::HashMap<String, String>,
+    pub episode_pars: std::collections::HashMap<(usize, usize), i32>,
+    pub map_pars: std::collections::HashMap<usize, i32>,
+}
+
+impl Default for Dehacked {
+    fn default() -> Self {
+        Self {
+            misc: misc::MiscValues::default(),
+            cheats: cheat::default_cheats(),
+            strings: std::collections::HashMap::new(),
+            episode_pars: std::collections::HashMap::new(),
+            map_pars: std::collections::HashMap::new(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Section {
+    Thing(usize),
+    Frame(usize),
+    Pointer(usize),
+    Sound(usize),
+    Ammo(usize),
+    Weapon(usize),
+    Cheat,
+    Misc,
+    Text,
+    Ignored,
+    Strings,
+    Pars,
+    CodePointers,
+    IgnoredBlock,
+}
+
+impl Section {
+    // BEX blocks run until the next header rather than to a blank line.
+    fn is_block(self) -> bool {
+        matches!(
+            self,
+            Section::Strings | Section::Pars | Section::CodePointers | Section::IgnoredBlock
+        )
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+    line_number: usize,
+}
+
+impl<'a> Reader<'a> {
+    // Patches are plain 8-bit text, so every byte maps to one character and
+    // the lengths in Text sections keep counting bytes.
+    fn read_line(&mut self) -> Option<String> {
+        if self.position >= self.data.len() {
+            return None;
+        }
+
+        self.line_number += 1;
+        let rest = &self.data[self.position..];
+        let end = rest.iter().position(|&b| b == b'\n').unwrap_or(rest.len());
+        self.position += (end + 1).min(rest.len());
+
+        Some(
+            rest[..end]
+                .iter()
+                .filter(|&&b| b != b'\r')
+                .map(|&b| b as char)
+                .collect(),
+        )
+    }
+
+    fn read_chars(&mut self, count: usize) -> String {
+        let mut result = String::with_capacity(count);
+        let mut read = 0;
+
+        while read < count && self.position < self.data.len() {
+            let c = self.data[self.position];
+            self.position += 1;
+            match c {
+                b'\r' => continue,
+                b'\n' => self.line_number += 1,
+                _ => {}
+            }
+            result.push(c as char);
+            read += 1;
+        }
+
+        result
+    }
+}
+
+pub struct Patch<'a> {
+    file_name: &'a str,
+    line_number: usize,
+    info: &'a mut crate::info::Info,
+    dehacked: &'a mut Dehacked,
+    warnings: usize,
+    included: bool,
+    no_text: bool,
+    pending_string: Option<(String, String)>,
+}
+
+impl<'a> Patch<'a> {
+    pub fn warn<S: AsRef<str>>(&mut self, message: S) {
+        eprintln!(
+            "{}:{}: {}",
+            self.file_name,
+            self.line_number,
+            message.as_ref()
+        );
+        self.warnings += 1;
+    }
+
+    fn parse_int(&mut self, value: &str) -> Option<i32> {
+        let value = value.trim();
+        let parsed = if let Some(hex) = value
+            .strip_prefix("0x")
+            .or_else(|| value.strip_prefix("0X"))
+        {
+            i64::from_str_radix(hex, 16).ok()
+        } else {
+            value.parse::<i64>().ok()
+        };
+
+        match parsed {
+            Some(v) if v >= i32::MIN as i64 && v <= u32::MAX as i64 => Some(v as i32),
+            _ => {
+                self.warn(format!("'{}' is not a valid number", value));
+                None
+            }
+        }
+    }
+
+    fn parse_index(&mut self, value: &str, limit: usize, what: &str) -> Option<usize> {
+        let value = self.parse_int(value)?;
+        if value < 0 || value as usize >= limit {
+            self.warn(format!("invalid {} number {}", what, value));
+            return None;
+        }
+        Some(value as usize)
+    }
+
+    fn parse_state(&mut self, value: &str) -> Option<usize> {
+        self.parse_index(value, crate::info::NUM_STATES, "frame")
+    }
+
+    fn parse_sound(&mut self, value: &str) -> Option<usize> {
+        self.parse_index(value, crate::sounds::NUM_SFX, "sound")
+    }
+
+    fn unknown_field(&mut self, section: &str, field: &str) {
+        self.warn(format!("unknown {} field '{}'", section, field));
+    }
+
+    fn unsupported_field(&mut self, section: &str, field: &str) {
+        self.warn(format!("{} field '{}' is not supported", section, field));
+    }
+
+    fn section_number(
+        &mut self,
+        word: Option<&str>,
+        first: usize,
+        count: usize,
+        what: &str,
+    ) -> Option<usize> {
+        match word.and_then(|w| w.parse::<usize>().ok()) {
+            Some(n) if n >= first && n < first + count => Some(n - first),
+            _ => {
+                self.warn(format!(
+                    "invalid {} number '{}', section ignored",
+                    what,
+                    word.unwrap_or("")
+                ));
+                None
+            }
+        }
+    }
+
+    fn start_block(&mut self, line: &str) -> Section {
+        let name = line.trim().to_uppercase();
+        match name.as_str() {
+            "[STRINGS]" => Section::Strings,
+            "[PARS]" => Section::Pars,
+            "[CODEPTR]" => Section::CodePointers,
+            _ if UNSUPPORTED_BLOCKS.contains(&name.as_str()) => {
+                self.warn(format!("BEX block {} is not supported, ignored", name));
+                Section::IgnoredBlock
+            }
+            _ => {
+                self.warn(format!("unknown BEX block {}, ignored", line.trim()));
+                Section::IgnoredBlock
+            }
+        }
+    }
+
+    fn start_section(&mut self, line: &str, reader: &mut Reader) -> Option<Section> {
+        if line.trim_start().starts_with('[') {
+            return Some(self.start_block(line));
+        }
+
+        let mut words = line.split_whitespace();
+        let name = words.next()?.to_lowercase();
+        let number = words.next();
+
+        let section = match name.as_str() {
+            "thing" => self
+                .section_number(number, 1, crate::info::NUM_MOBJ_TYPES, "thing")
+                .map(Section::Thing),
+            "frame" => self
+                .section_number(number, 0, crate::info::NUM_STATES, "frame")
+                .map(Section::Frame),
+            "sound" => self
+                .section_number(number, 0, crate::sounds::NUM_SFX, "sound")
+                .map(Section::Sound),
+            "ammo" => self
+                .section_number(number, 0, crate::items::NUM_AMMO, "ammo")
+                .map(Section::Ammo),
+            "weapon" => self
+                .section_number(number, 0, crate::items::NUM_WEAPONS, "weapon")
+                .map(Section::Weapon),
+            "pointer" => self.start_pointer(line),
+            "cheat" => Some(Section::Cheat),
+            "misc" => Some(Section::Misc),
+            "text" => {
+                self.read_text(line, reader);
+                Some(Section::Text)
+            }
+            _ => return None,
+        };
+
+        Some(section.unwrap_or(Section::Ignored))
+    }
+
+    fn parse_section_line(&mut self, section: Section, line: &str) {
+        match section {
+            Section::Text | Section::Ignored | Section::IgnoredBlock => return,
+            Section::Strings => return self.parse_string_line(line),
+            Section::Pars => return self.parse_par_line(line),
+            _ => {}
+        }
+
+        let (field, value) = match parse_assignment(line) {
+            Some(assignment) => assignment,
+            None => {
+                self.warn(format!("failed to parse assignment '{}'", line.trim()));
+                return;
+            }
+        };
+
+        match section {
+            Section::Thing(n) => self.parse_thing_field(n, field, value),
+            Section::Frame(n) => self.parse_frame_field(n, field, value),
+            Section::Pointer(n) => self.parse_pointer_field(n, field, value),
+            Section::Sound(n) => self.parse_sound_field(n, field, value),
+            Section::Ammo(n) => self.parse_ammo_field(n, field, value),
+            Section::Weapon(n) => self.parse_weapon_field(n, field, value),
+            Section::Cheat => self.parse_cheat_field(field, value),
+            Section::Misc => self.parse_misc_field(field, value),
+            Section::CodePointers => self.parse_codeptr_field(field, value),
+            _ => {}
+        }
+    }
+
+    // "INCLUDE [NOTEXT] <file>" pulls in another patch, skipping its Text
+    // sections with NOTEXT. Like Boom, included files can't include again.
+    fn include(&mut self, line: &str) {
+        let mut rest = line.trim_start()[7..].trim();
+        let no_text = match rest.split_whitespace().next() {
+            Some(word) if word.eq_ignore_ascii_case("notext") => {
+                rest = rest[6..].trim();
+                true
+            }
+            _ => false,
+        };
+
+        if self.included {
+            return self.warn("nested INCLUDE is not supported, ignored");
+        }
+        if rest.is_empty() {
+            return self.warn("INCLUDE without a file name");
+        }
+
+        let mut path = std::path::PathBuf::from(rest);
+        if !path.exists() {
+            if let Some(dir) = std::path::Path::new(self.file_name).parent() {
+                path = dir.join(rest);
+            }
+        }
+        let path = path.to_string_lossy().into_owned();
+        let data = match std::fs::read(&path) {
+            Ok(data) => data,
+            Err(e) => return self.warn(format!("failed to include {}: {}", path, e)),
+        };
+
+        println!(" including {}", path);
+        let mut included = Patch {
+            file_name: &path,
+            line_number: 0,
+            info: &mut *self.info,
+            dehacked: &mut *self.dehacked,
+            warnings: 0,
+            included: true,
+            no_text,
+            pending_string: None,
+        };
+        let result = included.apply(&data);
+        self.warnings += included.warnings;
+
+        if let Err(e) = result {
+            self.warn(e);
+        }
+    }
+
+    fn apply(&mut self, data: &[u8]) -> Result<(), String> {
+        let mut reader = Reader {
+            data,
+            position: 0,
+            line_number: 0,
+        };
+
+        let first = std::iter::from_fn(|| reader.read_line()).find(|l| !l.trim().is_empty());
+        match first {
+            Some(line) if check_signature(&line) => {}
+            // Boom reads BEX-only files without the DeHackEd signature.
+            Some(line) if is_bex_line(&line) => {
+                reader.position = 0;
+                reader.line_number = 0;
+            }
+            _ => return Err(format!("{} is not a valid DeHackEd patch", self.file_name)),
+        }
+
+        let mut section = None;
+
+        while let Some(line) = reader.read_line() {
+            self.line_number = reader.line_number;
+
+            if self.pending_string.is_some() {
+                self.continue_string(&line);
+                continue;
+            }
+
+            if line.starts_with('#') {
+                continue;
+            }
+
+            if line.trim().is_empty() {
+                if !section.map(Section::is_block).unwrap_or(false) {
+                    section = None;
+                }
+                continue;
+            }
+
+            if is_keyword(&line, "include") {
+                self.include(&line);
+                section = None;
+                continue;
+            }
+
+            match section {
+                Some(current) if line.contains('=') => self.parse_section_line(current, &line),
+                _ => match self.start_section(&line, &mut reader) {
+                    Some(new_section) => section = Some(new_section),
+                    None => match section {
+                        Some(current) => self.parse_section_line(current, &line),
+                        None => self.parse_header_line(&line),
+                    },
+                },
+            }
+        }
+
+        if self.pending_string.is_some() {
+            self.warn("string continued past the end of the patch");
+        }
+
+        Ok(())
+    }
+
+    fn parse_header_line(&mut self, line: &str) {
+        let (field, value) = match parse_assignment(line) {
+            Some(assignment) => assignment,
+            None => {
+                self.warn(format!("unknown section '{}'", line.trim()));
+                return;
+            }
+        };
+
+        match field.to_lowercase().as_str() {
+            "doom version" => {
+                if let Some(version) = self.parse_int(value) {
+                    if !DOOM_VERSIONS.contains(&version) {
+                        self.warn(format!(
+                            "patch is for an unknown Doom version {}, it may not work",
+                            version
+                        ));
+                    }
+                }
+            }
+            "patch format" => {
+                if let Some(format) = self.parse_int(value) {
+                    if !PATCH_FORMATS.contains(&format) {
+                        self.warn(format!("unknown patch format {}, it may not work", format));
+                    }
+                }
+            }
+            _ => self.warn(format!("unknown header field '{}'", field)),
+        }
+    }
+}
+
+fn parse_assignment(line: &str) -> Option<(&str, &str)> {
+    let equals = line.find('=')?;
+    let field = line[..equals].trim();
+    let value = line[equals + 1..].trim();
+
+    if field.is_empty() {
+        None
+    } else {
+        Some((field, value))
+    }
+}
+
+fn is_keyword(line: &str, keyword: &str) -> bool {
+    line.split_whitespace()
+        .next()
+        .map(|w| w.eq_ignore_ascii_case(keyword))
+        .unwrap_or(false)
+}
+
+fn is_bex_line(line: &str) -> bool {
+    line.trim_start().starts_with('[') || is_keyword(line, "include")
+}
+
+fn check_signature(line: &str) -> bool {
+    line.trim_end()
+        .strip_prefix(SIGNATURE)
+        .map(|version| SUPPORTED_VERSIONS.contains(&version))
+        .unwrap_or(false)
+}
+
+pub fn apply_patch(
+    file_name: &str,
+    data: &[u8],
+    info: &mut crate::info::Info,
+    dehacked: &mut Dehacked,
+) -> Result<usize, String> {
+    let mut patch = Patch {
+        file_name,
+        line_number: 0,
+        info,
+        dehacked,
+        warnings: 0,
+        included: false,
+        no_text: false,
+        pending_string: None,
+    };
+    patch.apply(data)?;
+
+    Ok(patch.warnings)
+}
+
+impl crate::state::State {
+    pub fn load_dehacked(&mut self) {
+        let lumps = self
+            .wad
+            .lumps
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| l.name().eq_ignore_ascii_case("DEHACKED"))
+            .map(|(i, l)| (i, l.wad_file))
+            .collect::<Vec<_>>();
+
+        for &(lump, _) in lumps.iter().filter(|(_, wad_file)| *wad_file == 0) {
+            self.load_dehacked_lump(lump);
+        }
+
+        for dir in self.autoload_dirs.clone() {
+            for path in crate::wad::main::autoload_files(&dir, &["deh", "bex"]) {
+                self.load_dehacked_file(&path);
+            }
+        }
+
+        for path in self.parm_files("-deh") {
+            self.load_dehacked_file(&path);
+        }
+
+        for &(lump, _) in lumps.iter().filter(|(_, wad_file)| *wad_file != 0) {
+            self.load_dehacked_lump(lump);
+        }
+    }
+
+    pub fn load_dehacked_file(&mut self, path: &str) {
+        println!(" loading {}", path);
+
+        let data = std::fs::read(path)
+            .unwrap_or_else(|e| self.error(format!("Failed to read {}: {}", path, e)));
+        self.apply_dehacked(path, &data);
+    }
+
+    pub fn load_dehacked_lump(&mut self, lump: usize) {
+        let file_name = format!(
+            "{}:DEHACKED",
+            self.wad.files[self.wad.lumps[lump].wad_file].path
+        );
+        println!(" loading {}", file_name);
+
+        let data = self
+            .wad
+            .read_lump(lump)
+            .unwrap_or_else(|e| self.error(e.to_string()));
+        self.apply_dehacked(&file_name, &data);
+    }
+
+    fn apply_dehacked(&mut self, file_name: &str, data: &[u8]) {
+        let result = apply_patch(file_name, data, &mut self.info, &mut self.dehacked);
+        if let Err(e) = result {
+            self.error(e);
+        }
+    }
+
+    pub fn deh_string<'a>(&'a self, s: &'a str) -> &'a str {
+        self.dehacked
+            .strings
+            .get(s)
+            .map(|r| r.as_str())
+            .unwrap_or(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wad::testing::TempFile;
+
+    struct Applied {
+        warnings: usize,
+        info: crate::info::Info,
+        dehacked: Dehacked,
+    }
+
+    fn apply_named(file_name: &str, patch: &[u8]) -> Applied {
+        let mut info = crate::info::Info::default();
+        let mut dehacked = Dehacked::default();
+        let warnings = apply_patch(file_name, patch, &mut info, &mut dehacked).unwrap();
+        Applied {
+            warnings,
+            info,
+            dehacked,
+        }
+    }
+
+    fn apply(patch: &[u8]) -> Applied {
+        apply_named("test.bex", patch)
+    }
+
+    #[test]
+    fn text_lengths_count_bytes() {
+        let patch = b"Patch File for DeHackEd v3.0\n\
+            Text 5 5\n\
+            caf\xe9!h\xe9llo\n\
+            \n\
+            Misc 0\n\
+            Initial Health = 150\n";
+
+        let mut info = crate::info::Info::default();
+        let mut dehacked = Dehacked::default();
+        let warnings = apply_patch("test.deh", patch, &mut info, &mut dehacked).unwrap();
+
+        assert_eq!(warnings, 0);
+        assert_eq!(dehacked.strings["caf\u{e9}!"], "h\u{e9}llo");
+        assert_eq!(dehacked.misc.initial_health, 150);
+    }
+
+    #[test]
+    fn bex_strings_replace_by_mnemonic() {
+        let applied = apply(
+            b"[STRINGS]\n\
+            gotredskull = Red key!\n\
+            HUSTR_E1M1 = Hangar \\\n\
+            \tof doom\\n\n\
+            NOSUCHSTRING = whatever\n",
+        );
+
+        assert_eq!(applied.warnings, 1);
+        let strings = &applied.dehacked.strings;
+        assert_eq!(strings[crate::english::GOTREDSKULL], "Red key!");
+        assert_eq!(strings[crate::english::HUSTR_E1M1], "Hangar of doom\n");
+        assert_eq!(strings.len(), 2);
+    }
+
+    #[test]
+    fn bex_pars_set_episode_and_map_times() {
+        let applied = apply(
+            b"Patch File for DeHackEd v3.0\n\
+            [PARS]\n\
+            par 1 2 90\n\
+            par 15 300\n\
+            \n\
+            par 5 1 10\n\
+            pars 3 4\n",
+        );
+
+        assert_eq!(applied.warnings, 2);
+        assert_eq!(applied.dehacked.episode_par(1, 2), Some(90));
+        assert_eq!(applied.dehacked.map_par(15), Some(300));
+        assert_eq!(applied.dehacked.episode_pars.len(), 1);
+        assert_eq!(applied.dehacked.map_pars.len(), 1);
+    }
+
+    #[test]
+    fn bex_codepointers_set_frame_actions() {
+        use crate::info::Action;
+
+        let applied = apply(
+            b"[CODEPTR]\n\
+            FRAME 1 = Scream\n\
+            Frame 2 = A_Explode\n\
+            FRAME 3 = Detonate\n\
+            FRAME 4 = NoSuchAction\n\
+            FRAME 99999 = Scream\n",
+        );
+
+        assert_eq!(applied.warnings, 3);
+        assert_eq!(applied.info.states[1].action, Action::Scream);
+        assert_eq!(applied.info.states[2].action, Action::Explode);
+        let original = crate::info::Info::default();
+        assert_eq!(applied.info.states[3].action, original.states[3].action);
+        assert_eq!(applied.info.states[4].action, original.states[4].action);
+    }
+
+    #[test]
+    fn thing_bits_take_flag_mnemonics() {
+        use crate::info::{MF_COUNTKILL, MF_SHOOTABLE, MF_SOLID, MF_TRANSSHIFT};
+
+        let applied = apply(
+            b"Patch File for DeHackEd v3.0\n\
+            Thing 1 (Player)\n\
+            Bits = solid+SHOOTABLE | COUNTKILL, TRANSLATION1\n\
+            \n\
+            Thing 2 (Zombieman)\n\
+            Bits = SOLID+NOSUCHFLAG\n\
+            \n\
+            Thing 3 (Sergeant)\n\
+            Bits = 6\n",
+        );
+
+        assert_eq!(applied.warnings, 1);
+        let mobj_info = &applied.info.mobj_info;
+        assert_eq!(
+            mobj_info[0].flags,
+            MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL | 1 << MF_TRANSSHIFT
+        );
+        let original = crate::info::Info::default();
+        assert_eq!(mobj_info[1].flags, original.mobj_info[1].flags);
+        assert_eq!(mobj_info[2].flags, MF_SOLID | MF_SHOOTABLE);
+    }
+
+    #[test]
+    fn include_applies_another_patch() {
+        let included = TempFile::new(
+            "deh",
+            b"Patch File for DeHackEd v3.0\n\
+            Misc 0\n\
+            Initial Health = 150\n\
+            \n\
+            Text 6 3\n\
+            ZOMBIEfoo\n",
+        );
+        let name = std::path::Path::new(&included.path)
+            .file_name()
+            .unwrap()
+            .to_str()
+            .unwrap();
+        // Found next to the including patch, not in the working directory.
+        let main = std::env::temp_dir().join("main.bex");
+        let main = main.to_str().unwrap();
+
+        let applied = apply_named(main, format!("INCLUDE {}\n", name).as_bytes());
+        assert_eq!(applied.warnings, 0);
+        assert_eq!(applied.dehacked.misc.initial_health, 150);
+        assert_eq!(applied.dehacked.strings["ZOMBIE"], "foo");
+
+        let applied = apply_named(main, format!("INCLUDE NOTEXT {}\n", name).as_bytes());
+        assert_eq!(applied.warnings, 0);
+        assert_eq!(applied.dehacked.misc.initial_health, 150);
+        assert!(applied.dehacked.strings.is_empty());
+
+        let applied = apply_named(main, b"INCLUDE no_such_patch.deh\nINCLUDE\n");
+        assert_eq!(applied.warnings, 2);
+    }
+
+    #[test]
+    fn include_does_not_nest() {
+        let inner = TempFile::new("deh", b"[PARS]\npar 1 1 30\n");
+        let outer = TempFile::new("deh", format!("INCLUDE {}\n", inner.path).as_bytes());
+
+        let applied = apply(format!("INCLUDE {}\n", outer.path).as_bytes());
+        assert_eq!(applied.warnings, 1);
+        assert!(applied.dehacked.episode_pars.is_empty());
+    }
+}
@@ -0,0 +1,14 @@
+impl super::Patch<'_> {
+    pub(super) fn parse_ammo_field(&mut self, ammo: usize, field: &str, value: &str) {
+        let value = match self.parse_int(value) {
+            Some(value) => value,
+            None => return,
+        };
+
+        match field.to_lowercase().as_str() {
+            "max ammo" => self.info.max_ammo[ammo] = value,
+            "per ammo" => self.info.clip_ammo[ammo] = value,
+            _ => self.unknown_field("Ammo", field),
+        }
+    }
+}
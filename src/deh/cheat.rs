@@ -0,0 +1,70 @@
+// DeHackEd ends a cheat sequence with this byte instead of a null.
+const SEQUENCE_END: char = '\u{ff}';
+
+const CHEATS: [(&str, &str); 17] = [
+    ("Change music", "idmus"),
+    ("Chainsaw", "idchoppers"),
+    ("God mode", "iddqd"),
+    ("Ammo & Keys", "idkfa"),
+    ("Ammo", "idfa"),
+    ("No Clipping 1", "idspispopd"),
+    ("No Clipping 2", "idclip"),
+    ("Invincibility", "idbeholdv"),
+    ("Berserk", "idbeholds"),
+    ("Invisibility", "idbeholdi"),
+    ("Radiation Suit", "idbeholdr"),
+    ("Auto-map", "idbeholda"),
+    ("Lite-Amp Goggles", "idbeholdl"),
+    ("BEHOLD menu", "idbehold"),
+    ("Level Warp", "idclev"),
+    ("Player Position", "idmypos"),
+    ("Map cheat", "iddt"),
+];
+
+#[derive(Clone, Debug)]
+pub struct CheatSequence {
+    pub name: &'static str,
+    pub sequence: String,
+}
+
+pub fn default_cheats() -> Vec<CheatSequence> {
+    CHEATS
+        .iter()
+        .map(|&(name, sequence)| CheatSequence {
+            name,
+            sequence: sequence.to_owned(),
+        })
+        .collect()
+}
+
+impl super::Dehacked {
+    pub fn cheat_sequence(&self, name: &str) -> Option<&str> {
+        self.cheats
+            .iter()
+            .find(|c| c.name == name)
+            .map(|c| c.sequence.as_str())
+    }
+}
+
+impl super::Patch<'_> {
+    pub(super) fn parse_cheat_field(&mut self, field: &str, value: &str) {
+        let index = match CHEATS
+            .iter()
+            .position(|(name, _)| name.eq_ignore_ascii_case(field))
+        {
+            Some(index) => index,
+            None => return self.unknown_field("Cheat", field),
+        };
+
+        let sequence = value.split(SEQUENCE_END).next().unwrap_or("");
+        let original = CHEATS[index].1;
+        if sequence.len() > original.len() {
+            return self.warn(format!(
+                "cheat sequence '{}' is longer than the original '{}'",
+                sequence, original
+            ));
+        }
+
+        self.dehacked.cheats[index].sequence = sequence.to_owned();
+    }
+}
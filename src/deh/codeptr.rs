@@ -0,0 +1,52 @@
+// MBF's additions, which BEX patches for MBF ports use but vanilla has no
+// code for.
+const MBF_ACTIONS: [&str; 13] = [
+    "Detonate",
+    "Mushroom",
+    "Die",
+    "Spawn",
+    "Turn",
+    "Face",
+    "Scratch",
+    "PlaySound",
+    "RandomJump",
+    "LineEffect",
+    "FireOldBFG",
+    "BetaSkullAttack",
+    "Stop",
+];
+
+impl super::Patch<'_> {
+    // "FRAME <n> = <mnemonic>", the mnemonic being the A_ function name
+    // with or without its prefix.
+    pub(super) fn parse_codeptr_field(&mut self, field: &str, value: &str) {
+        let mut words = field.split_whitespace();
+        if !words
+            .next()
+            .map(|w| w.eq_ignore_ascii_case("frame"))
+            .unwrap_or(false)
+        {
+            return self.unknown_field("CODEPTR", field);
+        }
+
+        let frame = match words.next() {
+            Some(frame) => match self.parse_state(frame) {
+                Some(frame) => frame,
+                None => return,
+            },
+            None => return self.unknown_field("CODEPTR", field),
+        };
+
+        match crate::info::Action::from_name(value) {
+            Some(action) => self.info.states[frame].action = action,
+            None => {
+                let name = value.strip_prefix("A_").unwrap_or(value);
+                if MBF_ACTIONS.iter().any(|a| a.eq_ignore_ascii_case(name)) {
+                    self.warn(format!("codepointer '{}' is not supported", value));
+                } else {
+                    self.warn(format!("unknown codepointer '{}'", value));
+                }
+            }
+        }
+    }
+}
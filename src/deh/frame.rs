@@ -0,0 +1,29 @@
+impl super::Patch<'_> {
+    pub(super) fn parse_frame_field(&mut self, frame: usize, field: &str, value: &str) {
+        let name = field.to_lowercase();
+
+        let value = match name.as_str() {
+            "sprite number" => self
+                .parse_index(value, crate::info::NUM_SPRITES, "sprite")
+                .map(|v| v as i32),
+            "next frame" => self.parse_state(value).map(|v| v as i32),
+            "codep frame" => return self.unsupported_field("Frame", field),
+            _ => self.parse_int(value),
+        };
+        let value = match value {
+            Some(value) => value,
+            None => return,
+        };
+
+        let state = &mut self.info.states[frame];
+        match name.as_str() {
+            "sprite number" => state.sprite = value as usize,
+            "sprite subnumber" => state.frame = value,
+            "duration" => state.tics = value,
+            "next frame" => state.next_state = value as usize,
+            "unknown 1" => state.misc1 = value,
+            "unknown 2" => state.misc2 = value,
+            _ => self.unknown_field("Frame", field),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+const INFIGHTING_OFF: i32 = 202;
+const INFIGHTING_ON: i32 = 221;
+
+#[derive(Clone, Debug)]
+pub struct MiscValues {
+    pub initial_health: i32,
+    pub initial_bullets: i32,
+    pub max_health: i32,
+    pub max_armor: i32,
+    pub green_armor_class: i32,
+    pub blue_armor_class: i32,
+    pub max_soulsphere: i32,
+    pub soulsphere_health: i32,
+    pub megasphere_health: i32,
+    pub god_mode_health: i32,
+    pub idfa_armor: i32,
+    pub idfa_armor_class: i32,
+    pub idkfa_armor: i32,
+    pub idkfa_armor_class: i32,
+    pub bfg_cells_per_shot: i32,
+    pub species_infighting: bool,
+}
+
+impl Default for MiscValues {
+    fn default() -> Self {
+        Self {
+            initial_health: 100,
+            initial_bullets: 50,
+            max_health: 200,
+            max_armor: 200,
+            green_armor_class: 1,
+            blue_armor_class: 2,
+            max_soulsphere: 200,
+            soulsphere_health: 100,
+            megasphere_health: 200,
+            god_mode_health: 100,
+            idfa_armor: 200,
+            idfa_armor_class: 2,
+            idkfa_armor: 200,
+            idkfa_armor_class: 2,
+            bfg_cells_per_shot: 40,
+            species_infighting: false,
+        }
+    }
+}
+
+impl super::Patch<'_> {
+    pub(super) fn parse_misc_field(&mut self, field: &str, value: &str) {
+        let value = match self.parse_int(value) {
+            Some(value) => value,
+            None => return,
+        };
+
+        let misc = &mut self.dehacked.misc;
+        match field.to_lowercase().as_str() {
+            "initial health" => misc.initial_health = value,
+            "initial bullets" => misc.initial_bullets = value,
+            "max health" => misc.max_health = value,
+            "max armor" => misc.max_armor = value,
+            "green armor class" => misc.green_armor_class = value,
+            "blue armor class" => misc.blue_armor_class = value,
+            "max soulsphere" => misc.max_soulsphere = value,
+            "soulsphere health" => misc.soulsphere_health = value,
+            "megasphere health" => misc.megasphere_health = value,
+            "god mode health" => misc.god_mode_health = value,
+            "idfa armor" => misc.idfa_armor = value,
+            "idfa armor class" => misc.idfa_armor_class = value,
+            "idkfa armor" => misc.idkfa_armor = value,
+            "idkfa armor class" => misc.idkfa_armor_class = value,
+            "bfg cells/shot" => misc.bfg_cells_per_shot = value,
+            "monsters infight" => match value {
+                INFIGHTING_OFF => misc.species_infighting = false,
+                INFIGHTING_ON => misc.species_infighting = true,
+                _ => self.warn(format!(
+                    "invalid Monsters Infight value {} (expected {} or {})",
+                    value, INFIGHTING_OFF, INFIGHTING_ON
+                )),
+            },
+            _ => self.unknown_field("Misc", field),
+        }
+    }
+}
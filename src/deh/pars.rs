@@ -0,0 +1,41 @@
+impl super::Patch<'_> {
+    // "par <episode> <map> <seconds>" for Doom 1 maps, "par <map> <seconds>"
+    // for Doom 2 ones.
+    pub(super) fn parse_par_line(&mut self, line: &str) {
+        let mut words = line.split_whitespace();
+        if !words
+            .next()
+            .map(|w| w.eq_ignore_ascii_case("par"))
+            .unwrap_or(false)
+        {
+            return self.warn(format!("failed to parse par time '{}'", line.trim()));
+        }
+
+        let numbers = words
+            .map(|w| w.parse::<usize>().ok())
+            .collect::<Option<Vec<_>>>();
+        match numbers.as_deref() {
+            Some(&[episode, map, seconds])
+                if (1..=4).contains(&episode) && (1..=9).contains(&map) =>
+            {
+                self.dehacked
+                    .episode_pars
+                    .insert((episode, map), seconds as i32);
+            }
+            Some(&[map, seconds]) if (1..=32).contains(&map) => {
+                self.dehacked.map_pars.insert(map, seconds as i32);
+            }
+            _ => self.warn(format!("invalid par time '{}'", line.trim())),
+        }
+    }
+}
+
+impl super::Dehacked {
+    pub fn episode_par(&self, episode: usize, map: usize) -> Option<i32> {
+        self.episode_pars.get(&(episode, map)).copied()
+    }
+
+    pub fn map_par(&self, map: usize) -> Option<i32> {
+        self.map_pars.get(&map).copied()
+    }
+}
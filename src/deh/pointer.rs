@@ -0,0 +1,24 @@
+impl super::Patch<'_> {
+    // The header is "Pointer <n> (Frame <frame>)"; only the frame number in
+    // brackets matters, <n> is an index into DeHackEd's own table.
+    pub(super) fn start_pointer(&mut self, line: &str) -> Option<super::Section> {
+        let frame = line
+            .split('(')
+            .nth(1)
+            .and_then(|rest| rest.split(')').next())
+            .and_then(|inner| inner.split_whitespace().nth(1));
+
+        self.section_number(frame, 0, crate::info::NUM_STATES, "pointer frame")
+            .map(super::Section::Pointer)
+    }
+
+    pub(super) fn parse_pointer_field(&mut self, frame: usize, field: &str, value: &str) {
+        if !field.eq_ignore_ascii_case("codep frame") {
+            return self.unknown_field("Pointer", field);
+        }
+
+        if let Some(source) = self.parse_state(value) {
+            self.info.states[frame].action = crate::info::STATES[source].action;
+        }
+    }
+}
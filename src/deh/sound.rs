@@ -0,0 +1,32 @@
+impl super::Patch<'_> {
+    pub(super) fn parse_sound_field(&mut self, sound: usize, field: &str, value: &str) {
+        let name = field.to_lowercase();
+
+        match name.as_str() {
+            "offset" | "zero 4" | "neg. one 1" | "neg. one 2" => {
+                return self.unsupported_field("Sound", field)
+            }
+            "zero 1" => {
+                if let Some(link) = self.parse_sound(value) {
+                    self.info.sfx[sound].link = if link == 0 { None } else { Some(link) };
+                }
+                return;
+            }
+            _ => {}
+        }
+
+        let value = match self.parse_int(value) {
+            Some(value) => value,
+            None => return,
+        };
+
+        let sfx = &mut self.info.sfx[sound];
+        match name.as_str() {
+            "zero/one" => sfx.singularity = value != 0,
+            "value" => sfx.priority = value,
+            "zero 2" => sfx.pitch = value,
+            "zero 3" => sfx.volume = value,
+            _ => self.unknown_field("Sound", field),
+        }
+    }
+}
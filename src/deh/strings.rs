@@ -0,0 +1,363 @@
+// BEX names the strings it replaces by their d_englsh.h macro names; the
+// last few are lump names and the savegame prefix, which Boom added too.
+const MNEMONICS: [(&str, &str); 293] = [
+    ("D_DEVSTR", crate::english::DEVSTR),
+    ("D_CDROM", crate::english::CDROM),
+    ("PRESSKEY", crate::english::PRESSKEY),
+    ("PRESSYN", crate::english::PRESSYN),
+    ("QUITMSG", crate::english::QUITMSG),
+    ("LOADNET", crate::english::LOADNET),
+    ("QLOADNET", crate::english::QLOADNET),
+    ("QSAVESPOT", crate::english::QSAVESPOT),
+    ("SAVEDEAD", crate::english::SAVEDEAD),
+    ("QSPROMPT", crate::english::QSPROMPT),
+    ("QLPROMPT", crate::english::QLPROMPT),
+    ("NEWGAME", crate::english::NEWGAME),
+    ("NIGHTMARE", crate::english::NIGHTMARE),
+    ("SWSTRING", crate::english::SWSTRING),
+    ("MSGOFF", crate::english::MSGOFF),
+    ("MSGON", crate::english::MSGON),
+    ("NETEND", crate::english::NETEND),
+    ("ENDGAME", crate::english::ENDGAME),
+    ("DOSY", crate::english::DOSY),
+    ("DETAILHI", crate::english::DETAILHI),
+    ("DETAILLO", crate::english::DETAILLO),
+    ("GAMMALVL0", crate::english::GAMMALVL0),
+    ("GAMMALVL1", crate::english::GAMMALVL1),
+    ("GAMMALVL2", crate::english::GAMMALVL2),
+    ("GAMMALVL3", crate::english::GAMMALVL3),
+    ("GAMMALVL4", crate::english::GAMMALVL4),
+    ("EMPTYSTRING", crate::english::EMPTYSTRING),
+    ("GOTARMOR", crate::english::GOTARMOR),
+    ("GOTMEGA", crate::english::GOTMEGA),
+    ("GOTHTHBONUS", crate::english::GOTHTHBONUS),
+    ("GOTARMBONUS", crate::english::GOTARMBONUS),
+    ("GOTSTIM", crate::english::GOTSTIM),
+    ("GOTMEDINEED", crate::english::GOTMEDINEED),
+    ("GOTMEDIKIT", crate::english::GOTMEDIKIT),
+    ("GOTSUPER", crate::english::GOTSUPER),
+    ("GOTBLUECARD", crate::english::GOTBLUECARD),
+    ("GOTYELWCARD", crate::english::GOTYELWCARD),
+    ("GOTREDCARD", crate::english::GOTREDCARD),
+    ("GOTBLUESKUL", crate::english::GOTBLUESKUL),
+    ("GOTYELWSKUL", crate::english::GOTYELWSKUL),
+    ("GOTREDSKULL", crate::english::GOTREDSKULL),
+    ("GOTINVUL", crate::english::GOTINVUL),
+    ("GOTBERSERK", crate::english::GOTBERSERK),
+    ("GOTINVIS", crate::english::GOTINVIS),
+    ("GOTSUIT", crate::english::GOTSUIT),
+    ("GOTMAP", crate::english::GOTMAP),
+    ("GOTVISOR", crate::english::GOTVISOR),
+    ("GOTMSPHERE", crate::english::GOTMSPHERE),
+    ("GOTCLIP", crate::english::GOTCLIP),
+    ("GOTCLIPBOX", crate::english::GOTCLIPBOX),
+    ("GOTROCKET", crate::english::GOTROCKET),
+    ("GOTROCKBOX", crate::english::GOTROCKBOX),
+    ("GOTCELL", crate::english::GOTCELL),
+    ("GOTCELLBOX", crate::english::GOTCELLBOX),
+    ("GOTSHELLS", crate::english::GOTSHELLS),
+    ("GOTSHELLBOX", crate::english::GOTSHELLBOX),
+    ("GOTBACKPACK", crate::english::GOTBACKPACK),
+    ("GOTBFG9000", crate::english::GOTBFG9000),
+    ("GOTCHAINGUN", crate::english::GOTCHAINGUN),
+    ("GOTCHAINSAW", crate::english::GOTCHAINSAW),
+    ("GOTLAUNCHER", crate::english::GOTLAUNCHER),
+    ("GOTPLASMA", crate::english::GOTPLASMA),
+    ("GOTSHOTGUN", crate::english::GOTSHOTGUN),
+    ("GOTSHOTGUN2", crate::english::GOTSHOTGUN2),
+    ("PD_BLUEO", crate::english::PD_BLUEO),
+    ("PD_REDO", crate::english::PD_REDO),
+    ("PD_YELLOWO", crate::english::PD_YELLOWO),
+    ("PD_BLUEK", crate::english::PD_BLUEK),
+    ("PD_REDK", crate::english::PD_REDK),
+    ("PD_YELLOWK", crate::english::PD_YELLOWK),
+    ("GGSAVED", crate::english::GGSAVED),
+    ("HUSTR_MSGU", crate::english::HUSTR_MSGU),
+    ("HUSTR_E1M1", crate::english::HUSTR_E1M1),
+    ("HUSTR_E1M2", crate::english::HUSTR_E1M2),
+    ("HUSTR_E1M3", crate::english::HUSTR_E1M3),
+    ("HUSTR_E1M4", crate::english::HUSTR_E1M4),
+    ("HUSTR_E1M5", crate::english::HUSTR_E1M5),
+    ("HUSTR_E1M6", crate::english::HUSTR_E1M6),
+    ("HUSTR_E1M7", crate::english::HUSTR_E1M7),
+    ("HUSTR_E1M8", crate::english::HUSTR_E1M8),
+    ("HUSTR_E1M9", crate::english::HUSTR_E1M9),
+    ("HUSTR_E2M1", crate::english::HUSTR_E2M1),
+    ("HUSTR_E2M2", crate::english::HUSTR_E2M2),
+    ("HUSTR_E2M3", crate::english::HUSTR_E2M3),
+    ("HUSTR_E2M4", crate::english::HUSTR_E2M4),
+    ("HUSTR_E2M5", crate::english::HUSTR_E2M5),
+    ("HUSTR_E2M6", crate::english::HUSTR_E2M6),
+    ("HUSTR_E2M7", crate::english::HUSTR_E2M7),
+    ("HUSTR_E2M8", crate::english::HUSTR_E2M8),
+    ("HUSTR_E2M9", crate::english::HUSTR_E2M9),
+    ("HUSTR_E3M1", crate::english::HUSTR_E3M1),
+    ("HUSTR_E3M2", crate::english::HUSTR_E3M2),
+    ("HUSTR_E3M3", crate::english::HUSTR_E3M3),
+    ("HUSTR_E3M4", crate::english::HUSTR_E3M4),
+    ("HUSTR_E3M5", crate::english::HUSTR_E3M5),
+    ("HUSTR_E3M6", crate::english::HUSTR_E3M6),
+    ("HUSTR_E3M7", crate::english::HUSTR_E3M7),
+    ("HUSTR_E3M8", crate::english::HUSTR_E3M8),
+    ("HUSTR_E3M9", crate::english::HUSTR_E3M9),
+    ("HUSTR_E4M1", crate::english::HUSTR_E4M1),
+    ("HUSTR_E4M2", crate::english::HUSTR_E4M2),
+    ("HUSTR_E4M3", crate::english::HUSTR_E4M3),
+    ("HUSTR_E4M4", crate::english::HUSTR_E4M4),
+    ("HUSTR_E4M5", crate::english::HUSTR_E4M5),
+    ("HUSTR_E4M6", crate::english::HUSTR_E4M6),
+    ("HUSTR_E4M7", crate::english::HUSTR_E4M7),
+    ("HUSTR_E4M8", crate::english::HUSTR_E4M8),
+    ("HUSTR_E4M9", crate::english::HUSTR_E4M9),
+    ("HUSTR_1", crate::english::HUSTR_1),
+    ("HUSTR_2", crate::english::HUSTR_2),
+    ("HUSTR_3", crate::english::HUSTR_3),
+    ("HUSTR_4", crate::english::HUSTR_4),
+    ("HUSTR_5", crate::english::HUSTR_5),
+    ("HUSTR_6", crate::english::HUSTR_6),
+    ("HUSTR_7", crate::english::HUSTR_7),
+    ("HUSTR_8", crate::english::HUSTR_8),
+    ("HUSTR_9", crate::english::HUSTR_9),
+    ("HUSTR_10", crate::english::HUSTR_10),
+    ("HUSTR_11", crate::english::HUSTR_11),
+    ("HUSTR_12", crate::english::HUSTR_12),
+    ("HUSTR_13", crate::english::HUSTR_13),
+    ("HUSTR_14", crate::english::HUSTR_14),
+    ("HUSTR_15", crate::english::HUSTR_15),
+    ("HUSTR_16", crate::english::HUSTR_16),
+    ("HUSTR_17", crate::english::HUSTR_17),
+    ("HUSTR_18", crate::english::HUSTR_18),
+    ("HUSTR_19", crate::english::HUSTR_19),
+    ("HUSTR_20", crate::english::HUSTR_20),
+    ("HUSTR_21", crate::english::HUSTR_21),
+    ("HUSTR_22", crate::english::HUSTR_22),
+    ("HUSTR_23", crate::english::HUSTR_23),
+    ("HUSTR_24", crate::english::HUSTR_24),
+    ("HUSTR_25", crate::english::HUSTR_25),
+    ("HUSTR_26", crate::english::HUSTR_26),
+    ("HUSTR_27", crate::english::HUSTR_27),
+    ("HUSTR_28", crate::english::HUSTR_28),
+    ("HUSTR_29", crate::english::HUSTR_29),
+    ("HUSTR_30", crate::english::HUSTR_30),
+    ("HUSTR_31", crate::english::HUSTR_31),
+    ("HUSTR_32", crate::english::HUSTR_32),
+    ("PHUSTR_1", crate::english::PHUSTR_1),
+    ("PHUSTR_2", crate::english::PHUSTR_2),
+    ("PHUSTR_3", crate::english::PHUSTR_3),
+    ("PHUSTR_4", crate::english::PHUSTR_4),
+    ("PHUSTR_5", crate::english::PHUSTR_5),
+    ("PHUSTR_6", crate::english::PHUSTR_6),
+    ("PHUSTR_7", crate::english::PHUSTR_7),
+    ("PHUSTR_8", crate::english::PHUSTR_8),
+    ("PHUSTR_9", crate::english::PHUSTR_9),
+    ("PHUSTR_10", crate::english::PHUSTR_10),
+    ("PHUSTR_11", crate::english::PHUSTR_11),
+    ("PHUSTR_12", crate::english::PHUSTR_12),
+    ("PHUSTR_13", crate::english::PHUSTR_13),
+    ("PHUSTR_14", crate::english::PHUSTR_14),
+    ("PHUSTR_15", crate::english::PHUSTR_15),
+    ("PHUSTR_16", crate::english::PHUSTR_16),
+    ("PHUSTR_17", crate::english::PHUSTR_17),
+    ("PHUSTR_18", crate::english::PHUSTR_18),
+    ("PHUSTR_19", crate::english::PHUSTR_19),
+    ("PHUSTR_20", crate::english::PHUSTR_20),
+    ("PHUSTR_21", crate::english::PHUSTR_21),
+    ("PHUSTR_22", crate::english::PHUSTR_22),
+    ("PHUSTR_23", crate::english::PHUSTR_23),
+    ("PHUSTR_24", crate::english::PHUSTR_24),
+    ("PHUSTR_25", crate::english::PHUSTR_25),
+    ("PHUSTR_26", crate::english::PHUSTR_26),
+    ("PHUSTR_27", crate::english::PHUSTR_27),
+    ("PHUSTR_28", crate::english::PHUSTR_28),
+    ("PHUSTR_29", crate::english::PHUSTR_29),
+    ("PHUSTR_30", crate::english::PHUSTR_30),
+    ("PHUSTR_31", crate::english::PHUSTR_31),
+    ("PHUSTR_32", crate::english::PHUSTR_32),
+    ("THUSTR_1", crate::english::THUSTR_1),
+    ("THUSTR_2", crate::english::THUSTR_2),
+    ("THUSTR_3", crate::english::THUSTR_3),
+    ("THUSTR_4", crate::english::THUSTR_4),
+    ("THUSTR_5", crate::english::THUSTR_5),
+    ("THUSTR_6", crate::english::THUSTR_6),
+    ("THUSTR_7", crate::english::THUSTR_7),
+    ("THUSTR_8", crate::english::THUSTR_8),
+    ("THUSTR_9", crate::english::THUSTR_9),
+    ("THUSTR_10", crate::english::THUSTR_10),
+    ("THUSTR_11", crate::english::THUSTR_11),
+    ("THUSTR_12", crate::english::THUSTR_12),
+    ("THUSTR_13", crate::english::THUSTR_13),
+    ("THUSTR_14", crate::english::THUSTR_14),
+    ("THUSTR_15", crate::english::THUSTR_15),
+    ("THUSTR_16", crate::english::THUSTR_16),
+    ("THUSTR_17", crate::english::THUSTR_17),
+    ("THUSTR_18", crate::english::THUSTR_18),
+    ("THUSTR_19", crate::english::THUSTR_19),
+    ("THUSTR_20", crate::english::THUSTR_20),
+    ("THUSTR_21", crate::english::THUSTR_21),
+    ("THUSTR_22", crate::english::THUSTR_22),
+    ("THUSTR_23", crate::english::THUSTR_23),
+    ("THUSTR_24", crate::english::THUSTR_24),
+    ("THUSTR_25", crate::english::THUSTR_25),
+    ("THUSTR_26", crate::english::THUSTR_26),
+    ("THUSTR_27", crate::english::THUSTR_27),
+    ("THUSTR_28", crate::english::THUSTR_28),
+    ("THUSTR_29", crate::english::THUSTR_29),
+    ("THUSTR_30", crate::english::THUSTR_30),
+    ("THUSTR_31", crate::english::THUSTR_31),
+    ("THUSTR_32", crate::english::THUSTR_32),
+    ("HUSTR_CHATMACRO1", crate::english::HUSTR_CHATMACRO1),
+    ("HUSTR_CHATMACRO2", crate::english::HUSTR_CHATMACRO2),
+    ("HUSTR_CHATMACRO3", crate::english::HUSTR_CHATMACRO3),
+    ("HUSTR_CHATMACRO4", crate::english::HUSTR_CHATMACRO4),
+    ("HUSTR_CHATMACRO5", crate::english::HUSTR_CHATMACRO5),
+    ("HUSTR_CHATMACRO6", crate::english::HUSTR_CHATMACRO6),
+    ("HUSTR_CHATMACRO7", crate::english::HUSTR_CHATMACRO7),
+    ("HUSTR_CHATMACRO8", crate::english::HUSTR_CHATMACRO8),
+    ("HUSTR_CHATMACRO9", crate::english::HUSTR_CHATMACRO9),
+    ("HUSTR_CHATMACRO0", crate::english::HUSTR_CHATMACRO0),
+    ("HUSTR_TALKTOSELF1", crate::english::HUSTR_TALKTOSELF1),
+    ("HUSTR_TALKTOSELF2", crate::english::HUSTR_TALKTOSELF2),
+    ("HUSTR_TALKTOSELF3", crate::english::HUSTR_TALKTOSELF3),
+    ("HUSTR_TALKTOSELF4", crate::english::HUSTR_TALKTOSELF4),
+    ("HUSTR_TALKTOSELF5", crate::english::HUSTR_TALKTOSELF5),
+    ("HUSTR_MESSAGESENT", crate::english::HUSTR_MESSAGESENT),
+    ("HUSTR_PLRGREEN", crate::english::HUSTR_PLRGREEN),
+    ("HUSTR_PLRINDIGO", crate::english::HUSTR_PLRINDIGO),
+    ("HUSTR_PLRBROWN", crate::english::HUSTR_PLRBROWN),
+    ("HUSTR_PLRRED", crate::english::HUSTR_PLRRED),
+    ("AMSTR_FOLLOWON", crate::english::AMSTR_FOLLOWON),
+    ("AMSTR_FOLLOWOFF", crate::english::AMSTR_FOLLOWOFF),
+    ("AMSTR_GRIDON", crate::english::AMSTR_GRIDON),
+    ("AMSTR_GRIDOFF", crate::english::AMSTR_GRIDOFF),
+    ("AMSTR_MARKEDSPOT", crate::english::AMSTR_MARKEDSPOT),
+    ("AMSTR_MARKSCLEARED", crate::english::AMSTR_MARKSCLEARED),
+    ("STSTR_MUS", crate::english::STSTR_MUS),
+    ("STSTR_NOMUS", crate::english::STSTR_NOMUS),
+    ("STSTR_DQDON", crate::english::STSTR_DQDON),
+    ("STSTR_DQDOFF", crate::english::STSTR_DQDOFF),
+    ("STSTR_KFAADDED", crate::english::STSTR_KFAADDED),
+    ("STSTR_FAADDED", crate::english::STSTR_FAADDED),
+    ("STSTR_NCON", crate::english::STSTR_NCON),
+    ("STSTR_NCOFF", crate::english::STSTR_NCOFF),
+    ("STSTR_BEHOLD", crate::english::STSTR_BEHOLD),
+    ("STSTR_BEHOLDX", crate::english::STSTR_BEHOLDX),
+    ("STSTR_CHOPPERS", crate::english::STSTR_CHOPPERS),
+    ("STSTR_CLEV", crate::english::STSTR_CLEV),
+    ("E1TEXT", crate::english::E1TEXT),
+    ("E2TEXT", crate::english::E2TEXT),
+    ("E3TEXT", crate::english::E3TEXT),
+    ("E4TEXT", crate::english::E4TEXT),
+    ("C1TEXT", crate::english::C1TEXT),
+    ("C2TEXT", crate::english::C2TEXT),
+    ("C3TEXT", crate::english::C3TEXT),
+    ("C4TEXT", crate::english::C4TEXT),
+    ("C5TEXT", crate::english::C5TEXT),
+    ("C6TEXT", crate::english::C6TEXT),
+    ("P1TEXT", crate::english::P1TEXT),
+    ("P2TEXT", crate::english::P2TEXT),
+    ("P3TEXT", crate::english::P3TEXT),
+    ("P4TEXT", crate::english::P4TEXT),
+    ("P5TEXT", crate::english::P5TEXT),
+    ("P6TEXT", crate::english::P6TEXT),
+    ("T1TEXT", crate::english::T1TEXT),
+    ("T2TEXT", crate::english::T2TEXT),
+    ("T3TEXT", crate::english::T3TEXT),
+    ("T4TEXT", crate::english::T4TEXT),
+    ("T5TEXT", crate::english::T5TEXT),
+    ("T6TEXT", crate::english::T6TEXT),
+    ("CC_ZOMBIE", crate::english::CC_ZOMBIE),
+    ("CC_SHOTGUN", crate::english::CC_SHOTGUN),
+    ("CC_HEAVY", crate::english::CC_HEAVY),
+    ("CC_IMP", crate::english::CC_IMP),
+    ("CC_DEMON", crate::english::CC_DEMON),
+    ("CC_LOST", crate::english::CC_LOST),
+    ("CC_CACO", crate::english::CC_CACO),
+    ("CC_HELL", crate::english::CC_HELL),
+    ("CC_BARON", crate::english::CC_BARON),
+    ("CC_ARACH", crate::english::CC_ARACH),
+    ("CC_PAIN", crate::english::CC_PAIN),
+    ("CC_REVEN", crate::english::CC_REVEN),
+    ("CC_MANCU", crate::english::CC_MANCU),
+    ("CC_ARCH", crate::english::CC_ARCH),
+    ("CC_SPIDER", crate::english::CC_SPIDER),
+    ("CC_CYBER", crate::english::CC_CYBER),
+    ("CC_HERO", crate::english::CC_HERO),
+    ("BGFLATE1", "FLOOR4_8"),
+    ("BGFLATE2", "SFLR6_1"),
+    ("BGFLATE3", "MFLR8_4"),
+    ("BGFLATE4", "MFLR8_3"),
+    ("BGFLAT06", "SLIME16"),
+    ("BGFLAT11", "RROCK14"),
+    ("BGFLAT20", "RROCK07"),
+    ("BGFLAT30", "RROCK17"),
+    ("BGFLAT15", "RROCK13"),
+    ("BGFLAT31", "RROCK19"),
+    ("BGCASTCALL", "BOSSBACK"),
+    ("SAVEGAMENAME", "doomsav"),
+];
+
+impl super::Patch<'_> {
+    pub(super) fn parse_string_line(&mut self, line: &str) {
+        let (mnemonic, value) = match super::parse_assignment(line) {
+            Some(assignment) => assignment,
+            None => return self.warn(format!("failed to parse string '{}'", line.trim())),
+        };
+
+        self.pending_string = Some((mnemonic.to_string(), String::new()));
+        self.continue_string(value);
+    }
+
+    // A value ending in a backslash carries on to the next line.
+    pub(super) fn continue_string(&mut self, line: &str) {
+        let line = line.trim();
+        let (mnemonic, mut value) = match self.pending_string.take() {
+            Some(pending) => pending,
+            None => return,
+        };
+
+        match line.strip_suffix('\\') {
+            Some(start) => {
+                value.push_str(start);
+                self.pending_string = Some((mnemonic, value));
+            }
+            None => {
+                value.push_str(line);
+                self.replace_string(&mnemonic, &unescape(&value));
+            }
+        }
+    }
+
+    fn replace_string(&mut self, mnemonic: &str, value: &str) {
+        match MNEMONICS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(mnemonic))
+        {
+            Some((_, original)) => {
+                self.dehacked
+                    .strings
+                    .insert(original.to_string(), value.to_string());
+            }
+            None => self.warn(format!("unknown string mnemonic '{}'", mnemonic)),
+        }
+    }
+}
+
+fn unescape(value: &str) -> String {
+    let mut result = String::with_capacity(value.len());
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some(other) => result.push(other),
+            None => result.push('\\'),
+        }
+    }
+
+    result
+}
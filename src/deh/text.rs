@@ -0,0 +1,34 @@
+impl super::Patch<'_> {
+    // "Text <from length> <to length>" is followed by both strings back to
+    // back, newlines included, so they are read by length rather than by line.
+    pub(super) fn read_text(&mut self, line: &str, reader: &mut super::Reader) {
+        let lengths = line
+            .split_whitespace()
+            .skip(1)
+            .map(|w| w.parse::<usize>().ok())
+            .collect::<Option<Vec<_>>>();
+        let (from_length, to_length) = match lengths.as_deref() {
+            Some(&[from_length, to_length]) => (from_length, to_length),
+            _ => return self.warn(format!("invalid Text section header '{}'", line.trim())),
+        };
+
+        let from = reader.read_chars(from_length);
+        let to = reader.read_chars(to_length);
+
+        // doom.exe pads its strings to four bytes, which is all the room a
+        // replacement had there.
+        let max_length = ((from_length + 4) & !3) - 1;
+        if to_length > max_length {
+            self.warn(format!(
+                "replacement for '{}' is longer than vanilla allows ({} > {})",
+                from.escape_debug(),
+                to_length,
+                max_length
+            ));
+        }
+
+        if !self.no_text {
+            self.dehacked.strings.insert(from, to);
+        }
+    }
+}
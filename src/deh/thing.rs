@@ -0,0 +1,123 @@
+use crate::info::*;
+
+// BEX lets "Bits" name the flags instead of giving the number.
+const FLAG_NAMES: [(&str, i32); 29] = [
+    ("SPECIAL", MF_SPECIAL),
+    ("SOLID", MF_SOLID),
+    ("SHOOTABLE", MF_SHOOTABLE),
+    ("NOSECTOR", MF_NOSECTOR),
+    ("NOBLOCKMAP", MF_NOBLOCKMAP),
+    ("AMBUSH", MF_AMBUSH),
+    ("JUSTHIT", MF_JUSTHIT),
+    ("JUSTATTACKED", MF_JUSTATTACKED),
+    ("SPAWNCEILING", MF_SPAWNCEILING),
+    ("NOGRAVITY", MF_NOGRAVITY),
+    ("DROPOFF", MF_DROPOFF),
+    ("PICKUP", MF_PICKUP),
+    ("NOCLIP", MF_NOCLIP),
+    ("SLIDE", MF_SLIDE),
+    ("FLOAT", MF_FLOAT),
+    ("TELEPORT", MF_TELEPORT),
+    ("MISSILE", MF_MISSILE),
+    ("DROPPED", MF_DROPPED),
+    ("SHADOW", MF_SHADOW),
+    ("NOBLOOD", MF_NOBLOOD),
+    ("CORPSE", MF_CORPSE),
+    ("INFLOAT", MF_INFLOAT),
+    ("COUNTKILL", MF_COUNTKILL),
+    ("COUNTITEM", MF_COUNTITEM),
+    ("SKULLFLY", MF_SKULLFLY),
+    ("NOTDMATCH", MF_NOTDMATCH),
+    ("TRANSLATION1", 1 << MF_TRANSSHIFT),
+    ("TRANSLATION2", 2 << MF_TRANSSHIFT),
+    ("TRANSLATION", MF_TRANSLATION),
+];
+
+const STATE_FIELDS: [&str; 8] = [
+    "initial frame",
+    "first moving frame",
+    "injury frame",
+    "close attack frame",
+    "far attack frame",
+    "death frame",
+    "exploding frame",
+    "respawn frame",
+];
+
+const SOUND_FIELDS: [&str; 5] = [
+    "alert sound",
+    "attack sound",
+    "pain sound",
+    "death sound",
+    "action sound",
+];
+
+impl super::Patch<'_> {
+    pub(super) fn parse_thing_field(&mut self, thing: usize, field: &str, value: &str) {
+        let name = field.to_lowercase();
+
+        let value = if STATE_FIELDS.contains(&name.as_str()) {
+            self.parse_state(value).map(|v| v as i32)
+        } else if SOUND_FIELDS.contains(&name.as_str()) {
+            self.parse_sound(value).map(|v| v as i32)
+        } else if name == "bits" && value.starts_with(|c: char| c.is_ascii_alphabetic()) {
+            self.parse_flags(value)
+        } else {
+            self.parse_int(value)
+        };
+        let value = match value {
+            Some(value) => value,
+            None => return,
+        };
+
+        let mobj = &mut self.info.mobj_info[thing];
+        match name.as_str() {
+            "id #" => mobj.doomed_num = value,
+            "initial frame" => mobj.spawn_state = value as usize,
+            "hit points" => mobj.spawn_health = value,
+            "first moving frame" => mobj.see_state = value as usize,
+            "alert sound" => mobj.see_sound = value as usize,
+            "reaction time" => mobj.reaction_time = value,
+            "attack sound" => mobj.attack_sound = value as usize,
+            "injury frame" => mobj.pain_state = value as usize,
+            "pain chance" => mobj.pain_chance = value,
+            "pain sound" => mobj.pain_sound = value as usize,
+            "close attack frame" => mobj.melee_state = value as usize,
+            "far attack frame" => mobj.missile_state = value as usize,
+            "death frame" => mobj.death_state = value as usize,
+            "exploding frame" => mobj.xdeath_state = value as usize,
+            "death sound" => mobj.death_sound = value as usize,
+            "speed" => mobj.speed = value,
+            "width" => mobj.radius = crate::fixed::Fixed(value),
+            "height" => mobj.height = crate::fixed::Fixed(value),
+            "mass" => mobj.mass = value,
+            "missile damage" => mobj.damage = value,
+            "action sound" => mobj.active_sound = value as usize,
+            "bits" => mobj.flags = value,
+            "respawn frame" => mobj.raise_state = value as usize,
+            _ => self.unknown_field("Thing", field),
+        }
+    }
+
+    fn parse_flags(&mut self, value: &str) -> Option<i32> {
+        let mut flags = 0;
+
+        for name in value
+            .split(|c: char| c == '+' || c == '|' || c == ',' || c.is_whitespace())
+            .filter(|n| !n.is_empty())
+        {
+            match FLAG_NAMES
+                .iter()
+                .find(|(flag, _)| flag.eq_ignore_ascii_case(name))
+            {
+                Some((_, bits)) => flags |= bits,
+                None => {
+                    self.warn(format!("unknown thing flag '{}'", name));
+                    return None;
+                }
+            }
+        }
+
+        Some(flags)
+    }
+}
@@ -0,0 +1,46 @@
+const FIELDS: [&str; 6] = [
+    "ammo type",
+    "deselect frame",
+    "select frame",
+    "bobbing frame",
+    "shooting frame",
+    "firing frame",
+];
+
+impl super::Patch<'_> {
+    pub(super) fn parse_weapon_field(&mut self, weapon: usize, field: &str, value: &str) {
+        let name = field.to_lowercase();
+        if !FIELDS.contains(&name.as_str()) {
+            return self.unknown_field("Weapon", field);
+        }
+
+        let value = if name == "ammo type" {
+            match self.parse_int(value) {
+                Some(ammo)
+                    if (0..crate::items::NUM_AMMO as i32).contains(&ammo)
+                        || ammo == crate::items::AM_NOAMMO as i32 =>
+                {
+                    ammo as usize
+                }
+                Some(ammo) => return self.warn(format!("invalid ammo type {}", ammo)),
+                None => return,
+            }
+        } else {
+            match self.parse_state(value) {
+                Some(state) => state,
+                None => return,
+            }
+        };
+
+        let info = &mut self.info.weapon_info[weapon];
+        match name.as_str() {
+            "ammo type" => info.ammo = value,
+            "deselect frame" => info.up_state = value,
+            "select frame" => info.down_state = value,
+            "bobbing frame" => info.ready_state = value,
+            "shooting frame" => info.attack_state = value,
+            "firing frame" => info.flash_state = value,
+            _ => {}
+        }
+    }
+}
@@ -1,2 +1,4 @@
+pub mod doom_loop;
 pub mod iwad;
 pub mod main;
+pub mod version;
@@ -0,0 +1,290 @@
+pub const TICRATE: u32 = 35;
+pub const BACKUPTICS: usize = 128;
+
+// What the player asked for in one tic. Tics are simulated from these alone,
+// which is what lets demos and netgames replay them.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TicCmd {
+    pub forward_move: i8,
+    pub side_move: i8,
+    pub angle_turn: i16,
+    pub chat_char: u8,
+    pub buttons: u8,
+}
+
+pub trait Clock {
+    // Whole tics since the clock started.
+    fn tics(&mut self) -> u32;
+
+    // Gives time a chance to pass while waiting for the next tic.
+    fn sleep(&mut self);
+}
+
+// Wall clock time. Tics are counted from the start rather than from the last
+// tic, so they don't drift away from 35 a second.
+pub struct SystemClock {
+    start: std::time::Instant,
+}
+
+impl Default for SystemClock {
+    fn default() -> Self {
+        Self {
+            start: std::time::Instant::now(),
+        }
+    }
+}
+
+impl Clock for SystemClock {
+    fn tics(&mut self) -> u32 {
+        (self.start.elapsed().as_millis() * TICRATE as u128 / 1000) as u32
+    }
+
+    fn sleep(&mut self) {
+        std::thread::sleep(std::time::Duration::from_millis(1));
+    }
+}
+
+// A clock that only moves when it is waited on, one tic at a time, for
+// headless runs and tests. A stall can be faked by moving it on by hand.
+#[derive(Default)]
+pub struct StepClock {
+    pub now: u32,
+}
+
+impl Clock for StepClock {
+    fn tics(&mut self) -> u32 {
+        self.now
+    }
+
+    fn sleep(&mut self) {
+        self.now += 1;
+    }
+}
+
+pub trait TicHandler {
+    // Reads pending input into the command for the next tic.
+    fn build_tic(&mut self) -> TicCmd;
+
+    // Moves the game on by one tic.
+    fn run_tic(&mut self, tic: u32, cmd: &TicCmd);
+
+    // Draws a frame of however the game stands now.
+    fn render(&mut self);
+
+    fn quitting(&self) -> bool;
+}
+
+// The single player half of vanilla's NetUpdate and TryRunTics: commands are
+// built for every tic of time that has passed, and run in a batch before
+// the next frame is drawn.
+pub struct TicScheduler<C: Clock> {
+    pub clock: C,
+    commands: [TicCmd; BACKUPTICS],
+    made_tics: u32,
+    game_tic: u32,
+    last_time: u32,
+    old_enter_tics: u32,
+}
+
+impl<C: Clock> TicScheduler<C> {
+    pub fn new(mut clock: C) -> Self {
+        let now = clock.tics();
+        Self {
+            clock,
+            commands: [TicCmd::default(); BACKUPTICS],
+            made_tics: 0,
+            game_tic: 0,
+            last_time: now,
+            old_enter_tics: now,
+        }
+    }
+
+    pub fn game_tic(&self) -> u32 {
+        self.game_tic
+    }
+
+    // Commands are only kept for BACKUPTICS tics, so after a long stall the
+    // time past half of that is dropped rather than caught up on.
+    fn build_tics(&mut self, game: &mut impl TicHandler) {
+        let now = self.clock.tics();
+        let new_tics = now.wrapping_sub(self.last_time);
+        self.last_time = now;
+
+        for _ in 0..new_tics {
+            if self.made_tics - self.game_tic >= BACKUPTICS as u32 / 2 - 1 {
+                break;
+            }
+            self.commands[self.made_tics as usize % BACKUPTICS] = game.build_tic();
+            self.made_tics += 1;
+        }
+    }
+
+    // Runs as many tics as the time since the last frame calls for, and at
+    // least one, then draws a frame.
+    pub fn run_frame(&mut self, game: &mut impl TicHandler) {
+        let enter_tics = self.clock.tics();
+        let real_tics = enter_tics.wrapping_sub(self.old_enter_tics);
+        self.old_enter_tics = enter_tics;
+
+        self.build_tics(game);
+
+        let available = self.made_tics - self.game_tic;
+        let counts = if real_tics + 1 < available {
+            real_tics + 1
+        } else {
+            real_tics.min(available)
+        }
+        .max(1);
+
+        while self.made_tics < self.game_tic + counts {
+            self.clock.sleep();
+            self.build_tics(game);
+        }
+
+        for _ in 0..counts {
+            let cmd = self.commands[self.game_tic as usize % BACKUPTICS];
+            game.run_tic(self.game_tic, &cmd);
+            self.game_tic += 1;
+            if game.quitting() {
+                return;
+            }
+            self.build_tics(game);
+        }
+
+        game.render();
+    }
+
+    pub fn run(&mut self, game: &mut impl TicHandler) {
+        while !game.quitting() {
+            self.run_frame(game);
+        }
+    }
+}
+
+impl TicHandler for crate::state::State {
+    fn build_tic(&mut self) -> TicCmd {
+        TicCmd::default()
+    }
+
+    fn run_tic(&mut self, tic: u32, _cmd: &TicCmd) {
+        if let Some(level) = &mut self.level {
+            level.ticker(&self.info);
+        }
+        self.game_tic = tic + 1;
+    }
+
+    fn render(&mut self) {}
+
+    // Until there is a title screen to go back to, the game is over once
+    // there is no level to play.
+    fn quitting(&self) -> bool {
+        self.level.is_none()
+            || self
+                .max_tics
+                .map(|max| self.game_tic >= max)
+                .unwrap_or(false)
+    }
+}
+
+impl crate::state::State {
+    // D_DoomLoop. Headless runs step the clock instead of waiting for it, so
+    // they run as fast as they can and always the same way.
+    pub fn doom_loop(&mut self) {
+        if self.headless {
+            TicScheduler::new(StepClock::default()).run(self);
+        } else {
+            TicScheduler::new(SystemClock::default()).run(self);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Default)]
+    struct Recorder {
+        built: u32,
+        ran: Vec<(u32, i8)>,
+        frames: Vec<usize>,
+        quit_after: u32,
+    }
+
+    impl TicHandler for Recorder {
+        fn build_tic(&mut self) -> TicCmd {
+            self.built += 1;
+            TicCmd {
+                forward_move: self.built as i8,
+                ..TicCmd::default()
+            }
+        }
+
+        fn run_tic(&mut self, tic: u32, cmd: &TicCmd) {
+            self.ran.push((tic, cmd.forward_move));
+        }
+
+        fn render(&mut self) {
+            self.frames.push(self.ran.len());
+        }
+
+        fn quitting(&self) -> bool {
+            self.ran.len() as u32 >= self.quit_after
+        }
+    }
+
+    #[test]
+    fn steps_one_tic_a_frame() {
+        let mut game = Recorder {
+            quit_after: 5,
+            ..Recorder::default()
+        };
+        TicScheduler::new(StepClock::default()).run(&mut game);
+
+        assert_eq!(game.ran, [(0, 1), (1, 2), (2, 3), (3, 4), (4, 5)]);
+        assert_eq!(game.frames, [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn catches_up_after_a_stall() {
+        let mut game = Recorder {
+            quit_after: 100,
+            ..Recorder::default()
+        };
+        let mut scheduler = TicScheduler::new(StepClock::default());
+        scheduler.run_frame(&mut game);
+        scheduler.clock.now += 10;
+        scheduler.run_frame(&mut game);
+
+        assert_eq!(game.frames, [1, 11]);
+        assert_eq!(scheduler.game_tic(), 11);
+        // Every command is run once, in the order it was built.
+        assert!(game
+            .ran
+            .iter()
+            .enumerate()
+            .all(|(i, &(tic, cmd))| tic == i as u32 && cmd == i as i8 + 1));
+    }
+
+    #[test]
+    fn drops_time_past_the_backup() {
+        let mut game = Recorder {
+            quit_after: 1000,
+            ..Recorder::default()
+        };
+        let mut scheduler = TicScheduler::new(StepClock::default());
+        scheduler.clock.now += 500;
+        scheduler.run_frame(&mut game);
+        scheduler.run_frame(&mut game);
+
+        assert_eq!(game.built, BACKUPTICS as u32 / 2);
+        assert_eq!(game.frames, [BACKUPTICS / 2 - 1, BACKUPTICS / 2]);
+    }
+
+    #[test]
+    fn system_clock_runs_at_35hz() {
+        let mut clock = SystemClock {
+            start: std::time::Instant::now() - std::time::Duration::from_millis(1000),
+        };
+        assert_eq!(clock.tics(), TICRATE);
+    }
+}
@@ -2,7 +2,9 @@ pub trait IwadName {
     fn is_iwad_name(&self) -> bool;
 }
 
-enum GameMission {
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameMission {
+    None,
     Doom,
     Doom2,
     PackTnt,
@@ -11,7 +13,8 @@ enum GameMission {
     PackHacx,
 }
 
-enum GameMode {
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GameMode {
     Shareware,
     Registered,
     Commercial,
@@ -106,9 +109,287 @@ impl IwadName for String {
 }
 
 impl crate::state::State {
+    pub fn identify_version(&mut self) {
+        let (mission, mode) = identify_mission_and_mode(&self.wad)
+            .unwrap_or_else(|| self.error("Unknown or invalid IWAD file."));
+
+        self.game_mission = mission;
+        self.game_mode = mode;
+        self.game_description = identify_description(&self.wad, mission, mode);
+    }
+
+    // The IWAD's canonical file name, whatever the file on disk is called;
+    // Freedoom shares missions with Doom, so it goes by its description.
+    pub fn save_game_iwad_name(&self) -> &'static str {
+        let iwads = || (0..).map(iwads).take_while(|iwad| iwad.is_some()).flatten();
+
+        iwads()
+            .find(|iwad| iwad.description == self.game_description)
+            .or_else(|| iwads().find(|iwad| iwad.mission == self.game_mission))
+            .map(|iwad| iwad.name)
+            .unwrap_or("unknown.wad")
+    }
+
+    pub fn init_autoload_dirs(&mut self) {
+        if self.parm_exists("--noautoload") || self.game_mode == GameMode::Shareware {
+            return;
+        }
+
+        let mut dirs = vec![];
+        if self.game_mission != GameMission::PackChex && self.game_mission != GameMission::PackHacx
+        {
+            dirs.push(self.get_autoload_dir("doom-all"));
+        }
+        dirs.push(self.get_autoload_dir(self.save_game_iwad_name()));
+
+        self.autoload_dirs = dirs;
+    }
+
     pub fn find_iwad(&mut self) -> String {
+        let dirs = self.build_iwad_dir_list();
+
         if let Some(iwad_parm) = self.check_parm_with_args("--iwad", 1) {
-            self.iwad_file = self.args[iwad_parm + 1].clone();
+            let iwad_file = self.args[iwad_parm + 1].clone();
+            return try_find_wad_by_name(&iwad_file, &dirs).unwrap_or_else(|| {
+                print_search_report(&[iwad_file.as_str()], &dirs);
+                self.error(format!("IWAD file '{}' not found!", iwad_file))
+            });
+        }
+
+        let mut names = vec![];
+        for i in 0.. {
+            let iwad = match iwads(i) {
+                Some(iwad) => iwad,
+                None => break,
+            };
+
+            for dir in &dirs {
+                if let Some(path) = find_in_dir(dir, iwad.name) {
+                    return path;
+                }
+            }
+            names.push(iwad.name);
+        }
+
+        print_search_report(&names, &dirs);
+        self.error(
+            "Game mode indeterminate.  No IWAD file was found.  Try\n\
+             specifying one with the '--iwad' command line parameter.",
+        )
+    }
+
+    fn build_iwad_dir_list(&self) -> Vec<String> {
+        let mut dirs = vec![];
+
+        add_iwad_dir(&mut dirs, String::from("."));
+
+        if let Ok(dir) = std::env::var("DOOMWADDIR") {
+            add_iwad_dir(&mut dirs, dir);
+        }
+
+        if let Some(path) = std::env::var_os("DOOMWADPATH") {
+            for dir in std::env::split_paths(&path) {
+                add_iwad_dir(&mut dirs, dir.to_string_lossy().to_string());
+            }
+        }
+
+        if !cfg!(windows) {
+            let data_home = std::env::var("XDG_DATA_HOME")
+                .ok()
+                .filter(|d| !d.is_empty())
+                .or_else(|| std::env::var("HOME").ok().map(|h| h + "/.local/share"));
+            if let Some(data_home) = data_home {
+                add_iwad_dir(&mut dirs, data_home + "/games/doom");
+            }
+
+            let data_dirs = std::env::var("XDG_DATA_DIRS")
+                .ok()
+                .filter(|d| !d.is_empty())
+                .unwrap_or_else(|| String::from("/usr/local/share:/usr/share"));
+            for dir in data_dirs.split(':').filter(|d| !d.is_empty()) {
+                add_iwad_dir(&mut dirs, String::from(dir) + "/games/doom");
+            }
+        }
+
+        if !self.config_dir.is_empty() {
+            add_iwad_dir(&mut dirs, self.config_dir.clone());
+        }
+
+        dirs
+    }
+}
+
+fn identify_mission_and_mode(wad: &crate::wad::Wad) -> Option<(GameMission, GameMode)> {
+    let has_lump = |name| wad.check_num_for_name(name).is_some();
+
+    if has_lump("MAP01") {
+        let mission = if has_lump("HACX-R") {
+            GameMission::PackHacx
+        } else if has_lump("REDTNT2") {
+            GameMission::PackTnt
+        } else if has_lump("CAMO1") {
+            GameMission::PackPlut
+        } else {
+            GameMission::Doom2
+        };
+        Some((mission, GameMode::Commercial))
+    } else if has_lump("E1M1") {
+        if has_lump("W94_1") {
+            Some((GameMission::PackChex, GameMode::Retail))
+        } else if has_lump("E4M1") {
+            Some((GameMission::Doom, GameMode::Retail))
+        } else if has_lump("E3M1") {
+            Some((GameMission::Doom, GameMode::Registered))
+        } else {
+            Some((GameMission::Doom, GameMode::Shareware))
+        }
+    } else {
+        None
+    }
+}
+
+fn identify_description(wad: &crate::wad::Wad, mission: GameMission, mode: GameMode) -> String {
+    if wad.check_num_for_name("FREEDM").is_some() {
+        return String::from("FreeDM");
+    }
+
+    if wad.check_num_for_name("FREEDOOM").is_some() {
+        return String::from(if mission == GameMission::Doom {
+            "Freedoom: Phase 1"
+        } else {
+            "Freedoom: Phase 2"
+        });
+    }
+
+    if mission == GameMission::Doom && mode == GameMode::Registered {
+        return String::from("Doom Registered");
+    }
+
+    (0..)
+        .map(iwads)
+        .take_while(|iwad| iwad.is_some())
+        .flatten()
+        .find(|iwad| iwad.mission == mission && iwad.mode == mode)
+        .map(|iwad| iwad.description.to_owned())
+        .unwrap_or_else(|| String::from("Unknown game"))
+}
+
+fn add_iwad_dir(dirs: &mut Vec<String>, dir: String) {
+    if !dir.is_empty() && !dirs.contains(&dir) {
+        dirs.push(dir);
+    }
+}
+
+fn try_find_wad_by_name(name: &str, dirs: &[String]) -> Option<String> {
+    if std::path::Path::new(name).is_file() {
+        return Some(name.to_owned());
+    }
+
+    let file_name = std::path::Path::new(name)
+        .file_name()
+        .map(|f| f.to_string_lossy().to_string())
+        .unwrap_or_else(|| name.to_owned());
+
+    let mut candidates = vec![file_name.clone()];
+    if std::path::Path::new(&file_name).extension().is_none() {
+        candidates.push(file_name + ".wad");
+    }
+
+    candidates
+        .iter()
+        .find_map(|c| dirs.iter().find_map(|dir| find_in_dir(dir, c)))
+}
+
+fn find_in_dir(dir: &str, name: &str) -> Option<String> {
+    let dir_path = std::path::Path::new(dir);
+
+    // DOOMWADPATH entries may name an IWAD directly rather than a directory
+    if dir_path.is_file() {
+        return dir_path
+            .file_name()
+            .filter(|f| f.to_string_lossy().eq_ignore_ascii_case(name))
+            .map(|_| dir.to_owned());
+    }
+
+    std::fs::read_dir(dir_path)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .find(|entry| {
+            entry
+                .file_name()
+                .to_string_lossy()
+                .eq_ignore_ascii_case(name)
+                && entry.path().is_file()
+        })
+        .map(|entry| entry.path().to_string_lossy().to_string())
+}
+
+fn print_search_report(names: &[&str], dirs: &[String]) {
+    eprintln!("Searched for {} in:", names.join(", "));
+    for dir in dirs {
+        eprintln!("    {}", dir);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wad(names: &[&str]) -> crate::wad::Wad {
+        let mut wad = crate::wad::Wad::default();
+        for name in names {
+            wad.lumps.push(crate::wad::LumpInfo {
+                name: crate::wad::normalize_name(name.as_bytes()),
+                wad_file: 0,
+                position: 0,
+                size: 0,
+            });
+        }
+        wad.rebuild_lookup();
+        wad
+    }
+
+    #[test]
+    fn identifies_the_game_by_its_marker_lumps() {
+        use GameMission::{Doom, Doom2, PackChex, PackHacx, PackPlut, PackTnt};
+        use GameMode::{Commercial, Registered, Retail, Shareware};
+
+        type Case = (&'static [&'static str], Option<(GameMission, GameMode)>);
+        let cases: &[Case] = &[
+            (&["MAP01"], Some((Doom2, Commercial))),
+            (&["MAP01", "HACX-R"], Some((PackHacx, Commercial))),
+            (&["MAP01", "REDTNT2"], Some((PackTnt, Commercial))),
+            (&["MAP01", "CAMO1"], Some((PackPlut, Commercial))),
+            // Maps from both games are a Doom II IWAD.
+            (&["E1M1", "MAP01"], Some((Doom2, Commercial))),
+            (&["E1M1"], Some((Doom, Shareware))),
+            (&["E1M1", "E3M1"], Some((Doom, Registered))),
+            (&["E1M1", "E3M1", "E4M1"], Some((Doom, Retail))),
+            (&["E1M1", "W94_1"], Some((PackChex, Retail))),
+            (&["PLAYPAL"], None),
+        ];
+        for (names, expected) in cases {
+            assert_eq!(
+                identify_mission_and_mode(&wad(names)),
+                *expected,
+                "{:?}",
+                names
+            );
+        }
+    }
+
+    #[test]
+    fn describes_freedoom_by_its_marker_lumps() {
+        let cases: &[(&[&str], &str)] = &[
+            (&["E1M1", "E4M1", "FREEDOOM"], "Freedoom: Phase 1"),
+            (&["MAP01", "FREEDOOM"], "Freedoom: Phase 2"),
+            (&["MAP01", "FREEDM"], "FreeDM"),
+            (&["E1M1", "E3M1"], "Doom Registered"),
+        ];
+        for (names, expected) in cases {
+            let wad = wad(names);
+            let (mission, mode) = identify_mission_and_mode(&wad).unwrap();
+            assert_eq!(identify_description(&wad, mission, mode), *expected);
         }
     }
 }
@@ -29,6 +29,18 @@ impl crate::state::State {
         self.respawn_parm = self.parm_exists("--respawn");
         self.fast_parm = self.parm_exists("--fast");
         self.dev_parm = self.parm_exists("--dev-parm");
+        self.rebuild_blockmap_parm = self.parm_exists("--rebuild-blockmap");
+        self.headless = self.parm_exists("--headless");
+        if let Some(p) = self.check_parm_with_args("--max-tics", 1) {
+            let max_tics = self.args[p + 1]
+                .parse()
+                .unwrap_or_else(|_| self.error("--max-tics takes a number of tics"));
+            self.max_tics = Some(max_tics);
+        }
+        // Nothing else would stop a headless run.
+        if self.headless && self.max_tics.is_none() {
+            self.error("--headless needs --max-tics");
+        }
         self.display_fps_dots = self.dev_parm;
 
         if self.parm_exists("--deathmatch") {
@@ -75,6 +87,65 @@ impl crate::state::State {
         self.at_exit(Self::save_defaults, false);
 
         self.iwad_file = self.find_iwad();
+
+        let iwad_file = self.iwad_file.clone();
+        if !self.add_wad_file(&iwad_file) {
+            self.error(format!("Failed to load IWAD '{}'", iwad_file));
+        }
+
+        self.identify_version();
+        crate::strings::print_startup_banner(&self.game_description);
+
+        // Autoloaded files go first, so what is named on the command line
+        // overrides them.
+        self.init_autoload_dirs();
+        self.autoload_wads();
+        self.modified_game = self.parse_command_line_wads();
+
+        if let Some(p) = self.check_parm("--wad-hashes") {
+            let list_lumps = self.args.get(p + 1).map(|a| a == "lumps").unwrap_or(false);
+            self.wad_hashes_mode(list_lumps);
+        }
+
+        self.load_dehacked();
+
+        if let Some(p) = self.check_parm_with_args("--dump-substconfig", 1) {
+            self.dump_subst_config(&self.args[p + 1].clone());
+        }
+
+        if let Some(p) = self.check_parm_with_args("--map-info", 1) {
+            let json = self.args.get(p + 2).map(|a| a == "json").unwrap_or(false);
+            self.map_info_mode(&self.args[p + 1].clone(), json);
+        }
+
+        self.init_game_version();
+        self.print_game_version();
+
+        let snd_cachesize = self.sound_options.snd_cachesize.as_int().unwrap_or(0);
+        self.lump_cache
+            .sounds
+            .set_budget(snd_cachesize.max(0) as usize);
+
+        self.init_music();
+
+        let skill = match self.check_parm_with_args("--skill", 1) {
+            Some(p) => self.args[p + 1]
+                .parse()
+                .ok()
+                .and_then(crate::play::Skill::from_number)
+                .unwrap_or_else(|| self.error("--skill takes a number from 1 to 5")),
+            None => crate::play::Skill::Medium,
+        };
+        if let Some(p) = self.check_parm_with_args("--warp", 1) {
+            let map = self.warp_map(p);
+            self.init_new(skill, &map);
+        }
+
+        self.doom_loop();
+
+        if self.dev_parm {
+            self.print_cache_statistics();
+        }
     }
 
     fn endoom(&mut self) {
@@ -0,0 +1,167 @@
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum GameVersion {
+    Doom1_2,
+    Doom1_5,
+    Doom1_666,
+    Doom1_7,
+    Doom1_8,
+    Doom1_9,
+    Hacx,
+    Ultimate,
+    Final,
+    Final2,
+    Chex,
+}
+
+struct GameVersionInfo {
+    description: &'static str,
+    cmdline: &'static str,
+    version: GameVersion,
+}
+
+const GAME_VERSIONS: [GameVersionInfo; 11] = [
+    GameVersionInfo {
+        description: "Doom 1.2",
+        cmdline: "1.2",
+        version: GameVersion::Doom1_2,
+    },
+    GameVersionInfo {
+        description: "Doom 1.5",
+        cmdline: "1.5",
+        version: GameVersion::Doom1_5,
+    },
+    GameVersionInfo {
+        description: "Doom 1.666",
+        cmdline: "1.666",
+        version: GameVersion::Doom1_666,
+    },
+    GameVersionInfo {
+        description: "Doom 1.7/1.7a",
+        cmdline: "1.7",
+        version: GameVersion::Doom1_7,
+    },
+    GameVersionInfo {
+        description: "Doom 1.8",
+        cmdline: "1.8",
+        version: GameVersion::Doom1_8,
+    },
+    GameVersionInfo {
+        description: "Doom 1.9",
+        cmdline: "1.9",
+        version: GameVersion::Doom1_9,
+    },
+    GameVersionInfo {
+        description: "Hacx",
+        cmdline: "hacx",
+        version: GameVersion::Hacx,
+    },
+    GameVersionInfo {
+        description: "Ultimate Doom",
+        cmdline: "ultimate",
+        version: GameVersion::Ultimate,
+    },
+    GameVersionInfo {
+        description: "Final Doom",
+        cmdline: "final",
+        version: GameVersion::Final,
+    },
+    GameVersionInfo {
+        description: "Final Doom (alt)",
+        cmdline: "final2",
+        version: GameVersion::Final2,
+    },
+    GameVersionInfo {
+        description: "Chex Quest",
+        cmdline: "chex",
+        version: GameVersion::Chex,
+    },
+];
+
+impl GameVersion {
+    pub fn description(self) -> &'static str {
+        GAME_VERSIONS
+            .iter()
+            .find(|info| info.version == self)
+            .map(|info| info.description)
+            .unwrap_or("unknown")
+    }
+}
+
+impl crate::state::State {
+    pub fn init_game_version(&mut self) {
+        use super::iwad::GameMission;
+        use super::iwad::GameMode;
+
+        if let Some(p) = self.check_parm_with_args("--game-version", 1) {
+            let cmdline = &self.args[p + 1];
+            match GAME_VERSIONS.iter().find(|info| info.cmdline == cmdline) {
+                Some(info) => self.game_version = info.version,
+                None => {
+                    println!("Supported game versions:");
+                    for info in GAME_VERSIONS.iter() {
+                        println!("\t{} ({})", info.cmdline, info.description);
+                    }
+                    self.error(format!("Unknown game version '{}'", cmdline));
+                }
+            }
+        } else if self.game_mission == GameMission::PackChex {
+            self.game_version = GameVersion::Chex;
+        } else if self.game_mission == GameMission::PackHacx {
+            self.game_version = GameVersion::Hacx;
+        } else if self.game_mode == GameMode::Shareware
+            || self.game_mode == GameMode::Registered
+            || (self.game_mode == GameMode::Commercial && self.game_mission == GameMission::Doom2)
+        {
+            self.game_version = self.demo_game_version().unwrap_or(GameVersion::Doom1_9);
+        } else if self.game_mode == GameMode::Retail {
+            self.game_version = GameVersion::Ultimate;
+        } else if self.game_mode == GameMode::Commercial {
+            // Default to the first Final Doom executable, which has the crash
+            // in the demo loop but plays back most demos correctly.
+            self.game_version = GameVersion::Final;
+        }
+
+        // Deathmatch 2.0 did not exist until Doom v1.4
+        if self.game_version <= GameVersion::Doom1_2
+            && self.multiplayer_mode == crate::types::MultiplayerMode::AltDeathmatch
+        {
+            self.multiplayer_mode = crate::types::MultiplayerMode::Deathmatch;
+        }
+
+        // The original exe does not support retail - 4th episode not supported
+        if self.game_version < GameVersion::Ultimate && self.game_mode == GameMode::Retail {
+            self.game_mode = GameMode::Registered;
+        }
+
+        // EXEs prior to the Final Doom exes do not support Final Doom.
+        if self.game_version < GameVersion::Final
+            && self.game_mode == GameMode::Commercial
+            && (self.game_mission == GameMission::PackTnt
+                || self.game_mission == GameMission::PackPlut)
+        {
+            self.game_mission = GameMission::Doom2;
+        }
+    }
+
+    pub fn print_game_version(&self) {
+        println!(
+            "Emulating the behavior of the '{}' executable.",
+            self.game_version.description()
+        );
+    }
+
+    fn demo_game_version(&self) -> Option<GameVersion> {
+        (1..=3).find_map(|i| {
+            let lump = self.wad.check_num_for_name(&format!("DEMO{}", i))?;
+            let demo_version = *self.wad.lump_data(lump).ok()?.first()?;
+            match demo_version {
+                0..=4 => Some(GameVersion::Doom1_2),
+                106 => Some(GameVersion::Doom1_666),
+                107 => Some(GameVersion::Doom1_7),
+                108 => Some(GameVersion::Doom1_8),
+                109 => Some(GameVersion::Doom1_9),
+                _ => None,
+            }
+        })
+    }
+}
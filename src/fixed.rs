@@ -1 +1,298 @@
-pub type Fixed = i32;
+use std::ops::{Add, AddAssign, Div, Mul, Neg, Sub, SubAssign};
+
+pub const FRACBITS: i32 = 16;
+pub const FRACUNIT: Fixed = Fixed(1 << FRACBITS);
+
+// A 16.16 fixed point number. Arithmetic wraps on overflow like the C int
+// math vanilla relies on, since demos only stay in sync if every result is
+// the same down to the last bit.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed(pub i32);
+
+impl Fixed {
+    pub const MAX: Fixed = Fixed(i32::MAX);
+    pub const MIN: Fixed = Fixed(i32::MIN);
+
+    pub const fn from_int(n: i32) -> Self {
+        Fixed(n << FRACBITS)
+    }
+
+    // Rounds towards negative infinity, as an arithmetic shift does.
+    pub const fn to_int(self) -> i32 {
+        self.0 >> FRACBITS
+    }
+
+    pub fn from_f64(f: f64) -> Self {
+        Fixed((f * FRACUNIT.0 as f64).round() as i32)
+    }
+
+    pub fn to_f64(self) -> f64 {
+        self.0 as f64 / FRACUNIT.0 as f64
+    }
+
+    pub fn abs(self) -> Self {
+        Fixed(self.0.wrapping_abs())
+    }
+
+    // FixedMul: the 64 bit product, with the high bits that don't fit cut off.
+    pub fn fixed_mul(self, other: Fixed) -> Self {
+        Fixed(((self.0 as i64 * other.0 as i64) >> FRACBITS) as i32)
+    }
+
+    // FixedDiv: results that would overflow are clamped to the largest value
+    // with the right sign. abs(INT_MIN) stays negative in C, so the check lets
+    // it through to the division; dividing it by zero, which crashes vanilla,
+    // clamps instead.
+    pub fn fixed_div(self, other: Fixed) -> Self {
+        let (a, b) = (self.0, other.0);
+        if (a.wrapping_abs() >> 14) >= b.wrapping_abs() || b == 0 {
+            if (a ^ b) < 0 {
+                Fixed::MIN
+            } else {
+                Fixed::MAX
+            }
+        } else {
+            Fixed((((a as i64) << FRACBITS) / b as i64) as i32)
+        }
+    }
+}
+
+impl From<i32> for Fixed {
+    fn from(n: i32) -> Self {
+        Fixed::from_int(n)
+    }
+}
+
+impl From<Fixed> for f64 {
+    fn from(f: Fixed) -> Self {
+        f.to_f64()
+    }
+}
+
+impl std::fmt::Display for Fixed {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_f64())
+    }
+}
+
+impl Add for Fixed {
+    type Output = Fixed;
+
+    fn add(self, other: Fixed) -> Fixed {
+        Fixed(self.0.wrapping_add(other.0))
+    }
+}
+
+impl AddAssign for Fixed {
+    fn add_assign(&mut self, other: Fixed) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Fixed {
+    type Output = Fixed;
+
+    fn sub(self, other: Fixed) -> Fixed {
+        Fixed(self.0.wrapping_sub(other.0))
+    }
+}
+
+impl SubAssign for Fixed {
+    fn sub_assign(&mut self, other: Fixed) {
+        *self = *self - other;
+    }
+}
+
+impl Neg for Fixed {
+    type Output = Fixed;
+
+    fn neg(self) -> Fixed {
+        Fixed(self.0.wrapping_neg())
+    }
+}
+
+impl Mul for Fixed {
+    type Output = Fixed;
+
+    fn mul(self, other: Fixed) -> Fixed {
+        self.fixed_mul(other)
+    }
+}
+
+impl Div for Fixed {
+    type Output = Fixed;
+
+    fn div(self, other: Fixed) -> Fixed {
+        self.fixed_div(other)
+    }
+}
+
+// Scaling by a plain integer is ordinary int math, as in vanilla.
+impl Mul<i32> for Fixed {
+    type Output = Fixed;
+
+    fn mul(self, n: i32) -> Fixed {
+        Fixed(self.0.wrapping_mul(n))
+    }
+}
+
+impl Mul<Fixed> for i32 {
+    type Output = Fixed;
+
+    fn mul(self, f: Fixed) -> Fixed {
+        f * self
+    }
+}
+
+impl Div<i32> for Fixed {
+    type Output = Fixed;
+
+    fn div(self, n: i32) -> Fixed {
+        Fixed(self.0.wrapping_div(n))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // References for FixedMul and FixedDiv worked out with exact 128 bit
+    // arithmetic, rather than with the shifts and casts the code uses.
+
+    // Wraps an exact result to 32 bits, as the C cast to int does.
+    fn wrap(n: i128) -> i32 {
+        n.rem_euclid(1 << 32) as u32 as i32
+    }
+
+    // The exact product in 16.16, rounded down as the shift does.
+    fn reference_mul(a: i32, b: i32) -> i32 {
+        wrap((a as i128 * b as i128).div_euclid(1 << 16))
+    }
+
+    // The exact quotient rounded towards zero, unless vanilla's guard
+    // clamps it: that is whenever |a| / 2^14 rounded down is at least |b|,
+    // so for any quotient of 2^30 or more. C's abs() leaves INT_MIN
+    // negative, so a dividend of INT_MIN never clamps and its quotient
+    // wraps. Dividing by zero crashes vanilla; here it clamps by the sign
+    // of the dividend.
+    fn reference_div(a: i32, b: i32) -> i32 {
+        let c_abs = |n: i32| {
+            if n == i32::MIN {
+                n as i128
+            } else {
+                (n as i128).abs()
+            }
+        };
+        let clamped = if (a < 0) != (b < 0) {
+            i32::MIN
+        } else {
+            i32::MAX
+        };
+
+        if b == 0 || c_abs(a).div_euclid(1 << 14) >= c_abs(b) {
+            clamped
+        } else {
+            wrap((a as i128 * (1 << 16)) / b as i128)
+        }
+    }
+
+    // A fixed xorshift sequence, mixed with the values most likely to go
+    // wrong, so failures are repeatable.
+    fn values() -> Vec<i32> {
+        let mut values = vec![
+            0,
+            1,
+            -1,
+            FRACUNIT.0,
+            -FRACUNIT.0,
+            i32::MAX,
+            i32::MIN,
+            i32::MIN + 1,
+            0x4000,
+            -0x4000,
+            0x10_0000,
+        ];
+        let mut state = 0x2545_f491_u32;
+        for _ in 0..2000 {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            // Shift some values down so small numbers are covered too.
+            values.push((state as i32) >> (state % 31));
+        }
+        values
+    }
+
+    #[test]
+    fn mul_matches_vanilla() {
+        let values = values();
+        for &a in &values {
+            for &b in values.iter().step_by(7) {
+                assert_eq!(
+                    (Fixed(a) * Fixed(b)).0,
+                    reference_mul(a, b),
+                    "{} * {}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn div_matches_vanilla() {
+        let values = values();
+        for &a in &values {
+            for &b in values.iter().step_by(7) {
+                assert_eq!(
+                    (Fixed(a) / Fixed(b)).0,
+                    reference_div(a, b),
+                    "{} / {}",
+                    a,
+                    b
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn div_only_clamps_big_quotients() {
+        let values = values();
+        for &a in values.iter().filter(|&&a| a != i32::MIN) {
+            for &b in values.iter().step_by(7).filter(|&&b| b != 0) {
+                let exact = (a as i128 * (1 << 16)) / b as i128;
+                let result = (Fixed(a) / Fixed(b)).0 as i128;
+                if exact.abs() < 1 << 30 {
+                    assert_eq!(result, exact, "{} / {}", a, b);
+                } else {
+                    assert_eq!(result.signum(), exact.signum(), "{} / {}", a, b);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn div_clamps_overflow() {
+        assert_eq!(Fixed::from_int(0x4000) / Fixed(1), Fixed::MAX);
+        assert_eq!(Fixed::from_int(-0x4000) / Fixed(1), Fixed::MIN);
+        assert_eq!(FRACUNIT / Fixed(0), Fixed::MAX);
+        assert_eq!(Fixed::MIN / Fixed(0), Fixed::MIN);
+    }
+
+    #[test]
+    fn conversions() {
+        assert_eq!(Fixed::from_int(3), Fixed(3 << 16));
+        assert_eq!(Fixed::from_int(-3).to_int(), -3);
+        assert_eq!(Fixed(-1).to_int(), -1);
+        assert_eq!(Fixed::from_f64(1.5), Fixed(0x18000));
+        assert_eq!(Fixed(0x18000).to_f64(), 1.5);
+        assert_eq!(Fixed::from(2), 2 * FRACUNIT);
+    }
+
+    #[test]
+    fn wraps_like_c() {
+        assert_eq!(Fixed::MAX + Fixed(1), Fixed::MIN);
+        assert_eq!(-Fixed::MIN, Fixed::MIN);
+        assert_eq!(Fixed(25) * 150 / 100, Fixed(37));
+    }
+}
@@ -0,0 +1,6320 @@
+use crate::fixed::{Fixed, FRACBITS};
+
+pub const FF_FULLBRIGHT: i32 = 0x8000;
+pub const FF_FRAMEMASK: i32 = 0x7fff;
+
+pub const MF_SPECIAL: i32 = 0x1;
+pub const MF_SOLID: i32 = 0x2;
+pub const MF_SHOOTABLE: i32 = 0x4;
+pub const MF_NOSECTOR: i32 = 0x8;
+pub const MF_NOBLOCKMAP: i32 = 0x10;
+pub const MF_AMBUSH: i32 = 0x20;
+pub const MF_JUSTHIT: i32 = 0x40;
+pub const MF_JUSTATTACKED: i32 = 0x80;
+pub const MF_SPAWNCEILING: i32 = 0x100;
+pub const MF_NOGRAVITY: i32 = 0x200;
+pub const MF_DROPOFF: i32 = 0x400;
+pub const MF_PICKUP: i32 = 0x800;
+pub const MF_NOCLIP: i32 = 0x1000;
+pub const MF_SLIDE: i32 = 0x2000;
+pub const MF_FLOAT: i32 = 0x4000;
+pub const MF_TELEPORT: i32 = 0x8000;
+pub const MF_MISSILE: i32 = 0x10000;
+pub const MF_DROPPED: i32 = 0x20000;
+pub const MF_SHADOW: i32 = 0x40000;
+pub const MF_NOBLOOD: i32 = 0x80000;
+pub const MF_CORPSE: i32 = 0x100000;
+pub const MF_INFLOAT: i32 = 0x200000;
+pub const MF_COUNTKILL: i32 = 0x400000;
+pub const MF_COUNTITEM: i32 = 0x800000;
+pub const MF_SKULLFLY: i32 = 0x1000000;
+pub const MF_NOTDMATCH: i32 = 0x2000000;
+pub const MF_TRANSLATION: i32 = 0xc000000;
+pub const MF_TRANSSHIFT: i32 = 26;
+
+pub const NUM_SPRITES: usize = 138;
+
+pub const SPR_TROO: usize = 0;
+pub const SPR_SHTG: usize = 1;
+pub const SPR_PUNG: usize = 2;
+pub const SPR_PISG: usize = 3;
+pub const SPR_PISF: usize = 4;
+pub const SPR_SHTF: usize = 5;
+pub const SPR_SHT2: usize = 6;
+pub const SPR_CHGG: usize = 7;
+pub const SPR_CHGF: usize = 8;
+pub const SPR_MISG: usize = 9;
+pub const SPR_MISF: usize = 10;
+pub const SPR_SAWG: usize = 11;
+pub const SPR_PLSG: usize = 12;
+pub const SPR_PLSF: usize = 13;
+pub const SPR_BFGG: usize = 14;
+pub const SPR_BFGF: usize = 15;
+pub const SPR_BLUD: usize = 16;
+pub const SPR_PUFF: usize = 17;
+pub const SPR_BAL1: usize = 18;
+pub const SPR_BAL2: usize = 19;
+pub const SPR_PLSS: usize = 20;
+pub const SPR_PLSE: usize = 21;
+pub const SPR_MISL: usize = 22;
+pub const SPR_BFS1: usize = 23;
+pub const SPR_BFE1: usize = 24;
+pub const SPR_BFE2: usize = 25;
+pub const SPR_TFOG: usize = 26;
+pub const SPR_IFOG: usize = 27;
+pub const SPR_PLAY: usize = 28;
+pub const SPR_POSS: usize = 29;
+pub const SPR_SPOS: usize = 30;
+pub const SPR_VILE: usize = 31;
+pub const SPR_FIRE: usize = 32;
+pub const SPR_FATB: usize = 33;
+pub const SPR_FBXP: usize = 34;
+pub const SPR_SKEL: usize = 35;
+pub const SPR_MANF: usize = 36;
+pub const SPR_FATT: usize = 37;
+pub const SPR_CPOS: usize = 38;
+pub const SPR_SARG: usize = 39;
+pub const SPR_HEAD: usize = 40;
+pub const SPR_BAL7: usize = 41;
+pub const SPR_BOSS: usize = 42;
+pub const SPR_BOS2: usize = 43;
+pub const SPR_SKUL: usize = 44;
+pub const SPR_SPID: usize = 45;
+pub const SPR_BSPI: usize = 46;
+pub const SPR_APLS: usize = 47;
+pub const SPR_APBX: usize = 48;
+pub const SPR_CYBR: usize = 49;
+pub const SPR_PAIN: usize = 50;
+pub const SPR_SSWV: usize = 51;
+pub const SPR_KEEN: usize = 52;
+pub const SPR_BBRN: usize = 53;
+pub const SPR_BOSF: usize = 54;
+pub const SPR_ARM1: usize = 55;
+pub const SPR_ARM2: usize = 56;
+pub const SPR_BAR1: usize = 57;
+pub const SPR_BEXP: usize = 58;
+pub const SPR_FCAN: usize = 59;
+pub const SPR_BON1: usize = 60;
+pub const SPR_BON2: usize = 61;
+pub const SPR_BKEY: usize = 62;
+pub const SPR_RKEY: usize = 63;
+pub const SPR_YKEY: usize = 64;
+pub const SPR_BSKU: usize = 65;
+pub const SPR_RSKU: usize = 66;
+pub const SPR_YSKU: usize = 67;
+pub const SPR_STIM: usize = 68;
+pub const SPR_MEDI: usize = 69;
+pub const SPR_SOUL: usize = 70;
+pub const SPR_PINV: usize = 71;
+pub const SPR_PSTR: usize = 72;
+pub const SPR_PINS: usize = 73;
+pub const SPR_MEGA: usize = 74;
+pub const SPR_SUIT: usize = 75;
+pub const SPR_PMAP: usize = 76;
+pub const SPR_PVIS: usize = 77;
+pub const SPR_CLIP: usize = 78;
+pub const SPR_AMMO: usize = 79;
+pub const SPR_ROCK: usize = 80;
+pub const SPR_BROK: usize = 81;
+pub const SPR_CELL: usize = 82;
+pub const SPR_CELP: usize = 83;
+pub const SPR_SHEL: usize = 84;
+pub const SPR_SBOX: usize = 85;
+pub const SPR_BPAK: usize = 86;
+pub const SPR_BFUG: usize = 87;
+pub const SPR_MGUN: usize = 88;
+pub const SPR_CSAW: usize = 89;
+pub const SPR_LAUN: usize = 90;
+pub const SPR_PLAS: usize = 91;
+pub const SPR_SHOT: usize = 92;
+pub const SPR_SGN2: usize = 93;
+pub const SPR_COLU: usize = 94;
+pub const SPR_SMT2: usize = 95;
+pub const SPR_GOR1: usize = 96;
+pub const SPR_POL2: usize = 97;
+pub const SPR_POL5: usize = 98;
+pub const SPR_POL4: usize = 99;
+pub const SPR_POL3: usize = 100;
+pub const SPR_POL1: usize = 101;
+pub const SPR_POL6: usize = 102;
+pub const SPR_GOR2: usize = 103;
+pub const SPR_GOR3: usize = 104;
+pub const SPR_GOR4: usize = 105;
+pub const SPR_GOR5: usize = 106;
+pub const SPR_SMIT: usize = 107;
+pub const SPR_COL1: usize = 108;
+pub const SPR_COL2: usize = 109;
+pub const SPR_COL3: usize = 110;
+pub const SPR_COL4: usize = 111;
+pub const SPR_CAND: usize = 112;
+pub const SPR_CBRA: usize = 113;
+pub const SPR_COL6: usize = 114;
+pub const SPR_TRE1: usize = 115;
+pub const SPR_TRE2: usize = 116;
+pub const SPR_ELEC: usize = 117;
+pub const SPR_CEYE: usize = 118;
+pub const SPR_FSKU: usize = 119;
+pub const SPR_COL5: usize = 120;
+pub const SPR_TBLU: usize = 121;
+pub const SPR_TGRN: usize = 122;
+pub const SPR_TRED: usize = 123;
+pub const SPR_SMBT: usize = 124;
+pub const SPR_SMGT: usize = 125;
+pub const SPR_SMRT: usize = 126;
+pub const SPR_HDB1: usize = 127;
+pub const SPR_HDB2: usize = 128;
+pub const SPR_HDB3: usize = 129;
+pub const SPR_HDB4: usize = 130;
+pub const SPR_HDB5: usize = 131;
+pub const SPR_HDB6: usize = 132;
+pub const SPR_POB1: usize = 133;
+pub const SPR_POB2: usize = 134;
+pub const SPR_BRS1: usize = 135;
+pub const SPR_TLMP: usize = 136;
+pub const SPR_TLP2: usize = 137;
+
+pub const SPRITE_NAMES: [&str; NUM_SPRITES] = [
+    "TROO", "SHTG", "PUNG", "PISG", "PISF", "SHTF", "SHT2", "CHGG", "CHGF", "MISG", "MISF", "SAWG",
+    "PLSG", "PLSF", "BFGG", "BFGF", "BLUD", "PUFF", "BAL1", "BAL2", "PLSS", "PLSE", "MISL", "BFS1",
+    "BFE1", "BFE2", "TFOG", "IFOG", "PLAY", "POSS", "SPOS", "VILE", "FIRE", "FATB", "FBXP", "SKEL",
+    "MANF", "FATT", "CPOS", "SARG", "HEAD", "BAL7", "BOSS", "BOS2", "SKUL", "SPID", "BSPI", "APLS",
+    "APBX", "CYBR", "PAIN", "SSWV", "KEEN", "BBRN", "BOSF", "ARM1", "ARM2", "BAR1", "BEXP", "FCAN",
+    "BON1", "BON2", "BKEY", "RKEY", "YKEY", "BSKU", "RSKU", "YSKU", "STIM", "MEDI", "SOUL", "PINV",
+    "PSTR", "PINS", "MEGA", "SUIT", "PMAP", "PVIS", "CLIP", "AMMO", "ROCK", "BROK", "CELL", "CELP",
+    "SHEL", "SBOX", "BPAK", "BFUG", "MGUN", "CSAW", "LAUN", "PLAS", "SHOT", "SGN2", "COLU", "SMT2",
+    "GOR1", "POL2", "POL5", "POL4", "POL3", "POL1", "POL6", "GOR2", "GOR3", "GOR4", "GOR5", "SMIT",
+    "COL1", "COL2", "COL3", "COL4", "CAND", "CBRA", "COL6", "TRE1", "TRE2", "ELEC", "CEYE", "FSKU",
+    "COL5", "TBLU", "TGRN", "TRED", "SMBT", "SMGT", "SMRT", "HDB1", "HDB2", "HDB3", "HDB4", "HDB5",
+    "HDB6", "POB1", "POB2", "BRS1", "TLMP", "TLP2",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    None,
+    Light0,
+    WeaponReady,
+    Lower,
+    Raise,
+    Punch,
+    ReFire,
+    FirePistol,
+    Light1,
+    FireShotgun,
+    Light2,
+    FireShotgun2,
+    CheckReload,
+    OpenShotgun2,
+    LoadShotgun2,
+    CloseShotgun2,
+    FireCGun,
+    GunFlash,
+    FireMissile,
+    Saw,
+    FirePlasma,
+    BFGsound,
+    FireBFG,
+    BFGSpray,
+    Explode,
+    Pain,
+    PlayerScream,
+    Fall,
+    XScream,
+    Look,
+    Chase,
+    FaceTarget,
+    PosAttack,
+    Scream,
+    SPosAttack,
+    VileChase,
+    VileStart,
+    VileTarget,
+    VileAttack,
+    StartFire,
+    Fire,
+    FireCrackle,
+    Tracer,
+    SkelWhoosh,
+    SkelFist,
+    SkelMissile,
+    FatRaise,
+    FatAttack1,
+    FatAttack2,
+    FatAttack3,
+    BossDeath,
+    CPosAttack,
+    CPosRefire,
+    TroopAttack,
+    SargAttack,
+    HeadAttack,
+    BruisAttack,
+    SkullAttack,
+    Metal,
+    SpidRefire,
+    BabyMetal,
+    BspiAttack,
+    Hoof,
+    CyberAttack,
+    PainAttack,
+    PainDie,
+    KeenDie,
+    BrainPain,
+    BrainScream,
+    BrainDie,
+    BrainAwake,
+    BrainSpit,
+    SpawnSound,
+    SpawnFly,
+    BrainExplode,
+}
+
+pub const NUM_ACTIONS: usize = 75;
+
+// Names as BEX [CODEPTR] blocks spell them, i.e. the A_ function names
+// without the prefix.
+pub const ACTION_NAMES: [(Action, &str); NUM_ACTIONS] = [
+    (Action::None, "NULL"),
+    (Action::Light0, "Light0"),
+    (Action::WeaponReady, "WeaponReady"),
+    (Action::Lower, "Lower"),
+    (Action::Raise, "Raise"),
+    (Action::Punch, "Punch"),
+    (Action::ReFire, "ReFire"),
+    (Action::FirePistol, "FirePistol"),
+    (Action::Light1, "Light1"),
+    (Action::FireShotgun, "FireShotgun"),
+    (Action::Light2, "Light2"),
+    (Action::FireShotgun2, "FireShotgun2"),
+    (Action::CheckReload, "CheckReload"),
+    (Action::OpenShotgun2, "OpenShotgun2"),
+    (Action::LoadShotgun2, "LoadShotgun2"),
+    (Action::CloseShotgun2, "CloseShotgun2"),
+    (Action::FireCGun, "FireCGun"),
+    (Action::GunFlash, "GunFlash"),
+    (Action::FireMissile, "FireMissile"),
+    (Action::Saw, "Saw"),
+    (Action::FirePlasma, "FirePlasma"),
+    (Action::BFGsound, "BFGsound"),
+    (Action::FireBFG, "FireBFG"),
+    (Action::BFGSpray, "BFGSpray"),
+    (Action::Explode, "Explode"),
+    (Action::Pain, "Pain"),
+    (Action::PlayerScream, "PlayerScream"),
+    (Action::Fall, "Fall"),
+    (Action::XScream, "XScream"),
+    (Action::Look, "Look"),
+    (Action::Chase, "Chase"),
+    (Action::FaceTarget, "FaceTarget"),
+    (Action::PosAttack, "PosAttack"),
+    (Action::Scream, "Scream"),
+    (Action::SPosAttack, "SPosAttack"),
+    (Action::VileChase, "VileChase"),
+    (Action::VileStart, "VileStart"),
+    (Action::VileTarget, "VileTarget"),
+    (Action::VileAttack, "VileAttack"),
+    (Action::StartFire, "StartFire"),
+    (Action::Fire, "Fire"),
+    (Action::FireCrackle, "FireCrackle"),
+    (Action::Tracer, "Tracer"),
+    (Action::SkelWhoosh, "SkelWhoosh"),
+    (Action::SkelFist, "SkelFist"),
+    (Action::SkelMissile, "SkelMissile"),
+    (Action::FatRaise, "FatRaise"),
+    (Action::FatAttack1, "FatAttack1"),
+    (Action::FatAttack2, "FatAttack2"),
+    (Action::FatAttack3, "FatAttack3"),
+    (Action::BossDeath, "BossDeath"),
+    (Action::CPosAttack, "CPosAttack"),
+    (Action::CPosRefire, "CPosRefire"),
+    (Action::TroopAttack, "TroopAttack"),
+    (Action::SargAttack, "SargAttack"),
+    (Action::HeadAttack, "HeadAttack"),
+    (Action::BruisAttack, "BruisAttack"),
+    (Action::SkullAttack, "SkullAttack"),
+    (Action::Metal, "Metal"),
+    (Action::SpidRefire, "SpidRefire"),
+    (Action::BabyMetal, "BabyMetal"),
+    (Action::BspiAttack, "BspiAttack"),
+    (Action::Hoof, "Hoof"),
+    (Action::CyberAttack, "CyberAttack"),
+    (Action::PainAttack, "PainAttack"),
+    (Action::PainDie, "PainDie"),
+    (Action::KeenDie, "KeenDie"),
+    (Action::BrainPain, "BrainPain"),
+    (Action::BrainScream, "BrainScream"),
+    (Action::BrainDie, "BrainDie"),
+    (Action::BrainAwake, "BrainAwake"),
+    (Action::BrainSpit, "BrainSpit"),
+    (Action::SpawnSound, "SpawnSound"),
+    (Action::SpawnFly, "SpawnFly"),
+    (Action::BrainExplode, "BrainExplode"),
+];
+
+impl Action {
+    pub fn name(self) -> &'static str {
+        ACTION_NAMES
+            .iter()
+            .find(|(action, _)| *action == self)
+            .map(|(_, name)| *name)
+            .unwrap_or("NULL")
+    }
+
+    pub fn from_name(name: &str) -> Option<Action> {
+        let name = name.strip_prefix("A_").unwrap_or(name);
+        ACTION_NAMES
+            .iter()
+            .find(|(_, n)| n.eq_ignore_ascii_case(name))
+            .map(|(action, _)| *action)
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct StateInfo {
+    pub sprite: usize,
+    pub frame: i32,
+    pub tics: i32,
+    pub action: Action,
+    pub next_state: usize,
+    pub misc1: i32,
+    pub misc2: i32,
+}
+
+const fn state(
+    sprite: usize,
+    frame: i32,
+    tics: i32,
+    action: Action,
+    next_state: usize,
+) -> StateInfo {
+    StateInfo {
+        sprite,
+        frame,
+        tics,
+        action,
+        next_state,
+        misc1: 0,
+        misc2: 0,
+    }
+}
+
+pub const NUM_STATES: usize = 967;
+
+pub const S_NULL: usize = 0;
+pub const S_LIGHTDONE: usize = 1;
+pub const S_PUNCH: usize = 2;
+pub const S_PUNCHDOWN: usize = 3;
+pub const S_PUNCHUP: usize = 4;
+pub const S_PUNCH1: usize = 5;
+pub const S_PUNCH2: usize = 6;
+pub const S_PUNCH3: usize = 7;
+pub const S_PUNCH4: usize = 8;
+pub const S_PUNCH5: usize = 9;
+pub const S_PISTOL: usize = 10;
+pub const S_PISTOLDOWN: usize = 11;
+pub const S_PISTOLUP: usize = 12;
+pub const S_PISTOL1: usize = 13;
+pub const S_PISTOL2: usize = 14;
+pub const S_PISTOL3: usize = 15;
+pub const S_PISTOL4: usize = 16;
+pub const S_PISTOLFLASH: usize = 17;
+pub const S_SGUN: usize = 18;
+pub const S_SGUNDOWN: usize = 19;
+pub const S_SGUNUP: usize = 20;
+pub const S_SGUN1: usize = 21;
+pub const S_SGUN2: usize = 22;
+pub const S_SGUN3: usize = 23;
+pub const S_SGUN4: usize = 24;
+pub const S_SGUN5: usize = 25;
+pub const S_SGUN6: usize = 26;
+pub const S_SGUN7: usize = 27;
+pub const S_SGUN8: usize = 28;
+pub const S_SGUN9: usize = 29;
+pub const S_SGUNFLASH1: usize = 30;
+pub const S_SGUNFLASH2: usize = 31;
+pub const S_DSGUN: usize = 32;
+pub const S_DSGUNDOWN: usize = 33;
+pub const S_DSGUNUP: usize = 34;
+pub const S_DSGUN1: usize = 35;
+pub const S_DSGUN2: usize = 36;
+pub const S_DSGUN3: usize = 37;
+pub const S_DSGUN4: usize = 38;
+pub const S_DSGUN5: usize = 39;
+pub const S_DSGUN6: usize = 40;
+pub const S_DSGUN7: usize = 41;
+pub const S_DSGUN8: usize = 42;
+pub const S_DSGUN9: usize = 43;
+pub const S_DSGUN10: usize = 44;
+pub const S_DSNR1: usize = 45;
+pub const S_DSNR2: usize = 46;
+pub const S_DSGUNFLASH1: usize = 47;
+pub const S_DSGUNFLASH2: usize = 48;
+pub const S_CHAIN: usize = 49;
+pub const S_CHAINDOWN: usize = 50;
+pub const S_CHAINUP: usize = 51;
+pub const S_CHAIN1: usize = 52;
+pub const S_CHAIN2: usize = 53;
+pub const S_CHAIN3: usize = 54;
+pub const S_CHAINFLASH1: usize = 55;
+pub const S_CHAINFLASH2: usize = 56;
+pub const S_MISSILE: usize = 57;
+pub const S_MISSILEDOWN: usize = 58;
+pub const S_MISSILEUP: usize = 59;
+pub const S_MISSILE1: usize = 60;
+pub const S_MISSILE2: usize = 61;
+pub const S_MISSILE3: usize = 62;
+pub const S_MISSILEFLASH1: usize = 63;
+pub const S_MISSILEFLASH2: usize = 64;
+pub const S_MISSILEFLASH3: usize = 65;
+pub const S_MISSILEFLASH4: usize = 66;
+pub const S_SAW: usize = 67;
+pub const S_SAWB: usize = 68;
+pub const S_SAWDOWN: usize = 69;
+pub const S_SAWUP: usize = 70;
+pub const S_SAW1: usize = 71;
+pub const S_SAW2: usize = 72;
+pub const S_SAW3: usize = 73;
+pub const S_PLASMA: usize = 74;
+pub const S_PLASMADOWN: usize = 75;
+pub const S_PLASMAUP: usize = 76;
+pub const S_PLASMA1: usize = 77;
+pub const S_PLASMA2: usize = 78;
+pub const S_PLASMAFLASH1: usize = 79;
+pub const S_PLASMAFLASH2: usize = 80;
+pub const S_BFG: usize = 81;
+pub const S_BFGDOWN: usize = 82;
+pub const S_BFGUP: usize = 83;
+pub const S_BFG1: usize = 84;
+pub const S_BFG2: usize = 85;
+pub const S_BFG3: usize = 86;
+pub const S_BFG4: usize = 87;
+pub const S_BFGFLASH1: usize = 88;
+pub const S_BFGFLASH2: usize = 89;
+pub const S_BLOOD1: usize = 90;
+pub const S_BLOOD2: usize = 91;
+pub const S_BLOOD3: usize = 92;
+pub const S_PUFF1: usize = 93;
+pub const S_PUFF2: usize = 94;
+pub const S_PUFF3: usize = 95;
+pub const S_PUFF4: usize = 96;
+pub const S_TBALL1: usize = 97;
+pub const S_TBALL2: usize = 98;
+pub const S_TBALLX1: usize = 99;
+pub const S_TBALLX2: usize = 100;
+pub const S_TBALLX3: usize = 101;
+pub const S_RBALL1: usize = 102;
+pub const S_RBALL2: usize = 103;
+pub const S_RBALLX1: usize = 104;
+pub const S_RBALLX2: usize = 105;
+pub const S_RBALLX3: usize = 106;
+pub const S_PLASBALL: usize = 107;
+pub const S_PLASBALL2: usize = 108;
+pub const S_PLASEXP: usize = 109;
+pub const S_PLASEXP2: usize = 110;
+pub const S_PLASEXP3: usize = 111;
+pub const S_PLASEXP4: usize = 112;
+pub const S_PLASEXP5: usize = 113;
+pub const S_ROCKET: usize = 114;
+pub const S_BFGSHOT: usize = 115;
+pub const S_BFGSHOT2: usize = 116;
+pub const S_BFGLAND: usize = 117;
+pub const S_BFGLAND2: usize = 118;
+pub const S_BFGLAND3: usize = 119;
+pub const S_BFGLAND4: usize = 120;
+pub const S_BFGLAND5: usize = 121;
+pub const S_BFGLAND6: usize = 122;
+pub const S_BFGEXP: usize = 123;
+pub const S_BFGEXP2: usize = 124;
+pub const S_BFGEXP3: usize = 125;
+pub const S_BFGEXP4: usize = 126;
+pub const S_EXPLODE1: usize = 127;
+pub const S_EXPLODE2: usize = 128;
+pub const S_EXPLODE3: usize = 129;
+pub const S_TFOG: usize = 130;
+pub const S_TFOG01: usize = 131;
+pub const S_TFOG02: usize = 132;
+pub const S_TFOG2: usize = 133;
+pub const S_TFOG3: usize = 134;
+pub const S_TFOG4: usize = 135;
+pub const S_TFOG5: usize = 136;
+pub const S_TFOG6: usize = 137;
+pub const S_TFOG7: usize = 138;
+pub const S_TFOG8: usize = 139;
+pub const S_TFOG9: usize = 140;
+pub const S_TFOG10: usize = 141;
+pub const S_IFOG: usize = 142;
+pub const S_IFOG01: usize = 143;
+pub const S_IFOG02: usize = 144;
+pub const S_IFOG2: usize = 145;
+pub const S_IFOG3: usize = 146;
+pub const S_IFOG4: usize = 147;
+pub const S_IFOG5: usize = 148;
+pub const S_PLAY: usize = 149;
+pub const S_PLAY_RUN1: usize = 150;
+pub const S_PLAY_RUN2: usize = 151;
+pub const S_PLAY_RUN3: usize = 152;
+pub const S_PLAY_RUN4: usize = 153;
+pub const S_PLAY_ATK1: usize = 154;
+pub const S_PLAY_ATK2: usize = 155;
+pub const S_PLAY_PAIN: usize = 156;
+pub const S_PLAY_PAIN2: usize = 157;
+pub const S_PLAY_DIE1: usize = 158;
+pub const S_PLAY_DIE2: usize = 159;
+pub const S_PLAY_DIE3: usize = 160;
+pub const S_PLAY_DIE4: usize = 161;
+pub const S_PLAY_DIE5: usize = 162;
+pub const S_PLAY_DIE6: usize = 163;
+pub const S_PLAY_DIE7: usize = 164;
+pub const S_PLAY_XDIE1: usize = 165;
+pub const S_PLAY_XDIE2: usize = 166;
+pub const S_PLAY_XDIE3: usize = 167;
+pub const S_PLAY_XDIE4: usize = 168;
+pub const S_PLAY_XDIE5: usize = 169;
+pub const S_PLAY_XDIE6: usize = 170;
+pub const S_PLAY_XDIE7: usize = 171;
+pub const S_PLAY_XDIE8: usize = 172;
+pub const S_PLAY_XDIE9: usize = 173;
+pub const S_POSS_STND: usize = 174;
+pub const S_POSS_STND2: usize = 175;
+pub const S_POSS_RUN1: usize = 176;
+pub const S_POSS_RUN2: usize = 177;
+pub const S_POSS_RUN3: usize = 178;
+pub const S_POSS_RUN4: usize = 179;
+pub const S_POSS_RUN5: usize = 180;
+pub const S_POSS_RUN6: usize = 181;
+pub const S_POSS_RUN7: usize = 182;
+pub const S_POSS_RUN8: usize = 183;
+pub const S_POSS_ATK1: usize = 184;
+pub const S_POSS_ATK2: usize = 185;
+pub const S_POSS_ATK3: usize = 186;
+pub const S_POSS_PAIN: usize = 187;
+pub const S_POSS_PAIN2: usize = 188;
+pub const S_POSS_DIE1: usize = 189;
+pub const S_POSS_DIE2: usize = 190;
+pub const S_POSS_DIE3: usize = 191;
+pub const S_POSS_DIE4: usize = 192;
+pub const S_POSS_DIE5: usize = 193;
+pub const S_POSS_XDIE1: usize = 194;
+pub const S_POSS_XDIE2: usize = 195;
+pub const S_POSS_XDIE3: usize = 196;
+pub const S_POSS_XDIE4: usize = 197;
+pub const S_POSS_XDIE5: usize = 198;
+pub const S_POSS_XDIE6: usize = 199;
+pub const S_POSS_XDIE7: usize = 200;
+pub const S_POSS_XDIE8: usize = 201;
+pub const S_POSS_XDIE9: usize = 202;
+pub const S_POSS_RAISE1: usize = 203;
+pub const S_POSS_RAISE2: usize = 204;
+pub const S_POSS_RAISE3: usize = 205;
+pub const S_POSS_RAISE4: usize = 206;
+pub const S_SPOS_STND: usize = 207;
+pub const S_SPOS_STND2: usize = 208;
+pub const S_SPOS_RUN1: usize = 209;
+pub const S_SPOS_RUN2: usize = 210;
+pub const S_SPOS_RUN3: usize = 211;
+pub const S_SPOS_RUN4: usize = 212;
+pub const S_SPOS_RUN5: usize = 213;
+pub const S_SPOS_RUN6: usize = 214;
+pub const S_SPOS_RUN7: usize = 215;
+pub const S_SPOS_RUN8: usize = 216;
+pub const S_SPOS_ATK1: usize = 217;
+pub const S_SPOS_ATK2: usize = 218;
+pub const S_SPOS_ATK3: usize = 219;
+pub const S_SPOS_PAIN: usize = 220;
+pub const S_SPOS_PAIN2: usize = 221;
+pub const S_SPOS_DIE1: usize = 222;
+pub const S_SPOS_DIE2: usize = 223;
+pub const S_SPOS_DIE3: usize = 224;
+pub const S_SPOS_DIE4: usize = 225;
+pub const S_SPOS_DIE5: usize = 226;
+pub const S_SPOS_XDIE1: usize = 227;
+pub const S_SPOS_XDIE2: usize = 228;
+pub const S_SPOS_XDIE3: usize = 229;
+pub const S_SPOS_XDIE4: usize = 230;
+pub const S_SPOS_XDIE5: usize = 231;
+pub const S_SPOS_XDIE6: usize = 232;
+pub const S_SPOS_XDIE7: usize = 233;
+pub const S_SPOS_XDIE8: usize = 234;
+pub const S_SPOS_XDIE9: usize = 235;
+pub const S_SPOS_RAISE1: usize = 236;
+pub const S_SPOS_RAISE2: usize = 237;
+pub const S_SPOS_RAISE3: usize = 238;
+pub const S_SPOS_RAISE4: usize = 239;
+pub const S_SPOS_RAISE5: usize = 240;
+pub const S_VILE_STND: usize = 241;
+pub const S_VILE_STND2: usize = 242;
+pub const S_VILE_RUN1: usize = 243;
+pub const S_VILE_RUN2: usize = 244;
+pub const S_VILE_RUN3: usize = 245;
+pub const S_VILE_RUN4: usize = 246;
+pub const S_VILE_RUN5: usize = 247;
+pub const S_VILE_RUN6: usize = 248;
+pub const S_VILE_RUN7: usize = 249;
+pub const S_VILE_RUN8: usize = 250;
+pub const S_VILE_RUN9: usize = 251;
+pub const S_VILE_RUN10: usize = 252;
+pub const S_VILE_RUN11: usize = 253;
+pub const S_VILE_RUN12: usize = 254;
+pub const S_VILE_ATK1: usize = 255;
+pub const S_VILE_ATK2: usize = 256;
+pub const S_VILE_ATK3: usize = 257;
+pub const S_VILE_ATK4: usize = 258;
+pub const S_VILE_ATK5: usize = 259;
+pub const S_VILE_ATK6: usize = 260;
+pub const S_VILE_ATK7: usize = 261;
+pub const S_VILE_ATK8: usize = 262;
+pub const S_VILE_ATK9: usize = 263;
+pub const S_VILE_ATK10: usize = 264;
+pub const S_VILE_ATK11: usize = 265;
+pub const S_VILE_HEAL1: usize = 266;
+pub const S_VILE_HEAL2: usize = 267;
+pub const S_VILE_HEAL3: usize = 268;
+pub const S_VILE_PAIN: usize = 269;
+pub const S_VILE_PAIN2: usize = 270;
+pub const S_VILE_DIE1: usize = 271;
+pub const S_VILE_DIE2: usize = 272;
+pub const S_VILE_DIE3: usize = 273;
+pub const S_VILE_DIE4: usize = 274;
+pub const S_VILE_DIE5: usize = 275;
+pub const S_VILE_DIE6: usize = 276;
+pub const S_VILE_DIE7: usize = 277;
+pub const S_VILE_DIE8: usize = 278;
+pub const S_VILE_DIE9: usize = 279;
+pub const S_VILE_DIE10: usize = 280;
+pub const S_FIRE1: usize = 281;
+pub const S_FIRE2: usize = 282;
+pub const S_FIRE3: usize = 283;
+pub const S_FIRE4: usize = 284;
+pub const S_FIRE5: usize = 285;
+pub const S_FIRE6: usize = 286;
+pub const S_FIRE7: usize = 287;
+pub const S_FIRE8: usize = 288;
+pub const S_FIRE9: usize = 289;
+pub const S_FIRE10: usize = 290;
+pub const S_FIRE11: usize = 291;
+pub const S_FIRE12: usize = 292;
+pub const S_FIRE13: usize = 293;
+pub const S_FIRE14: usize = 294;
+pub const S_FIRE15: usize = 295;
+pub const S_FIRE16: usize = 296;
+pub const S_FIRE17: usize = 297;
+pub const S_FIRE18: usize = 298;
+pub const S_FIRE19: usize = 299;
+pub const S_FIRE20: usize = 300;
+pub const S_FIRE21: usize = 301;
+pub const S_FIRE22: usize = 302;
+pub const S_FIRE23: usize = 303;
+pub const S_FIRE24: usize = 304;
+pub const S_FIRE25: usize = 305;
+pub const S_FIRE26: usize = 306;
+pub const S_FIRE27: usize = 307;
+pub const S_FIRE28: usize = 308;
+pub const S_FIRE29: usize = 309;
+pub const S_FIRE30: usize = 310;
+pub const S_SMOKE1: usize = 311;
+pub const S_SMOKE2: usize = 312;
+pub const S_SMOKE3: usize = 313;
+pub const S_SMOKE4: usize = 314;
+pub const S_SMOKE5: usize = 315;
+pub const S_TRACER: usize = 316;
+pub const S_TRACER2: usize = 317;
+pub const S_TRACEEXP1: usize = 318;
+pub const S_TRACEEXP2: usize = 319;
+pub const S_TRACEEXP3: usize = 320;
+pub const S_SKEL_STND: usize = 321;
+pub const S_SKEL_STND2: usize = 322;
+pub const S_SKEL_RUN1: usize = 323;
+pub const S_SKEL_RUN2: usize = 324;
+pub const S_SKEL_RUN3: usize = 325;
+pub const S_SKEL_RUN4: usize = 326;
+pub const S_SKEL_RUN5: usize = 327;
+pub const S_SKEL_RUN6: usize = 328;
+pub const S_SKEL_RUN7: usize = 329;
+pub const S_SKEL_RUN8: usize = 330;
+pub const S_SKEL_RUN9: usize = 331;
+pub const S_SKEL_RUN10: usize = 332;
+pub const S_SKEL_RUN11: usize = 333;
+pub const S_SKEL_RUN12: usize = 334;
+pub const S_SKEL_FIST1: usize = 335;
+pub const S_SKEL_FIST2: usize = 336;
+pub const S_SKEL_FIST3: usize = 337;
+pub const S_SKEL_FIST4: usize = 338;
+pub const S_SKEL_MISS1: usize = 339;
+pub const S_SKEL_MISS2: usize = 340;
+pub const S_SKEL_MISS3: usize = 341;
+pub const S_SKEL_MISS4: usize = 342;
+pub const S_SKEL_PAIN: usize = 343;
+pub const S_SKEL_PAIN2: usize = 344;
+pub const S_SKEL_DIE1: usize = 345;
+pub const S_SKEL_DIE2: usize = 346;
+pub const S_SKEL_DIE3: usize = 347;
+pub const S_SKEL_DIE4: usize = 348;
+pub const S_SKEL_DIE5: usize = 349;
+pub const S_SKEL_DIE6: usize = 350;
+pub const S_SKEL_RAISE1: usize = 351;
+pub const S_SKEL_RAISE2: usize = 352;
+pub const S_SKEL_RAISE3: usize = 353;
+pub const S_SKEL_RAISE4: usize = 354;
+pub const S_SKEL_RAISE5: usize = 355;
+pub const S_SKEL_RAISE6: usize = 356;
+pub const S_FATSHOT1: usize = 357;
+pub const S_FATSHOT2: usize = 358;
+pub const S_FATSHOTX1: usize = 359;
+pub const S_FATSHOTX2: usize = 360;
+pub const S_FATSHOTX3: usize = 361;
+pub const S_FATT_STND: usize = 362;
+pub const S_FATT_STND2: usize = 363;
+pub const S_FATT_RUN1: usize = 364;
+pub const S_FATT_RUN2: usize = 365;
+pub const S_FATT_RUN3: usize = 366;
+pub const S_FATT_RUN4: usize = 367;
+pub const S_FATT_RUN5: usize = 368;
+pub const S_FATT_RUN6: usize = 369;
+pub const S_FATT_RUN7: usize = 370;
+pub const S_FATT_RUN8: usize = 371;
+pub const S_FATT_RUN9: usize = 372;
+pub const S_FATT_RUN10: usize = 373;
+pub const S_FATT_RUN11: usize = 374;
+pub const S_FATT_RUN12: usize = 375;
+pub const S_FATT_ATK1: usize = 376;
+pub const S_FATT_ATK2: usize = 377;
+pub const S_FATT_ATK3: usize = 378;
+pub const S_FATT_ATK4: usize = 379;
+pub const S_FATT_ATK5: usize = 380;
+pub const S_FATT_ATK6: usize = 381;
+pub const S_FATT_ATK7: usize = 382;
+pub const S_FATT_ATK8: usize = 383;
+pub const S_FATT_ATK9: usize = 384;
+pub const S_FATT_ATK10: usize = 385;
+pub const S_FATT_PAIN: usize = 386;
+pub const S_FATT_PAIN2: usize = 387;
+pub const S_FATT_DIE1: usize = 388;
+pub const S_FATT_DIE2: usize = 389;
+pub const S_FATT_DIE3: usize = 390;
+pub const S_FATT_DIE4: usize = 391;
+pub const S_FATT_DIE5: usize = 392;
+pub const S_FATT_DIE6: usize = 393;
+pub const S_FATT_DIE7: usize = 394;
+pub const S_FATT_DIE8: usize = 395;
+pub const S_FATT_DIE9: usize = 396;
+pub const S_FATT_DIE10: usize = 397;
+pub const S_FATT_RAISE1: usize = 398;
+pub const S_FATT_RAISE2: usize = 399;
+pub const S_FATT_RAISE3: usize = 400;
+pub const S_FATT_RAISE4: usize = 401;
+pub const S_FATT_RAISE5: usize = 402;
+pub const S_FATT_RAISE6: usize = 403;
+pub const S_FATT_RAISE7: usize = 404;
+pub const S_FATT_RAISE8: usize = 405;
+pub const S_CPOS_STND: usize = 406;
+pub const S_CPOS_STND2: usize = 407;
+pub const S_CPOS_RUN1: usize = 408;
+pub const S_CPOS_RUN2: usize = 409;
+pub const S_CPOS_RUN3: usize = 410;
+pub const S_CPOS_RUN4: usize = 411;
+pub const S_CPOS_RUN5: usize = 412;
+pub const S_CPOS_RUN6: usize = 413;
+pub const S_CPOS_RUN7: usize = 414;
+pub const S_CPOS_RUN8: usize = 415;
+pub const S_CPOS_ATK1: usize = 416;
+pub const S_CPOS_ATK2: usize = 417;
+pub const S_CPOS_ATK3: usize = 418;
+pub const S_CPOS_ATK4: usize = 419;
+pub const S_CPOS_PAIN: usize = 420;
+pub const S_CPOS_PAIN2: usize = 421;
+pub const S_CPOS_DIE1: usize = 422;
+pub const S_CPOS_DIE2: usize = 423;
+pub const S_CPOS_DIE3: usize = 424;
+pub const S_CPOS_DIE4: usize = 425;
+pub const S_CPOS_DIE5: usize = 426;
+pub const S_CPOS_DIE6: usize = 427;
+pub const S_CPOS_DIE7: usize = 428;
+pub const S_CPOS_XDIE1: usize = 429;
+pub const S_CPOS_XDIE2: usize = 430;
+pub const S_CPOS_XDIE3: usize = 431;
+pub const S_CPOS_XDIE4: usize = 432;
+pub const S_CPOS_XDIE5: usize = 433;
+pub const S_CPOS_XDIE6: usize = 434;
+pub const S_CPOS_RAISE1: usize = 435;
+pub const S_CPOS_RAISE2: usize = 436;
+pub const S_CPOS_RAISE3: usize = 437;
+pub const S_CPOS_RAISE4: usize = 438;
+pub const S_CPOS_RAISE5: usize = 439;
+pub const S_CPOS_RAISE6: usize = 440;
+pub const S_CPOS_RAISE7: usize = 441;
+pub const S_TROO_STND: usize = 442;
+pub const S_TROO_STND2: usize = 443;
+pub const S_TROO_RUN1: usize = 444;
+pub const S_TROO_RUN2: usize = 445;
+pub const S_TROO_RUN3: usize = 446;
+pub const S_TROO_RUN4: usize = 447;
+pub const S_TROO_RUN5: usize = 448;
+pub const S_TROO_RUN6: usize = 449;
+pub const S_TROO_RUN7: usize = 450;
+pub const S_TROO_RUN8: usize = 451;
+pub const S_TROO_ATK1: usize = 452;
+pub const S_TROO_ATK2: usize = 453;
+pub const S_TROO_ATK3: usize = 454;
+pub const S_TROO_PAIN: usize = 455;
+pub const S_TROO_PAIN2: usize = 456;
+pub const S_TROO_DIE1: usize = 457;
+pub const S_TROO_DIE2: usize = 458;
+pub const S_TROO_DIE3: usize = 459;
+pub const S_TROO_DIE4: usize = 460;
+pub const S_TROO_DIE5: usize = 461;
+pub const S_TROO_XDIE1: usize = 462;
+pub const S_TROO_XDIE2: usize = 463;
+pub const S_TROO_XDIE3: usize = 464;
+pub const S_TROO_XDIE4: usize = 465;
+pub const S_TROO_XDIE5: usize = 466;
+pub const S_TROO_XDIE6: usize = 467;
+pub const S_TROO_XDIE7: usize = 468;
+pub const S_TROO_XDIE8: usize = 469;
+pub const S_TROO_RAISE1: usize = 470;
+pub const S_TROO_RAISE2: usize = 471;
+pub const S_TROO_RAISE3: usize = 472;
+pub const S_TROO_RAISE4: usize = 473;
+pub const S_TROO_RAISE5: usize = 474;
+pub const S_SARG_STND: usize = 475;
+pub const S_SARG_STND2: usize = 476;
+pub const S_SARG_RUN1: usize = 477;
+pub const S_SARG_RUN2: usize = 478;
+pub const S_SARG_RUN3: usize = 479;
+pub const S_SARG_RUN4: usize = 480;
+pub const S_SARG_RUN5: usize = 481;
+pub const S_SARG_RUN6: usize = 482;
+pub const S_SARG_RUN7: usize = 483;
+pub const S_SARG_RUN8: usize = 484;
+pub const S_SARG_ATK1: usize = 485;
+pub const S_SARG_ATK2: usize = 486;
+pub const S_SARG_ATK3: usize = 487;
+pub const S_SARG_PAIN: usize = 488;
+pub const S_SARG_PAIN2: usize = 489;
+pub const S_SARG_DIE1: usize = 490;
+pub const S_SARG_DIE2: usize = 491;
+pub const S_SARG_DIE3: usize = 492;
+pub const S_SARG_DIE4: usize = 493;
+pub const S_SARG_DIE5: usize = 494;
+pub const S_SARG_DIE6: usize = 495;
+pub const S_SARG_RAISE1: usize = 496;
+pub const S_SARG_RAISE2: usize = 497;
+pub const S_SARG_RAISE3: usize = 498;
+pub const S_SARG_RAISE4: usize = 499;
+pub const S_SARG_RAISE5: usize = 500;
+pub const S_SARG_RAISE6: usize = 501;
+pub const S_HEAD_STND: usize = 502;
+pub const S_HEAD_RUN1: usize = 503;
+pub const S_HEAD_ATK1: usize = 504;
+pub const S_HEAD_ATK2: usize = 505;
+pub const S_HEAD_ATK3: usize = 506;
+pub const S_HEAD_PAIN: usize = 507;
+pub const S_HEAD_PAIN2: usize = 508;
+pub const S_HEAD_PAIN3: usize = 509;
+pub const S_HEAD_DIE1: usize = 510;
+pub const S_HEAD_DIE2: usize = 511;
+pub const S_HEAD_DIE3: usize = 512;
+pub const S_HEAD_DIE4: usize = 513;
+pub const S_HEAD_DIE5: usize = 514;
+pub const S_HEAD_DIE6: usize = 515;
+pub const S_HEAD_RAISE1: usize = 516;
+pub const S_HEAD_RAISE2: usize = 517;
+pub const S_HEAD_RAISE3: usize = 518;
+pub const S_HEAD_RAISE4: usize = 519;
+pub const S_HEAD_RAISE5: usize = 520;
+pub const S_HEAD_RAISE6: usize = 521;
+pub const S_BRBALL1: usize = 522;
+pub const S_BRBALL2: usize = 523;
+pub const S_BRBALLX1: usize = 524;
+pub const S_BRBALLX2: usize = 525;
+pub const S_BRBALLX3: usize = 526;
+pub const S_BOSS_STND: usize = 527;
+pub const S_BOSS_STND2: usize = 528;
+pub const S_BOSS_RUN1: usize = 529;
+pub const S_BOSS_RUN2: usize = 530;
+pub const S_BOSS_RUN3: usize = 531;
+pub const S_BOSS_RUN4: usize = 532;
+pub const S_BOSS_RUN5: usize = 533;
+pub const S_BOSS_RUN6: usize = 534;
+pub const S_BOSS_RUN7: usize = 535;
+pub const S_BOSS_RUN8: usize = 536;
+pub const S_BOSS_ATK1: usize = 537;
+pub const S_BOSS_ATK2: usize = 538;
+pub const S_BOSS_ATK3: usize = 539;
+pub const S_BOSS_PAIN: usize = 540;
+pub const S_BOSS_PAIN2: usize = 541;
+pub const S_BOSS_DIE1: usize = 542;
+pub const S_BOSS_DIE2: usize = 543;
+pub const S_BOSS_DIE3: usize = 544;
+pub const S_BOSS_DIE4: usize = 545;
+pub const S_BOSS_DIE5: usize = 546;
+pub const S_BOSS_DIE6: usize = 547;
+pub const S_BOSS_DIE7: usize = 548;
+pub const S_BOSS_RAISE1: usize = 549;
+pub const S_BOSS_RAISE2: usize = 550;
+pub const S_BOSS_RAISE3: usize = 551;
+pub const S_BOSS_RAISE4: usize = 552;
+pub const S_BOSS_RAISE5: usize = 553;
+pub const S_BOSS_RAISE6: usize = 554;
+pub const S_BOSS_RAISE7: usize = 555;
+pub const S_BOS2_STND: usize = 556;
+pub const S_BOS2_STND2: usize = 557;
+pub const S_BOS2_RUN1: usize = 558;
+pub const S_BOS2_RUN2: usize = 559;
+pub const S_BOS2_RUN3: usize = 560;
+pub const S_BOS2_RUN4: usize = 561;
+pub const S_BOS2_RUN5: usize = 562;
+pub const S_BOS2_RUN6: usize = 563;
+pub const S_BOS2_RUN7: usize = 564;
+pub const S_BOS2_RUN8: usize = 565;
+pub const S_BOS2_ATK1: usize = 566;
+pub const S_BOS2_ATK2: usize = 567;
+pub const S_BOS2_ATK3: usize = 568;
+pub const S_BOS2_PAIN: usize = 569;
+pub const S_BOS2_PAIN2: usize = 570;
+pub const S_BOS2_DIE1: usize = 571;
+pub const S_BOS2_DIE2: usize = 572;
+pub const S_BOS2_DIE3: usize = 573;
+pub const S_BOS2_DIE4: usize = 574;
+pub const S_BOS2_DIE5: usize = 575;
+pub const S_BOS2_DIE6: usize = 576;
+pub const S_BOS2_DIE7: usize = 577;
+pub const S_BOS2_RAISE1: usize = 578;
+pub const S_BOS2_RAISE2: usize = 579;
+pub const S_BOS2_RAISE3: usize = 580;
+pub const S_BOS2_RAISE4: usize = 581;
+pub const S_BOS2_RAISE5: usize = 582;
+pub const S_BOS2_RAISE6: usize = 583;
+pub const S_BOS2_RAISE7: usize = 584;
+pub const S_SKULL_STND: usize = 585;
+pub const S_SKULL_STND2: usize = 586;
+pub const S_SKULL_RUN1: usize = 587;
+pub const S_SKULL_RUN2: usize = 588;
+pub const S_SKULL_ATK1: usize = 589;
+pub const S_SKULL_ATK2: usize = 590;
+pub const S_SKULL_ATK3: usize = 591;
+pub const S_SKULL_ATK4: usize = 592;
+pub const S_SKULL_PAIN: usize = 593;
+pub const S_SKULL_PAIN2: usize = 594;
+pub const S_SKULL_DIE1: usize = 595;
+pub const S_SKULL_DIE2: usize = 596;
+pub const S_SKULL_DIE3: usize = 597;
+pub const S_SKULL_DIE4: usize = 598;
+pub const S_SKULL_DIE5: usize = 599;
+pub const S_SKULL_DIE6: usize = 600;
+pub const S_SPID_STND: usize = 601;
+pub const S_SPID_STND2: usize = 602;
+pub const S_SPID_RUN1: usize = 603;
+pub const S_SPID_RUN2: usize = 604;
+pub const S_SPID_RUN3: usize = 605;
+pub const S_SPID_RUN4: usize = 606;
+pub const S_SPID_RUN5: usize = 607;
+pub const S_SPID_RUN6: usize = 608;
+pub const S_SPID_RUN7: usize = 609;
+pub const S_SPID_RUN8: usize = 610;
+pub const S_SPID_RUN9: usize = 611;
+pub const S_SPID_RUN10: usize = 612;
+pub const S_SPID_RUN11: usize = 613;
+pub const S_SPID_RUN12: usize = 614;
+pub const S_SPID_ATK1: usize = 615;
+pub const S_SPID_ATK2: usize = 616;
+pub const S_SPID_ATK3: usize = 617;
+pub const S_SPID_ATK4: usize = 618;
+pub const S_SPID_PAIN: usize = 619;
+pub const S_SPID_PAIN2: usize = 620;
+pub const S_SPID_DIE1: usize = 621;
+pub const S_SPID_DIE2: usize = 622;
+pub const S_SPID_DIE3: usize = 623;
+pub const S_SPID_DIE4: usize = 624;
+pub const S_SPID_DIE5: usize = 625;
+pub const S_SPID_DIE6: usize = 626;
+pub const S_SPID_DIE7: usize = 627;
+pub const S_SPID_DIE8: usize = 628;
+pub const S_SPID_DIE9: usize = 629;
+pub const S_SPID_DIE10: usize = 630;
+pub const S_SPID_DIE11: usize = 631;
+pub const S_BSPI_STND: usize = 632;
+pub const S_BSPI_STND2: usize = 633;
+pub const S_BSPI_SIGHT: usize = 634;
+pub const S_BSPI_RUN1: usize = 635;
+pub const S_BSPI_RUN2: usize = 636;
+pub const S_BSPI_RUN3: usize = 637;
+pub const S_BSPI_RUN4: usize = 638;
+pub const S_BSPI_RUN5: usize = 639;
+pub const S_BSPI_RUN6: usize = 640;
+pub const S_BSPI_RUN7: usize = 641;
+pub const S_BSPI_RUN8: usize = 642;
+pub const S_BSPI_RUN9: usize = 643;
+pub const S_BSPI_RUN10: usize = 644;
+pub const S_BSPI_RUN11: usize = 645;
+pub const S_BSPI_RUN12: usize = 646;
+pub const S_BSPI_ATK1: usize = 647;
+pub const S_BSPI_ATK2: usize = 648;
+pub const S_BSPI_ATK3: usize = 649;
+pub const S_BSPI_ATK4: usize = 650;
+pub const S_BSPI_PAIN: usize = 651;
+pub const S_BSPI_PAIN2: usize = 652;
+pub const S_BSPI_DIE1: usize = 653;
+pub const S_BSPI_DIE2: usize = 654;
+pub const S_BSPI_DIE3: usize = 655;
+pub const S_BSPI_DIE4: usize = 656;
+pub const S_BSPI_DIE5: usize = 657;
+pub const S_BSPI_DIE6: usize = 658;
+pub const S_BSPI_DIE7: usize = 659;
+pub const S_BSPI_RAISE1: usize = 660;
+pub const S_BSPI_RAISE2: usize = 661;
+pub const S_BSPI_RAISE3: usize = 662;
+pub const S_BSPI_RAISE4: usize = 663;
+pub const S_BSPI_RAISE5: usize = 664;
+pub const S_BSPI_RAISE6: usize = 665;
+pub const S_BSPI_RAISE7: usize = 666;
+pub const S_ARACH_PLAZ: usize = 667;
+pub const S_ARACH_PLAZ2: usize = 668;
+pub const S_ARACH_PLEX: usize = 669;
+pub const S_ARACH_PLEX2: usize = 670;
+pub const S_ARACH_PLEX3: usize = 671;
+pub const S_ARACH_PLEX4: usize = 672;
+pub const S_ARACH_PLEX5: usize = 673;
+pub const S_CYBER_STND: usize = 674;
+pub const S_CYBER_STND2: usize = 675;
+pub const S_CYBER_RUN1: usize = 676;
+pub const S_CYBER_RUN2: usize = 677;
+pub const S_CYBER_RUN3: usize = 678;
+pub const S_CYBER_RUN4: usize = 679;
+pub const S_CYBER_RUN5: usize = 680;
+pub const S_CYBER_RUN6: usize = 681;
+pub const S_CYBER_RUN7: usize = 682;
+pub const S_CYBER_RUN8: usize = 683;
+pub const S_CYBER_ATK1: usize = 684;
+pub const S_CYBER_ATK2: usize = 685;
+pub const S_CYBER_ATK3: usize = 686;
+pub const S_CYBER_ATK4: usize = 687;
+pub const S_CYBER_ATK5: usize = 688;
+pub const S_CYBER_ATK6: usize = 689;
+pub const S_CYBER_PAIN: usize = 690;
+pub const S_CYBER_DIE1: usize = 691;
+pub const S_CYBER_DIE2: usize = 692;
+pub const S_CYBER_DIE3: usize = 693;
+pub const S_CYBER_DIE4: usize = 694;
+pub const S_CYBER_DIE5: usize = 695;
+pub const S_CYBER_DIE6: usize = 696;
+pub const S_CYBER_DIE7: usize = 697;
+pub const S_CYBER_DIE8: usize = 698;
+pub const S_CYBER_DIE9: usize = 699;
+pub const S_CYBER_DIE10: usize = 700;
+pub const S_PAIN_STND: usize = 701;
+pub const S_PAIN_RUN1: usize = 702;
+pub const S_PAIN_RUN2: usize = 703;
+pub const S_PAIN_RUN3: usize = 704;
+pub const S_PAIN_RUN4: usize = 705;
+pub const S_PAIN_RUN5: usize = 706;
+pub const S_PAIN_RUN6: usize = 707;
+pub const S_PAIN_ATK1: usize = 708;
+pub const S_PAIN_ATK2: usize = 709;
+pub const S_PAIN_ATK3: usize = 710;
+pub const S_PAIN_ATK4: usize = 711;
+pub const S_PAIN_PAIN: usize = 712;
+pub const S_PAIN_PAIN2: usize = 713;
+pub const S_PAIN_DIE1: usize = 714;
+pub const S_PAIN_DIE2: usize = 715;
+pub const S_PAIN_DIE3: usize = 716;
+pub const S_PAIN_DIE4: usize = 717;
+pub const S_PAIN_DIE5: usize = 718;
+pub const S_PAIN_DIE6: usize = 719;
+pub const S_PAIN_RAISE1: usize = 720;
+pub const S_PAIN_RAISE2: usize = 721;
+pub const S_PAIN_RAISE3: usize = 722;
+pub const S_PAIN_RAISE4: usize = 723;
+pub const S_PAIN_RAISE5: usize = 724;
+pub const S_PAIN_RAISE6: usize = 725;
+pub const S_SSWV_STND: usize = 726;
+pub const S_SSWV_STND2: usize = 727;
+pub const S_SSWV_RUN1: usize = 728;
+pub const S_SSWV_RUN2: usize = 729;
+pub const S_SSWV_RUN3: usize = 730;
+pub const S_SSWV_RUN4: usize = 731;
+pub const S_SSWV_RUN5: usize = 732;
+pub const S_SSWV_RUN6: usize = 733;
+pub const S_SSWV_RUN7: usize = 734;
+pub const S_SSWV_RUN8: usize = 735;
+pub const S_SSWV_ATK1: usize = 736;
+pub const S_SSWV_ATK2: usize = 737;
+pub const S_SSWV_ATK3: usize = 738;
+pub const S_SSWV_ATK4: usize = 739;
+pub const S_SSWV_ATK5: usize = 740;
+pub const S_SSWV_ATK6: usize = 741;
+pub const S_SSWV_PAIN: usize = 742;
+pub const S_SSWV_PAIN2: usize = 743;
+pub const S_SSWV_DIE1: usize = 744;
+pub const S_SSWV_DIE2: usize = 745;
+pub const S_SSWV_DIE3: usize = 746;
+pub const S_SSWV_DIE4: usize = 747;
+pub const S_SSWV_DIE5: usize = 748;
+pub const S_SSWV_XDIE1: usize = 749;
+pub const S_SSWV_XDIE2: usize = 750;
+pub const S_SSWV_XDIE3: usize = 751;
+pub const S_SSWV_XDIE4: usize = 752;
+pub const S_SSWV_XDIE5: usize = 753;
+pub const S_SSWV_XDIE6: usize = 754;
+pub const S_SSWV_XDIE7: usize = 755;
+pub const S_SSWV_XDIE8: usize = 756;
+pub const S_SSWV_XDIE9: usize = 757;
+pub const S_SSWV_RAISE1: usize = 758;
+pub const S_SSWV_RAISE2: usize = 759;
+pub const S_SSWV_RAISE3: usize = 760;
+pub const S_SSWV_RAISE4: usize = 761;
+pub const S_SSWV_RAISE5: usize = 762;
+pub const S_KEENSTND: usize = 763;
+pub const S_COMMKEEN: usize = 764;
+pub const S_COMMKEEN2: usize = 765;
+pub const S_COMMKEEN3: usize = 766;
+pub const S_COMMKEEN4: usize = 767;
+pub const S_COMMKEEN5: usize = 768;
+pub const S_COMMKEEN6: usize = 769;
+pub const S_COMMKEEN7: usize = 770;
+pub const S_COMMKEEN8: usize = 771;
+pub const S_COMMKEEN9: usize = 772;
+pub const S_COMMKEEN10: usize = 773;
+pub const S_COMMKEEN11: usize = 774;
+pub const S_COMMKEEN12: usize = 775;
+pub const S_KEENPAIN: usize = 776;
+pub const S_KEENPAIN2: usize = 777;
+pub const S_BRAIN: usize = 778;
+pub const S_BRAIN_PAIN: usize = 779;
+pub const S_BRAIN_DIE1: usize = 780;
+pub const S_BRAIN_DIE2: usize = 781;
+pub const S_BRAIN_DIE3: usize = 782;
+pub const S_BRAIN_DIE4: usize = 783;
+pub const S_BRAINEYE: usize = 784;
+pub const S_BRAINEYESEE: usize = 785;
+pub const S_BRAINEYE1: usize = 786;
+pub const S_SPAWN1: usize = 787;
+pub const S_SPAWN2: usize = 788;
+pub const S_SPAWN3: usize = 789;
+pub const S_SPAWN4: usize = 790;
+pub const S_SPAWNFIRE1: usize = 791;
+pub const S_SPAWNFIRE2: usize = 792;
+pub const S_SPAWNFIRE3: usize = 793;
+pub const S_SPAWNFIRE4: usize = 794;
+pub const S_SPAWNFIRE5: usize = 795;
+pub const S_SPAWNFIRE6: usize = 796;
+pub const S_SPAWNFIRE7: usize = 797;
+pub const S_SPAWNFIRE8: usize = 798;
+pub const S_BRAINEXPLODE1: usize = 799;
+pub const S_BRAINEXPLODE2: usize = 800;
+pub const S_BRAINEXPLODE3: usize = 801;
+pub const S_ARM1: usize = 802;
+pub const S_ARM1A: usize = 803;
+pub const S_ARM2: usize = 804;
+pub const S_ARM2A: usize = 805;
+pub const S_BAR1: usize = 806;
+pub const S_BAR2: usize = 807;
+pub const S_BEXP: usize = 808;
+pub const S_BEXP2: usize = 809;
+pub const S_BEXP3: usize = 810;
+pub const S_BEXP4: usize = 811;
+pub const S_BEXP5: usize = 812;
+pub const S_BBAR1: usize = 813;
+pub const S_BBAR2: usize = 814;
+pub const S_BBAR3: usize = 815;
+pub const S_BON1: usize = 816;
+pub const S_BON1A: usize = 817;
+pub const S_BON1B: usize = 818;
+pub const S_BON1C: usize = 819;
+pub const S_BON1D: usize = 820;
+pub const S_BON1E: usize = 821;
+pub const S_BON2: usize = 822;
+pub const S_BON2A: usize = 823;
+pub const S_BON2B: usize = 824;
+pub const S_BON2C: usize = 825;
+pub const S_BON2D: usize = 826;
+pub const S_BON2E: usize = 827;
+pub const S_BKEY: usize = 828;
+pub const S_BKEY2: usize = 829;
+pub const S_RKEY: usize = 830;
+pub const S_RKEY2: usize = 831;
+pub const S_YKEY: usize = 832;
+pub const S_YKEY2: usize = 833;
+pub const S_BSKULL: usize = 834;
+pub const S_BSKULL2: usize = 835;
+pub const S_RSKULL: usize = 836;
+pub const S_RSKULL2: usize = 837;
+pub const S_YSKULL: usize = 838;
+pub const S_YSKULL2: usize = 839;
+pub const S_STIM: usize = 840;
+pub const S_MEDI: usize = 841;
+pub const S_SOUL: usize = 842;
+pub const S_SOUL2: usize = 843;
+pub const S_SOUL3: usize = 844;
+pub const S_SOUL4: usize = 845;
+pub const S_SOUL5: usize = 846;
+pub const S_SOUL6: usize = 847;
+pub const S_PINV: usize = 848;
+pub const S_PINV2: usize = 849;
+pub const S_PINV3: usize = 850;
+pub const S_PINV4: usize = 851;
+pub const S_PSTR: usize = 852;
+pub const S_PINS: usize = 853;
+pub const S_PINS2: usize = 854;
+pub const S_PINS3: usize = 855;
+pub const S_PINS4: usize = 856;
+pub const S_MEGA: usize = 857;
+pub const S_MEGA2: usize = 858;
+pub const S_MEGA3: usize = 859;
+pub const S_MEGA4: usize = 860;
+pub const S_SUIT: usize = 861;
+pub const S_PMAP: usize = 862;
+pub const S_PMAP2: usize = 863;
+pub const S_PMAP3: usize = 864;
+pub const S_PMAP4: usize = 865;
+pub const S_PMAP5: usize = 866;
+pub const S_PMAP6: usize = 867;
+pub const S_PVIS: usize = 868;
+pub const S_PVIS2: usize = 869;
+pub const S_CLIP: usize = 870;
+pub const S_AMMO: usize = 871;
+pub const S_ROCK: usize = 872;
+pub const S_BROK: usize = 873;
+pub const S_CELL: usize = 874;
+pub const S_CELP: usize = 875;
+pub const S_SHEL: usize = 876;
+pub const S_SBOX: usize = 877;
+pub const S_BPAK: usize = 878;
+pub const S_BFUG: usize = 879;
+pub const S_MGUN: usize = 880;
+pub const S_CSAW: usize = 881;
+pub const S_LAUN: usize = 882;
+pub const S_PLAS: usize = 883;
+pub const S_SHOT: usize = 884;
+pub const S_SHOT2: usize = 885;
+pub const S_COLU: usize = 886;
+pub const S_STALAG: usize = 887;
+pub const S_BLOODYTWITCH: usize = 888;
+pub const S_BLOODYTWITCH2: usize = 889;
+pub const S_BLOODYTWITCH3: usize = 890;
+pub const S_BLOODYTWITCH4: usize = 891;
+pub const S_DEADTORSO: usize = 892;
+pub const S_DEADBOTTOM: usize = 893;
+pub const S_HEADSONSTICK: usize = 894;
+pub const S_GIBS: usize = 895;
+pub const S_HEADONASTICK: usize = 896;
+pub const S_HEADCANDLES: usize = 897;
+pub const S_HEADCANDLES2: usize = 898;
+pub const S_DEADSTICK: usize = 899;
+pub const S_LIVESTICK: usize = 900;
+pub const S_LIVESTICK2: usize = 901;
+pub const S_MEAT2: usize = 902;
+pub const S_MEAT3: usize = 903;
+pub const S_MEAT4: usize = 904;
+pub const S_MEAT5: usize = 905;
+pub const S_STALAGTITE: usize = 906;
+pub const S_TALLGRNCOL: usize = 907;
+pub const S_SHRTGRNCOL: usize = 908;
+pub const S_TALLREDCOL: usize = 909;
+pub const S_SHRTREDCOL: usize = 910;
+pub const S_CANDLESTIK: usize = 911;
+pub const S_CANDELABRA: usize = 912;
+pub const S_SKULLCOL: usize = 913;
+pub const S_TORCHTREE: usize = 914;
+pub const S_BIGTREE: usize = 915;
+pub const S_TECHPILLAR: usize = 916;
+pub const S_EVILEYE: usize = 917;
+pub const S_EVILEYE2: usize = 918;
+pub const S_EVILEYE3: usize = 919;
+pub const S_EVILEYE4: usize = 920;
+pub const S_FLOATSKULL: usize = 921;
+pub const S_FLOATSKULL2: usize = 922;
+pub const S_FLOATSKULL3: usize = 923;
+pub const S_HEARTCOL: usize = 924;
+pub const S_HEARTCOL2: usize = 925;
+pub const S_BLUETORCH: usize = 926;
+pub const S_BLUETORCH2: usize = 927;
+pub const S_BLUETORCH3: usize = 928;
+pub const S_BLUETORCH4: usize = 929;
+pub const S_GREENTORCH: usize = 930;
+pub const S_GREENTORCH2: usize = 931;
+pub const S_GREENTORCH3: usize = 932;
+pub const S_GREENTORCH4: usize = 933;
+pub const S_REDTORCH: usize = 934;
+pub const S_REDTORCH2: usize = 935;
+pub const S_REDTORCH3: usize = 936;
+pub const S_REDTORCH4: usize = 937;
+pub const S_BTORCHSHRT: usize = 938;
+pub const S_BTORCHSHRT2: usize = 939;
+pub const S_BTORCHSHRT3: usize = 940;
+pub const S_BTORCHSHRT4: usize = 941;
+pub const S_GTORCHSHRT: usize = 942;
+pub const S_GTORCHSHRT2: usize = 943;
+pub const S_GTORCHSHRT3: usize = 944;
+pub const S_GTORCHSHRT4: usize = 945;
+pub const S_RTORCHSHRT: usize = 946;
+pub const S_RTORCHSHRT2: usize = 947;
+pub const S_RTORCHSHRT3: usize = 948;
+pub const S_RTORCHSHRT4: usize = 949;
+pub const S_HANGNOGUTS: usize = 950;
+pub const S_HANGBNOBRAIN: usize = 951;
+pub const S_HANGTLOOKDN: usize = 952;
+pub const S_HANGTSKULL: usize = 953;
+pub const S_HANGTLOOKUP: usize = 954;
+pub const S_HANGTNOBRAIN: usize = 955;
+pub const S_COLONGIBS: usize = 956;
+pub const S_SMALLPOOL: usize = 957;
+pub const S_BRAINSTEM: usize = 958;
+pub const S_TECHLAMP: usize = 959;
+pub const S_TECHLAMP2: usize = 960;
+pub const S_TECHLAMP3: usize = 961;
+pub const S_TECHLAMP4: usize = 962;
+pub const S_TECH2LAMP: usize = 963;
+pub const S_TECH2LAMP2: usize = 964;
+pub const S_TECH2LAMP3: usize = 965;
+pub const S_TECH2LAMP4: usize = 966;
+
+pub static STATES: [StateInfo; NUM_STATES] = [
+    state(SPR_TROO, 0, -1, Action::None, S_NULL),
+    state(SPR_SHTG, 4, 0, Action::Light0, S_NULL),
+    state(SPR_PUNG, 0, 1, Action::WeaponReady, S_PUNCH),
+    state(SPR_PUNG, 0, 1, Action::Lower, S_PUNCHDOWN),
+    state(SPR_PUNG, 0, 1, Action::Raise, S_PUNCHUP),
+    state(SPR_PUNG, 1, 4, Action::None, S_PUNCH2),
+    state(SPR_PUNG, 2, 4, Action::Punch, S_PUNCH3),
+    state(SPR_PUNG, 3, 5, Action::None, S_PUNCH4),
+    state(SPR_PUNG, 2, 4, Action::None, S_PUNCH5),
+    state(SPR_PUNG, 1, 5, Action::ReFire, S_PUNCH),
+    state(SPR_PISG, 0, 1, Action::WeaponReady, S_PISTOL),
+    state(SPR_PISG, 0, 1, Action::Lower, S_PISTOLDOWN),
+    state(SPR_PISG, 0, 1, Action::Raise, S_PISTOLUP),
+    state(SPR_PISG, 0, 4, Action::None, S_PISTOL2),
+    state(SPR_PISG, 1, 6, Action::FirePistol, S_PISTOL3),
+    state(SPR_PISG, 2, 4, Action::None, S_PISTOL4),
+    state(SPR_PISG, 1, 5, Action::ReFire, S_PISTOL),
+    state(SPR_PISF, FF_FULLBRIGHT, 7, Action::Light1, S_LIGHTDONE),
+    state(SPR_SHTG, 0, 1, Action::WeaponReady, S_SGUN),
+    state(SPR_SHTG, 0, 1, Action::Lower, S_SGUNDOWN),
+    state(SPR_SHTG, 0, 1, Action::Raise, S_SGUNUP),
+    state(SPR_SHTG, 0, 3, Action::None, S_SGUN2),
+    state(SPR_SHTG, 0, 7, Action::FireShotgun, S_SGUN3),
+    state(SPR_SHTG, 1, 5, Action::None, S_SGUN4),
+    state(SPR_SHTG, 2, 5, Action::None, S_SGUN5),
+    state(SPR_SHTG, 3, 4, Action::None, S_SGUN6),
+    state(SPR_SHTG, 2, 5, Action::None, S_SGUN7),
+    state(SPR_SHTG, 1, 5, Action::None, S_SGUN8),
+    state(SPR_SHTG, 0, 3, Action::None, S_SGUN9),
+    state(SPR_SHTG, 0, 7, Action::ReFire, S_SGUN),
+    state(SPR_SHTF, FF_FULLBRIGHT, 4, Action::Light1, S_SGUNFLASH2),
+    state(SPR_SHTF, FF_FULLBRIGHT | 1, 3, Action::Light2, S_LIGHTDONE),
+    state(SPR_SHT2, 0, 1, Action::WeaponReady, S_DSGUN),
+    state(SPR_SHT2, 0, 1, Action::Lower, S_DSGUNDOWN),
+    state(SPR_SHT2, 0, 1, Action::Raise, S_DSGUNUP),
+    state(SPR_SHT2, 0, 3, Action::None, S_DSGUN2),
+    state(SPR_SHT2, 0, 7, Action::FireShotgun2, S_DSGUN3),
+    state(SPR_SHT2, 1, 7, Action::None, S_DSGUN4),
+    state(SPR_SHT2, 2, 7, Action::CheckReload, S_DSGUN5),
+    state(SPR_SHT2, 3, 7, Action::OpenShotgun2, S_DSGUN6),
+    state(SPR_SHT2, 4, 7, Action::None, S_DSGUN7),
+    state(SPR_SHT2, 5, 7, Action::LoadShotgun2, S_DSGUN8),
+    state(SPR_SHT2, 6, 6, Action::None, S_DSGUN9),
+    state(SPR_SHT2, 7, 6, Action::CloseShotgun2, S_DSGUN10),
+    state(SPR_SHT2, 0, 5, Action::ReFire, S_DSGUN),
+    state(SPR_SHT2, 1, 7, Action::None, S_DSNR2),
+    state(SPR_SHT2, 0, 3, Action::None, S_DSGUNDOWN),
+    state(
+        SPR_SHT2,
+        FF_FULLBRIGHT | 8,
+        5,
+        Action::Light1,
+        S_DSGUNFLASH2,
+    ),
+    state(SPR_SHT2, FF_FULLBRIGHT | 9, 4, Action::Light2, S_LIGHTDONE),
+    state(SPR_CHGG, 0, 1, Action::WeaponReady, S_CHAIN),
+    state(SPR_CHGG, 0, 1, Action::Lower, S_CHAINDOWN),
+    state(SPR_CHGG, 0, 1, Action::Raise, S_CHAINUP),
+    state(SPR_CHGG, 0, 4, Action::FireCGun, S_CHAIN2),
+    state(SPR_CHGG, 1, 4, Action::FireCGun, S_CHAIN3),
+    state(SPR_CHGG, 1, 0, Action::ReFire, S_CHAIN),
+    state(SPR_CHGF, FF_FULLBRIGHT, 5, Action::Light1, S_LIGHTDONE),
+    state(SPR_CHGF, FF_FULLBRIGHT | 1, 5, Action::Light2, S_LIGHTDONE),
+    state(SPR_MISG, 0, 1, Action::WeaponReady, S_MISSILE),
+    state(SPR_MISG, 0, 1, Action::Lower, S_MISSILEDOWN),
+    state(SPR_MISG, 0, 1, Action::Raise, S_MISSILEUP),
+    state(SPR_MISG, 1, 8, Action::GunFlash, S_MISSILE2),
+    state(SPR_MISG, 1, 12, Action::FireMissile, S_MISSILE3),
+    state(SPR_MISG, 1, 0, Action::ReFire, S_MISSILE),
+    state(SPR_MISF, FF_FULLBRIGHT, 3, Action::Light1, S_MISSILEFLASH2),
+    state(
+        SPR_MISF,
+        FF_FULLBRIGHT | 1,
+        4,
+        Action::None,
+        S_MISSILEFLASH3,
+    ),
+    state(
+        SPR_MISF,
+        FF_FULLBRIGHT | 2,
+        4,
+        Action::Light2,
+        S_MISSILEFLASH4,
+    ),
+    state(SPR_MISF, FF_FULLBRIGHT | 3, 4, Action::Light2, S_LIGHTDONE),
+    state(SPR_SAWG, 2, 4, Action::WeaponReady, S_SAWB),
+    state(SPR_SAWG, 3, 4, Action::WeaponReady, S_SAW),
+    state(SPR_SAWG, 2, 1, Action::Lower, S_SAWDOWN),
+    state(SPR_SAWG, 2, 1, Action::Raise, S_SAWUP),
+    state(SPR_SAWG, 0, 4, Action::Saw, S_SAW2),
+    state(SPR_SAWG, 1, 4, Action::Saw, S_SAW3),
+    state(SPR_SAWG, 1, 0, Action::ReFire, S_SAW),
+    state(SPR_PLSG, 0, 1, Action::WeaponReady, S_PLASMA),
+    state(SPR_PLSG, 0, 1, Action::Lower, S_PLASMADOWN),
+    state(SPR_PLSG, 0, 1, Action::Raise, S_PLASMAUP),
+    state(SPR_PLSG, 0, 3, Action::FirePlasma, S_PLASMA2),
+    state(SPR_PLSG, 1, 20, Action::ReFire, S_PLASMA),
+    state(SPR_PLSF, FF_FULLBRIGHT, 4, Action::Light1, S_LIGHTDONE),
+    state(SPR_PLSF, FF_FULLBRIGHT | 1, 4, Action::Light1, S_LIGHTDONE),
+    state(SPR_BFGG, 0, 1, Action::WeaponReady, S_BFG),
+    state(SPR_BFGG, 0, 1, Action::Lower, S_BFGDOWN),
+    state(SPR_BFGG, 0, 1, Action::Raise, S_BFGUP),
+    state(SPR_BFGG, 0, 20, Action::BFGsound, S_BFG2),
+    state(SPR_BFGG, 1, 10, Action::GunFlash, S_BFG3),
+    state(SPR_BFGG, 1, 10, Action::FireBFG, S_BFG4),
+    state(SPR_BFGG, 1, 20, Action::ReFire, S_BFG),
+    state(SPR_BFGF, FF_FULLBRIGHT, 11, Action::Light1, S_BFGFLASH2),
+    state(SPR_BFGF, FF_FULLBRIGHT | 1, 6, Action::Light2, S_LIGHTDONE),
+    state(SPR_BLUD, 2, 8, Action::None, S_BLOOD2),
+    state(SPR_BLUD, 1, 8, Action::None, S_BLOOD3),
+    state(SPR_BLUD, 0, 8, Action::None, S_NULL),
+    state(SPR_PUFF, FF_FULLBRIGHT, 4, Action::None, S_PUFF2),
+    state(SPR_PUFF, 1, 4, Action::None, S_PUFF3),
+    state(SPR_PUFF, 2, 4, Action::None, S_PUFF4),
+    state(SPR_PUFF, 3, 4, Action::None, S_NULL),
+    state(SPR_BAL1, FF_FULLBRIGHT, 4, Action::None, S_TBALL2),
+    state(SPR_BAL1, FF_FULLBRIGHT | 1, 4, Action::None, S_TBALL1),
+    state(SPR_BAL1, FF_FULLBRIGHT | 2, 6, Action::None, S_TBALLX2),
+    state(SPR_BAL1, FF_FULLBRIGHT | 3, 6, Action::None, S_TBALLX3),
+    state(SPR_BAL1, FF_FULLBRIGHT | 4, 6, Action::None, S_NULL),
+    state(SPR_BAL2, FF_FULLBRIGHT, 4, Action::None, S_RBALL2),
+    state(SPR_BAL2, FF_FULLBRIGHT | 1, 4, Action::None, S_RBALL1),
+    state(SPR_BAL2, FF_FULLBRIGHT | 2, 6, Action::None, S_RBALLX2),
+    state(SPR_BAL2, FF_FULLBRIGHT | 3, 6, Action::None, S_RBALLX3),
+    state(SPR_BAL2, FF_FULLBRIGHT | 4, 6, Action::None, S_NULL),
+    state(SPR_PLSS, FF_FULLBRIGHT, 6, Action::None, S_PLASBALL2),
+    state(SPR_PLSS, FF_FULLBRIGHT | 1, 6, Action::None, S_PLASBALL),
+    state(SPR_PLSE, FF_FULLBRIGHT, 4, Action::None, S_PLASEXP2),
+    state(SPR_PLSE, FF_FULLBRIGHT | 1, 4, Action::None, S_PLASEXP3),
+    state(SPR_PLSE, FF_FULLBRIGHT | 2, 4, Action::None, S_PLASEXP4),
+    state(SPR_PLSE, FF_FULLBRIGHT | 3, 4, Action::None, S_PLASEXP5),
+    state(SPR_PLSE, FF_FULLBRIGHT | 4, 4, Action::None, S_NULL),
+    state(SPR_MISL, FF_FULLBRIGHT, 1, Action::None, S_ROCKET),
+    state(SPR_BFS1, FF_FULLBRIGHT, 4, Action::None, S_BFGSHOT2),
+    state(SPR_BFS1, FF_FULLBRIGHT | 1, 4, Action::None, S_BFGSHOT),
+    state(SPR_BFE1, FF_FULLBRIGHT, 8, Action::None, S_BFGLAND2),
+    state(SPR_BFE1, FF_FULLBRIGHT | 1, 8, Action::None, S_BFGLAND3),
+    state(SPR_BFE1, FF_FULLBRIGHT | 2, 8, Action::BFGSpray, S_BFGLAND4),
+    state(SPR_BFE1, FF_FULLBRIGHT | 3, 8, Action::None, S_BFGLAND5),
+    state(SPR_BFE1, FF_FULLBRIGHT | 4, 8, Action::None, S_BFGLAND6),
+    state(SPR_BFE1, FF_FULLBRIGHT | 5, 8, Action::None, S_NULL),
+    state(SPR_BFE2, FF_FULLBRIGHT, 8, Action::None, S_BFGEXP2),
+    state(SPR_BFE2, FF_FULLBRIGHT | 1, 8, Action::None, S_BFGEXP3),
+    state(SPR_BFE2, FF_FULLBRIGHT | 2, 8, Action::None, S_BFGEXP4),
+    state(SPR_BFE2, FF_FULLBRIGHT | 3, 8, Action::None, S_NULL),
+    state(SPR_MISL, FF_FULLBRIGHT | 1, 8, Action::Explode, S_EXPLODE2),
+    state(SPR_MISL, FF_FULLBRIGHT | 2, 6, Action::None, S_EXPLODE3),
+    state(SPR_MISL, FF_FULLBRIGHT | 3, 4, Action::None, S_NULL),
+    state(SPR_TFOG, FF_FULLBRIGHT, 6, Action::None, S_TFOG01),
+    state(SPR_TFOG, FF_FULLBRIGHT | 1, 6, Action::None, S_TFOG02),
+    state(SPR_TFOG, FF_FULLBRIGHT, 6, Action::None, S_TFOG2),
+    state(SPR_TFOG, FF_FULLBRIGHT | 1, 6, Action::None, S_TFOG3),
+    state(SPR_TFOG, FF_FULLBRIGHT | 2, 6, Action::None, S_TFOG4),
+    state(SPR_TFOG, FF_FULLBRIGHT | 3, 6, Action::None, S_TFOG5),
+    state(SPR_TFOG, FF_FULLBRIGHT | 4, 6, Action::None, S_TFOG6),
+    state(SPR_TFOG, FF_FULLBRIGHT | 5, 6, Action::None, S_TFOG7),
+    state(SPR_TFOG, FF_FULLBRIGHT | 6, 6, Action::None, S_TFOG8),
+    state(SPR_TFOG, FF_FULLBRIGHT | 7, 6, Action::None, S_TFOG9),
+    state(SPR_TFOG, FF_FULLBRIGHT | 8, 6, Action::None, S_TFOG10),
+    state(SPR_TFOG, FF_FULLBRIGHT | 9, 6, Action::None, S_NULL),
+    state(SPR_IFOG, FF_FULLBRIGHT, 6, Action::None, S_IFOG01),
+    state(SPR_IFOG, FF_FULLBRIGHT | 1, 6, Action::None, S_IFOG02),
+    state(SPR_IFOG, FF_FULLBRIGHT, 6, Action::None, S_IFOG2),
+    state(SPR_IFOG, FF_FULLBRIGHT | 1, 6, Action::None, S_IFOG3),
+    state(SPR_IFOG, FF_FULLBRIGHT | 2, 6, Action::None, S_IFOG4),
+    state(SPR_IFOG, FF_FULLBRIGHT | 3, 6, Action::None, S_IFOG5),
+    state(SPR_IFOG, FF_FULLBRIGHT | 4, 6, Action::None, S_NULL),
+    state(SPR_PLAY, 0, -1, Action::None, S_NULL),
+    state(SPR_PLAY, 0, 4, Action::None, S_PLAY_RUN2),
+    state(SPR_PLAY, 1, 4, Action::None, S_PLAY_RUN3),
+    state(SPR_PLAY, 2, 4, Action::None, S_PLAY_RUN4),
+    state(SPR_PLAY, 3, 4, Action::None, S_PLAY_RUN1),
+    state(SPR_PLAY, 4, 12, Action::None, S_PLAY),
+    state(SPR_PLAY, FF_FULLBRIGHT | 5, 6, Action::None, S_PLAY_ATK1),
+    state(SPR_PLAY, 6, 4, Action::None, S_PLAY_PAIN2),
+    state(SPR_PLAY, 6, 4, Action::Pain, S_PLAY),
+    state(SPR_PLAY, 7, 10, Action::None, S_PLAY_DIE2),
+    state(SPR_PLAY, 8, 10, Action::PlayerScream, S_PLAY_DIE3),
+    state(SPR_PLAY, 9, 10, Action::Fall, S_PLAY_DIE4),
+    state(SPR_PLAY, 10, 10, Action::None, S_PLAY_DIE5),
+    state(SPR_PLAY, 11, 10, Action::None, S_PLAY_DIE6),
+    state(SPR_PLAY, 12, 10, Action::None, S_PLAY_DIE7),
+    state(SPR_PLAY, 13, -1, Action::None, S_NULL),
+    state(SPR_PLAY, 14, 5, Action::None, S_PLAY_XDIE2),
+    state(SPR_PLAY, 15, 5, Action::XScream, S_PLAY_XDIE3),
+    state(SPR_PLAY, 16, 5, Action::Fall, S_PLAY_XDIE4),
+    state(SPR_PLAY, 17, 5, Action::None, S_PLAY_XDIE5),
+    state(SPR_PLAY, 18, 5, Action::None, S_PLAY_XDIE6),
+    state(SPR_PLAY, 19, 5, Action::None, S_PLAY_XDIE7),
+    state(SPR_PLAY, 20, 5, Action::None, S_PLAY_XDIE8),
+    state(SPR_PLAY, 21, 5, Action::None, S_PLAY_XDIE9),
+    state(SPR_PLAY, 22, -1, Action::None, S_NULL),
+    state(SPR_POSS, 0, 10, Action::Look, S_POSS_STND2),
+    state(SPR_POSS, 1, 10, Action::Look, S_POSS_STND),
+    state(SPR_POSS, 0, 4, Action::Chase, S_POSS_RUN2),
+    state(SPR_POSS, 0, 4, Action::Chase, S_POSS_RUN3),
+    state(SPR_POSS, 1, 4, Action::Chase, S_POSS_RUN4),
+    state(SPR_POSS, 1, 4, Action::Chase, S_POSS_RUN5),
+    state(SPR_POSS, 2, 4, Action::Chase, S_POSS_RUN6),
+    state(SPR_POSS, 2, 4, Action::Chase, S_POSS_RUN7),
+    state(SPR_POSS, 3, 4, Action::Chase, S_POSS_RUN8),
+    state(SPR_POSS, 3, 4, Action::Chase, S_POSS_RUN1),
+    state(SPR_POSS, 4, 10, Action::FaceTarget, S_POSS_ATK2),
+    state(SPR_POSS, 5, 8, Action::PosAttack, S_POSS_ATK3),
+    state(SPR_POSS, 4, 8, Action::None, S_POSS_RUN1),
+    state(SPR_POSS, 6, 3, Action::None, S_POSS_PAIN2),
+    state(SPR_POSS, 6, 3, Action::Pain, S_POSS_RUN1),
+    state(SPR_POSS, 7, 5, Action::None, S_POSS_DIE2),
+    state(SPR_POSS, 8, 5, Action::Scream, S_POSS_DIE3),
+    state(SPR_POSS, 9, 5, Action::Fall, S_POSS_DIE4),
+    state(SPR_POSS, 10, 5, Action::None, S_POSS_DIE5),
+    state(SPR_POSS, 11, -1, Action::None, S_NULL),
+    state(SPR_POSS, 12, 5, Action::None, S_POSS_XDIE2),
+    state(SPR_POSS, 13, 5, Action::XScream, S_POSS_XDIE3),
+    state(SPR_POSS, 14, 5, Action::Fall, S_POSS_XDIE4),
+    state(SPR_POSS, 15, 5, Action::None, S_POSS_XDIE5),
+    state(SPR_POSS, 16, 5, Action::None, S_POSS_XDIE6),
+    state(SPR_POSS, 17, 5, Action::None, S_POSS_XDIE7),
+    state(SPR_POSS, 18, 5, Action::None, S_POSS_XDIE8),
+    state(SPR_POSS, 19, 5, Action::None, S_POSS_XDIE9),
+    state(SPR_POSS, 20, -1, Action::None, S_NULL),
+    state(SPR_POSS, 10, 5, Action::None, S_POSS_RAISE2),
+    state(SPR_POSS, 9, 5, Action::None, S_POSS_RAISE3),
+    state(SPR_POSS, 8, 5, Action::None, S_POSS_RAISE4),
+    state(SPR_POSS, 7, 5, Action::None, S_POSS_RUN1),
+    state(SPR_SPOS, 0, 10, Action::Look, S_SPOS_STND2),
+    state(SPR_SPOS, 1, 10, Action::Look, S_SPOS_STND),
+    state(SPR_SPOS, 0, 3, Action::Chase, S_SPOS_RUN2),
+    state(SPR_SPOS, 0, 3, Action::Chase, S_SPOS_RUN3),
+    state(SPR_SPOS, 1, 3, Action::Chase, S_SPOS_RUN4),
+    state(SPR_SPOS, 1, 3, Action::Chase, S_SPOS_RUN5),
+    state(SPR_SPOS, 2, 3, Action::Chase, S_SPOS_RUN6),
+    state(SPR_SPOS, 2, 3, Action::Chase, S_SPOS_RUN7),
+    state(SPR_SPOS, 3, 3, Action::Chase, S_SPOS_RUN8),
+    state(SPR_SPOS, 3, 3, Action::Chase, S_SPOS_RUN1),
+    state(SPR_SPOS, 4, 10, Action::FaceTarget, S_SPOS_ATK2),
+    state(
+        SPR_SPOS,
+        FF_FULLBRIGHT | 5,
+        10,
+        Action::SPosAttack,
+        S_SPOS_ATK3,
+    ),
+    state(SPR_SPOS, 4, 10, Action::None, S_SPOS_RUN1),
+    state(SPR_SPOS, 6, 3, Action::None, S_SPOS_PAIN2),
+    state(SPR_SPOS, 6, 3, Action::Pain, S_SPOS_RUN1),
+    state(SPR_SPOS, 7, 5, Action::None, S_SPOS_DIE2),
+    state(SPR_SPOS, 8, 5, Action::Scream, S_SPOS_DIE3),
+    state(SPR_SPOS, 9, 5, Action::Fall, S_SPOS_DIE4),
+    state(SPR_SPOS, 10, 5, Action::None, S_SPOS_DIE5),
+    state(SPR_SPOS, 11, -1, Action::None, S_NULL),
+    state(SPR_SPOS, 12, 5, Action::None, S_SPOS_XDIE2),
+    state(SPR_SPOS, 13, 5, Action::XScream, S_SPOS_XDIE3),
+    state(SPR_SPOS, 14, 5, Action::Fall, S_SPOS_XDIE4),
+    state(SPR_SPOS, 15, 5, Action::None, S_SPOS_XDIE5),
+    state(SPR_SPOS, 16, 5, Action::None, S_SPOS_XDIE6),
+    state(SPR_SPOS, 17, 5, Action::None, S_SPOS_XDIE7),
+    state(SPR_SPOS, 18, 5, Action::None, S_SPOS_XDIE8),
+    state(SPR_SPOS, 19, 5, Action::None, S_SPOS_XDIE9),
+    state(SPR_SPOS, 20, -1, Action::None, S_NULL),
+    state(SPR_SPOS, 11, 5, Action::None, S_SPOS_RAISE2),
+    state(SPR_SPOS, 10, 5, Action::None, S_SPOS_RAISE3),
+    state(SPR_SPOS, 9, 5, Action::None, S_SPOS_RAISE4),
+    state(SPR_SPOS, 8, 5, Action::None, S_SPOS_RAISE5),
+    state(SPR_SPOS, 7, 5, Action::None, S_SPOS_RUN1),
+    state(SPR_VILE, 0, 10, Action::Look, S_VILE_STND2),
+    state(SPR_VILE, 1, 10, Action::Look, S_VILE_STND),
+    state(SPR_VILE, 0, 2, Action::VileChase, S_VILE_RUN2),
+    state(SPR_VILE, 0, 2, Action::VileChase, S_VILE_RUN3),
+    state(SPR_VILE, 1, 2, Action::VileChase, S_VILE_RUN4),
+    state(SPR_VILE, 1, 2, Action::VileChase, S_VILE_RUN5),
+    state(SPR_VILE, 2, 2, Action::VileChase, S_VILE_RUN6),
+    state(SPR_VILE, 2, 2, Action::VileChase, S_VILE_RUN7),
+    state(SPR_VILE, 3, 2, Action::VileChase, S_VILE_RUN8),
+    state(SPR_VILE, 3, 2, Action::VileChase, S_VILE_RUN9),
+    state(SPR_VILE, 4, 2, Action::VileChase, S_VILE_RUN10),
+    state(SPR_VILE, 4, 2, Action::VileChase, S_VILE_RUN11),
+    state(SPR_VILE, 5, 2, Action::VileChase, S_VILE_RUN12),
+    state(SPR_VILE, 5, 2, Action::VileChase, S_VILE_RUN1),
+    state(
+        SPR_VILE,
+        FF_FULLBRIGHT | 6,
+        0,
+        Action::VileStart,
+        S_VILE_ATK2,
+    ),
+    state(
+        SPR_VILE,
+        FF_FULLBRIGHT | 6,
+        10,
+        Action::FaceTarget,
+        S_VILE_ATK3,
+    ),
+    state(
+        SPR_VILE,
+        FF_FULLBRIGHT | 7,
+        8,
+        Action::VileTarget,
+        S_VILE_ATK4,
+    ),
+    state(
+        SPR_VILE,
+        FF_FULLBRIGHT | 8,
+        8,
+        Action::FaceTarget,
+        S_VILE_ATK5,
+    ),
+    state(
+        SPR_VILE,
+        FF_FULLBRIGHT | 9,
+        8,
+        Action::FaceTarget,
+        S_VILE_ATK6,
+    ),
+    state(
+        SPR_VILE,
+        FF_FULLBRIGHT | 10,
+        8,
+        Action::FaceTarget,
+        S_VILE_ATK7,
+    ),
+    state(
+        SPR_VILE,
+        FF_FULLBRIGHT | 11,
+        8,
+        Action::FaceTarget,
+        S_VILE_ATK8,
+    ),
+    state(
+        SPR_VILE,
+        FF_FULLBRIGHT | 12,
+        8,
+        Action::FaceTarget,
+        S_VILE_ATK9,
+    ),
+    state(
+        SPR_VILE,
+        FF_FULLBRIGHT | 13,
+        8,
+        Action::FaceTarget,
+        S_VILE_ATK10,
+    ),
+    state(
+        SPR_VILE,
+        FF_FULLBRIGHT | 14,
+        8,
+        Action::VileAttack,
+        S_VILE_ATK11,
+    ),
+    state(SPR_VILE, FF_FULLBRIGHT | 15, 20, Action::None, S_VILE_RUN1),
+    state(SPR_VILE, FF_FULLBRIGHT | 26, 10, Action::None, S_VILE_HEAL2),
+    state(SPR_VILE, FF_FULLBRIGHT | 27, 10, Action::None, S_VILE_HEAL3),
+    state(SPR_VILE, FF_FULLBRIGHT | 28, 10, Action::None, S_VILE_RUN1),
+    state(SPR_VILE, 16, 5, Action::None, S_VILE_PAIN2),
+    state(SPR_VILE, 16, 5, Action::Pain, S_VILE_RUN1),
+    state(SPR_VILE, 16, 7, Action::None, S_VILE_DIE2),
+    state(SPR_VILE, 17, 7, Action::Scream, S_VILE_DIE3),
+    state(SPR_VILE, 18, 7, Action::Fall, S_VILE_DIE4),
+    state(SPR_VILE, 19, 7, Action::None, S_VILE_DIE5),
+    state(SPR_VILE, 20, 7, Action::None, S_VILE_DIE6),
+    state(SPR_VILE, 21, 7, Action::None, S_VILE_DIE7),
+    state(SPR_VILE, 22, 7, Action::None, S_VILE_DIE8),
+    state(SPR_VILE, 23, 7, Action::None, S_VILE_DIE9),
+    state(SPR_VILE, 24, 5, Action::None, S_VILE_DIE10),
+    state(SPR_VILE, 25, -1, Action::None, S_NULL),
+    state(SPR_FIRE, FF_FULLBRIGHT, 2, Action::StartFire, S_FIRE2),
+    state(SPR_FIRE, FF_FULLBRIGHT | 1, 2, Action::Fire, S_FIRE3),
+    state(SPR_FIRE, FF_FULLBRIGHT, 2, Action::Fire, S_FIRE4),
+    state(SPR_FIRE, FF_FULLBRIGHT | 1, 2, Action::Fire, S_FIRE5),
+    state(SPR_FIRE, FF_FULLBRIGHT | 2, 2, Action::FireCrackle, S_FIRE6),
+    state(SPR_FIRE, FF_FULLBRIGHT | 1, 2, Action::Fire, S_FIRE7),
+    state(SPR_FIRE, FF_FULLBRIGHT | 2, 2, Action::Fire, S_FIRE8),
+    state(SPR_FIRE, FF_FULLBRIGHT | 1, 2, Action::Fire, S_FIRE9),
+    state(SPR_FIRE, FF_FULLBRIGHT | 2, 2, Action::Fire, S_FIRE10),
+    state(SPR_FIRE, FF_FULLBRIGHT | 3, 2, Action::Fire, S_FIRE11),
+    state(SPR_FIRE, FF_FULLBRIGHT | 2, 2, Action::Fire, S_FIRE12),
+    state(SPR_FIRE, FF_FULLBRIGHT | 3, 2, Action::Fire, S_FIRE13),
+    state(SPR_FIRE, FF_FULLBRIGHT | 2, 2, Action::Fire, S_FIRE14),
+    state(SPR_FIRE, FF_FULLBRIGHT | 3, 2, Action::Fire, S_FIRE15),
+    state(SPR_FIRE, FF_FULLBRIGHT | 4, 2, Action::Fire, S_FIRE16),
+    state(SPR_FIRE, FF_FULLBRIGHT | 3, 2, Action::Fire, S_FIRE17),
+    state(SPR_FIRE, FF_FULLBRIGHT | 4, 2, Action::Fire, S_FIRE18),
+    state(SPR_FIRE, FF_FULLBRIGHT | 3, 2, Action::Fire, S_FIRE19),
+    state(
+        SPR_FIRE,
+        FF_FULLBRIGHT | 4,
+        2,
+        Action::FireCrackle,
+        S_FIRE20,
+    ),
+    state(SPR_FIRE, FF_FULLBRIGHT | 5, 2, Action::Fire, S_FIRE21),
+    state(SPR_FIRE, FF_FULLBRIGHT | 4, 2, Action::Fire, S_FIRE22),
+    state(SPR_FIRE, FF_FULLBRIGHT | 5, 2, Action::Fire, S_FIRE23),
+    state(SPR_FIRE, FF_FULLBRIGHT | 4, 2, Action::Fire, S_FIRE24),
+    state(SPR_FIRE, FF_FULLBRIGHT | 5, 2, Action::Fire, S_FIRE25),
+    state(SPR_FIRE, FF_FULLBRIGHT | 6, 2, Action::Fire, S_FIRE26),
+    state(SPR_FIRE, FF_FULLBRIGHT | 7, 2, Action::Fire, S_FIRE27),
+    state(SPR_FIRE, FF_FULLBRIGHT | 6, 2, Action::Fire, S_FIRE28),
+    state(SPR_FIRE, FF_FULLBRIGHT | 7, 2, Action::Fire, S_FIRE29),
+    state(SPR_FIRE, FF_FULLBRIGHT | 6, 2, Action::Fire, S_FIRE30),
+    state(SPR_FIRE, FF_FULLBRIGHT | 7, 2, Action::Fire, S_NULL),
+    state(SPR_PUFF, 1, 4, Action::None, S_SMOKE2),
+    state(SPR_PUFF, 2, 4, Action::None, S_SMOKE3),
+    state(SPR_PUFF, 1, 4, Action::None, S_SMOKE4),
+    state(SPR_PUFF, 2, 4, Action::None, S_SMOKE5),
+    state(SPR_PUFF, 3, 4, Action::None, S_NULL),
+    state(SPR_FATB, FF_FULLBRIGHT, 2, Action::Tracer, S_TRACER2),
+    state(SPR_FATB, FF_FULLBRIGHT | 1, 2, Action::Tracer, S_TRACER),
+    state(SPR_FBXP, FF_FULLBRIGHT, 8, Action::None, S_TRACEEXP2),
+    state(SPR_FBXP, FF_FULLBRIGHT | 1, 6, Action::None, S_TRACEEXP3),
+    state(SPR_FBXP, FF_FULLBRIGHT | 2, 4, Action::None, S_NULL),
+    state(SPR_SKEL, 0, 10, Action::Look, S_SKEL_STND2),
+    state(SPR_SKEL, 1, 10, Action::Look, S_SKEL_STND),
+    state(SPR_SKEL, 0, 2, Action::Chase, S_SKEL_RUN2),
+    state(SPR_SKEL, 0, 2, Action::Chase, S_SKEL_RUN3),
+    state(SPR_SKEL, 1, 2, Action::Chase, S_SKEL_RUN4),
+    state(SPR_SKEL, 1, 2, Action::Chase, S_SKEL_RUN5),
+    state(SPR_SKEL, 2, 2, Action::Chase, S_SKEL_RUN6),
+    state(SPR_SKEL, 2, 2, Action::Chase, S_SKEL_RUN7),
+    state(SPR_SKEL, 3, 2, Action::Chase, S_SKEL_RUN8),
+    state(SPR_SKEL, 3, 2, Action::Chase, S_SKEL_RUN9),
+    state(SPR_SKEL, 4, 2, Action::Chase, S_SKEL_RUN10),
+    state(SPR_SKEL, 4, 2, Action::Chase, S_SKEL_RUN11),
+    state(SPR_SKEL, 5, 2, Action::Chase, S_SKEL_RUN12),
+    state(SPR_SKEL, 5, 2, Action::Chase, S_SKEL_RUN1),
+    state(SPR_SKEL, 6, 0, Action::FaceTarget, S_SKEL_FIST2),
+    state(SPR_SKEL, 6, 6, Action::SkelWhoosh, S_SKEL_FIST3),
+    state(SPR_SKEL, 7, 6, Action::FaceTarget, S_SKEL_FIST4),
+    state(SPR_SKEL, 8, 6, Action::SkelFist, S_SKEL_RUN1),
+    state(
+        SPR_SKEL,
+        FF_FULLBRIGHT | 9,
+        0,
+        Action::FaceTarget,
+        S_SKEL_MISS2,
+    ),
+    state(
+        SPR_SKEL,
+        FF_FULLBRIGHT | 9,
+        10,
+        Action::FaceTarget,
+        S_SKEL_MISS3,
+    ),
+    state(SPR_SKEL, 10, 10, Action::SkelMissile, S_SKEL_MISS4),
+    state(SPR_SKEL, 10, 10, Action::FaceTarget, S_SKEL_RUN1),
+    state(SPR_SKEL, 11, 5, Action::None, S_SKEL_PAIN2),
+    state(SPR_SKEL, 11, 5, Action::Pain, S_SKEL_RUN1),
+    state(SPR_SKEL, 11, 7, Action::None, S_SKEL_DIE2),
+    state(SPR_SKEL, 12, 7, Action::None, S_SKEL_DIE3),
+    state(SPR_SKEL, 13, 7, Action::Scream, S_SKEL_DIE4),
+    state(SPR_SKEL, 14, 7, Action::Fall, S_SKEL_DIE5),
+    state(SPR_SKEL, 15, 7, Action::None, S_SKEL_DIE6),
+    state(SPR_SKEL, 16, -1, Action::None, S_NULL),
+    state(SPR_SKEL, 16, 5, Action::None, S_SKEL_RAISE2),
+    state(SPR_SKEL, 15, 5, Action::None, S_SKEL_RAISE3),
+    state(SPR_SKEL, 14, 5, Action::None, S_SKEL_RAISE4),
+    state(SPR_SKEL, 13, 5, Action::None, S_SKEL_RAISE5),
+    state(SPR_SKEL, 12, 5, Action::None, S_SKEL_RAISE6),
+    state(SPR_SKEL, 11, 5, Action::None, S_SKEL_RUN1),
+    state(SPR_MANF, FF_FULLBRIGHT, 4, Action::None, S_FATSHOT2),
+    state(SPR_MANF, FF_FULLBRIGHT | 1, 4, Action::None, S_FATSHOT1),
+    state(SPR_MISL, FF_FULLBRIGHT | 1, 8, Action::None, S_FATSHOTX2),
+    state(SPR_MISL, FF_FULLBRIGHT | 2, 6, Action::None, S_FATSHOTX3),
+    state(SPR_MISL, FF_FULLBRIGHT | 3, 4, Action::None, S_NULL),
+    state(SPR_FATT, 0, 15, Action::Look, S_FATT_STND2),
+    state(SPR_FATT, 1, 15, Action::Look, S_FATT_STND),
+    state(SPR_FATT, 0, 4, Action::Chase, S_FATT_RUN2),
+    state(SPR_FATT, 0, 4, Action::Chase, S_FATT_RUN3),
+    state(SPR_FATT, 1, 4, Action::Chase, S_FATT_RUN4),
+    state(SPR_FATT, 1, 4, Action::Chase, S_FATT_RUN5),
+    state(SPR_FATT, 2, 4, Action::Chase, S_FATT_RUN6),
+    state(SPR_FATT, 2, 4, Action::Chase, S_FATT_RUN7),
+    state(SPR_FATT, 3, 4, Action::Chase, S_FATT_RUN8),
+    state(SPR_FATT, 3, 4, Action::Chase, S_FATT_RUN9),
+    state(SPR_FATT, 4, 4, Action::Chase, S_FATT_RUN10),
+    state(SPR_FATT, 4, 4, Action::Chase, S_FATT_RUN11),
+    state(SPR_FATT, 5, 4, Action::Chase, S_FATT_RUN12),
+    state(SPR_FATT, 5, 4, Action::Chase, S_FATT_RUN1),
+    state(SPR_FATT, 6, 20, Action::FatRaise, S_FATT_ATK2),
+    state(
+        SPR_FATT,
+        FF_FULLBRIGHT | 7,
+        10,
+        Action::FatAttack1,
+        S_FATT_ATK3,
+    ),
+    state(SPR_FATT, 8, 5, Action::FaceTarget, S_FATT_ATK4),
+    state(SPR_FATT, 6, 5, Action::FaceTarget, S_FATT_ATK5),
+    state(
+        SPR_FATT,
+        FF_FULLBRIGHT | 7,
+        10,
+        Action::FatAttack2,
+        S_FATT_ATK6,
+    ),
+    state(SPR_FATT, 8, 5, Action::FaceTarget, S_FATT_ATK7),
+    state(SPR_FATT, 6, 5, Action::FaceTarget, S_FATT_ATK8),
+    state(
+        SPR_FATT,
+        FF_FULLBRIGHT | 7,
+        10,
+        Action::FatAttack3,
+        S_FATT_ATK9,
+    ),
+    state(SPR_FATT, 8, 5, Action::FaceTarget, S_FATT_ATK10),
+    state(SPR_FATT, 6, 5, Action::FaceTarget, S_FATT_RUN1),
+    state(SPR_FATT, 9, 3, Action::None, S_FATT_PAIN2),
+    state(SPR_FATT, 9, 3, Action::Pain, S_FATT_RUN1),
+    state(SPR_FATT, 10, 6, Action::None, S_FATT_DIE2),
+    state(SPR_FATT, 11, 6, Action::Scream, S_FATT_DIE3),
+    state(SPR_FATT, 12, 6, Action::Fall, S_FATT_DIE4),
+    state(SPR_FATT, 13, 6, Action::None, S_FATT_DIE5),
+    state(SPR_FATT, 14, 6, Action::None, S_FATT_DIE6),
+    state(SPR_FATT, 15, 6, Action::None, S_FATT_DIE7),
+    state(SPR_FATT, 16, 6, Action::None, S_FATT_DIE8),
+    state(SPR_FATT, 17, 6, Action::None, S_FATT_DIE9),
+    state(SPR_FATT, 18, 6, Action::None, S_FATT_DIE10),
+    state(SPR_FATT, 19, -1, Action::BossDeath, S_NULL),
+    state(SPR_FATT, 17, 5, Action::None, S_FATT_RAISE2),
+    state(SPR_FATT, 16, 5, Action::None, S_FATT_RAISE3),
+    state(SPR_FATT, 15, 5, Action::None, S_FATT_RAISE4),
+    state(SPR_FATT, 14, 5, Action::None, S_FATT_RAISE5),
+    state(SPR_FATT, 13, 5, Action::None, S_FATT_RAISE6),
+    state(SPR_FATT, 12, 5, Action::None, S_FATT_RAISE7),
+    state(SPR_FATT, 11, 5, Action::None, S_FATT_RAISE8),
+    state(SPR_FATT, 10, 5, Action::None, S_FATT_RUN1),
+    state(SPR_CPOS, 0, 10, Action::Look, S_CPOS_STND2),
+    state(SPR_CPOS, 1, 10, Action::Look, S_CPOS_STND),
+    state(SPR_CPOS, 0, 3, Action::Chase, S_CPOS_RUN2),
+    state(SPR_CPOS, 0, 3, Action::Chase, S_CPOS_RUN3),
+    state(SPR_CPOS, 1, 3, Action::Chase, S_CPOS_RUN4),
+    state(SPR_CPOS, 1, 3, Action::Chase, S_CPOS_RUN5),
+    state(SPR_CPOS, 2, 3, Action::Chase, S_CPOS_RUN6),
+    state(SPR_CPOS, 2, 3, Action::Chase, S_CPOS_RUN7),
+    state(SPR_CPOS, 3, 3, Action::Chase, S_CPOS_RUN8),
+    state(SPR_CPOS, 3, 3, Action::Chase, S_CPOS_RUN1),
+    state(SPR_CPOS, 4, 10, Action::FaceTarget, S_CPOS_ATK2),
+    state(
+        SPR_CPOS,
+        FF_FULLBRIGHT | 4,
+        4,
+        Action::CPosAttack,
+        S_CPOS_ATK3,
+    ),
+    state(
+        SPR_CPOS,
+        FF_FULLBRIGHT | 5,
+        4,
+        Action::CPosAttack,
+        S_CPOS_ATK4,
+    ),
+    state(SPR_CPOS, 5, 1, Action::CPosRefire, S_CPOS_ATK2),
+    state(SPR_CPOS, 6, 3, Action::None, S_CPOS_PAIN2),
+    state(SPR_CPOS, 6, 3, Action::Pain, S_CPOS_RUN1),
+    state(SPR_CPOS, 7, 5, Action::None, S_CPOS_DIE2),
+    state(SPR_CPOS, 8, 5, Action::Scream, S_CPOS_DIE3),
+    state(SPR_CPOS, 9, 5, Action::Fall, S_CPOS_DIE4),
+    state(SPR_CPOS, 10, 5, Action::None, S_CPOS_DIE5),
+    state(SPR_CPOS, 11, 5, Action::None, S_CPOS_DIE6),
+    state(SPR_CPOS, 12, 5, Action::None, S_CPOS_DIE7),
+    state(SPR_CPOS, 13, -1, Action::None, S_NULL),
+    state(SPR_CPOS, 14, 5, Action::None, S_CPOS_XDIE2),
+    state(SPR_CPOS, 15, 5, Action::XScream, S_CPOS_XDIE3),
+    state(SPR_CPOS, 16, 5, Action::Fall, S_CPOS_XDIE4),
+    state(SPR_CPOS, 17, 5, Action::None, S_CPOS_XDIE5),
+    state(SPR_CPOS, 18, 5, Action::None, S_CPOS_XDIE6),
+    state(SPR_CPOS, 19, -1, Action::None, S_NULL),
+    state(SPR_CPOS, 13, 5, Action::None, S_CPOS_RAISE2),
+    state(SPR_CPOS, 12, 5, Action::None, S_CPOS_RAISE3),
+    state(SPR_CPOS, 11, 5, Action::None, S_CPOS_RAISE4),
+    state(SPR_CPOS, 10, 5, Action::None, S_CPOS_RAISE5),
+    state(SPR_CPOS, 9, 5, Action::None, S_CPOS_RAISE6),
+    state(SPR_CPOS, 8, 5, Action::None, S_CPOS_RAISE7),
+    state(SPR_CPOS, 7, 5, Action::None, S_CPOS_RUN1),
+    state(SPR_TROO, 0, 10, Action::Look, S_TROO_STND2),
+    state(SPR_TROO, 1, 10, Action::Look, S_TROO_STND),
+    state(SPR_TROO, 0, 3, Action::Chase, S_TROO_RUN2),
+    state(SPR_TROO, 0, 3, Action::Chase, S_TROO_RUN3),
+    state(SPR_TROO, 1, 3, Action::Chase, S_TROO_RUN4),
+    state(SPR_TROO, 1, 3, Action::Chase, S_TROO_RUN5),
+    state(SPR_TROO, 2, 3, Action::Chase, S_TROO_RUN6),
+    state(SPR_TROO, 2, 3, Action::Chase, S_TROO_RUN7),
+    state(SPR_TROO, 3, 3, Action::Chase, S_TROO_RUN8),
+    state(SPR_TROO, 3, 3, Action::Chase, S_TROO_RUN1),
+    state(SPR_TROO, 4, 8, Action::FaceTarget, S_TROO_ATK2),
+    state(SPR_TROO, 5, 8, Action::FaceTarget, S_TROO_ATK3),
+    state(SPR_TROO, 6, 6, Action::TroopAttack, S_TROO_RUN1),
+    state(SPR_TROO, 7, 2, Action::None, S_TROO_PAIN2),
+    state(SPR_TROO, 7, 2, Action::Pain, S_TROO_RUN1),
+    state(SPR_TROO, 8, 8, Action::None, S_TROO_DIE2),
+    state(SPR_TROO, 9, 8, Action::Scream, S_TROO_DIE3),
+    state(SPR_TROO, 10, 6, Action::None, S_TROO_DIE4),
+    state(SPR_TROO, 11, 6, Action::Fall, S_TROO_DIE5),
+    state(SPR_TROO, 12, -1, Action::None, S_NULL),
+    state(SPR_TROO, 13, 5, Action::None, S_TROO_XDIE2),
+    state(SPR_TROO, 14, 5, Action::XScream, S_TROO_XDIE3),
+    state(SPR_TROO, 15, 5, Action::None, S_TROO_XDIE4),
+    state(SPR_TROO, 16, 5, Action::Fall, S_TROO_XDIE5),
+    state(SPR_TROO, 17, 5, Action::None, S_TROO_XDIE6),
+    state(SPR_TROO, 18, 5, Action::None, S_TROO_XDIE7),
+    state(SPR_TROO, 19, 5, Action::None, S_TROO_XDIE8),
+    state(SPR_TROO, 20, -1, Action::None, S_NULL),
+    state(SPR_TROO, 12, 8, Action::None, S_TROO_RAISE2),
+    state(SPR_TROO, 11, 8, Action::None, S_TROO_RAISE3),
+    state(SPR_TROO, 10, 6, Action::None, S_TROO_RAISE4),
+    state(SPR_TROO, 9, 6, Action::None, S_TROO_RAISE5),
+    state(SPR_TROO, 8, 6, Action::None, S_TROO_RUN1),
+    state(SPR_SARG, 0, 10, Action::Look, S_SARG_STND2),
+    state(SPR_SARG, 1, 10, Action::Look, S_SARG_STND),
+    state(SPR_SARG, 0, 2, Action::Chase, S_SARG_RUN2),
+    state(SPR_SARG, 0, 2, Action::Chase, S_SARG_RUN3),
+    state(SPR_SARG, 1, 2, Action::Chase, S_SARG_RUN4),
+    state(SPR_SARG, 1, 2, Action::Chase, S_SARG_RUN5),
+    state(SPR_SARG, 2, 2, Action::Chase, S_SARG_RUN6),
+    state(SPR_SARG, 2, 2, Action::Chase, S_SARG_RUN7),
+    state(SPR_SARG, 3, 2, Action::Chase, S_SARG_RUN8),
+    state(SPR_SARG, 3, 2, Action::Chase, S_SARG_RUN1),
+    state(SPR_SARG, 4, 8, Action::FaceTarget, S_SARG_ATK2),
+    state(SPR_SARG, 5, 8, Action::FaceTarget, S_SARG_ATK3),
+    state(SPR_SARG, 6, 8, Action::SargAttack, S_SARG_RUN1),
+    state(SPR_SARG, 7, 2, Action::None, S_SARG_PAIN2),
+    state(SPR_SARG, 7, 2, Action::Pain, S_SARG_RUN1),
+    state(SPR_SARG, 8, 8, Action::None, S_SARG_DIE2),
+    state(SPR_SARG, 9, 8, Action::Scream, S_SARG_DIE3),
+    state(SPR_SARG, 10, 4, Action::None, S_SARG_DIE4),
+    state(SPR_SARG, 11, 4, Action::Fall, S_SARG_DIE5),
+    state(SPR_SARG, 12, 4, Action::None, S_SARG_DIE6),
+    state(SPR_SARG, 13, -1, Action::None, S_NULL),
+    state(SPR_SARG, 13, 5, Action::None, S_SARG_RAISE2),
+    state(SPR_SARG, 12, 5, Action::None, S_SARG_RAISE3),
+    state(SPR_SARG, 11, 5, Action::None, S_SARG_RAISE4),
+    state(SPR_SARG, 10, 5, Action::None, S_SARG_RAISE5),
+    state(SPR_SARG, 9, 5, Action::None, S_SARG_RAISE6),
+    state(SPR_SARG, 8, 5, Action::None, S_SARG_RUN1),
+    state(SPR_HEAD, 0, 10, Action::Look, S_HEAD_STND),
+    state(SPR_HEAD, 0, 3, Action::Chase, S_HEAD_RUN1),
+    state(SPR_HEAD, 1, 5, Action::FaceTarget, S_HEAD_ATK2),
+    state(SPR_HEAD, 2, 5, Action::FaceTarget, S_HEAD_ATK3),
+    state(
+        SPR_HEAD,
+        FF_FULLBRIGHT | 3,
+        5,
+        Action::HeadAttack,
+        S_HEAD_RUN1,
+    ),
+    state(SPR_HEAD, 4, 3, Action::None, S_HEAD_PAIN2),
+    state(SPR_HEAD, 4, 3, Action::Pain, S_HEAD_PAIN3),
+    state(SPR_HEAD, 5, 6, Action::None, S_HEAD_RUN1),
+    state(SPR_HEAD, 6, 8, Action::None, S_HEAD_DIE2),
+    state(SPR_HEAD, 7, 8, Action::Scream, S_HEAD_DIE3),
+    state(SPR_HEAD, 8, 8, Action::None, S_HEAD_DIE4),
+    state(SPR_HEAD, 9, 8, Action::None, S_HEAD_DIE5),
+    state(SPR_HEAD, 10, 8, Action::Fall, S_HEAD_DIE6),
+    state(SPR_HEAD, 11, -1, Action::None, S_NULL),
+    state(SPR_HEAD, 11, 8, Action::None, S_HEAD_RAISE2),
+    state(SPR_HEAD, 10, 8, Action::None, S_HEAD_RAISE3),
+    state(SPR_HEAD, 9, 8, Action::None, S_HEAD_RAISE4),
+    state(SPR_HEAD, 8, 8, Action::None, S_HEAD_RAISE5),
+    state(SPR_HEAD, 7, 8, Action::None, S_HEAD_RAISE6),
+    state(SPR_HEAD, 6, 8, Action::None, S_HEAD_RUN1),
+    state(SPR_BAL7, FF_FULLBRIGHT, 4, Action::None, S_BRBALL2),
+    state(SPR_BAL7, FF_FULLBRIGHT | 1, 4, Action::None, S_BRBALL1),
+    state(SPR_BAL7, FF_FULLBRIGHT | 2, 6, Action::None, S_BRBALLX2),
+    state(SPR_BAL7, FF_FULLBRIGHT | 3, 6, Action::None, S_BRBALLX3),
+    state(SPR_BAL7, FF_FULLBRIGHT | 4, 6, Action::None, S_NULL),
+    state(SPR_BOSS, 0, 10, Action::Look, S_BOSS_STND2),
+    state(SPR_BOSS, 1, 10, Action::Look, S_BOSS_STND),
+    state(SPR_BOSS, 0, 3, Action::Chase, S_BOSS_RUN2),
+    state(SPR_BOSS, 0, 3, Action::Chase, S_BOSS_RUN3),
+    state(SPR_BOSS, 1, 3, Action::Chase, S_BOSS_RUN4),
+    state(SPR_BOSS, 1, 3, Action::Chase, S_BOSS_RUN5),
+    state(SPR_BOSS, 2, 3, Action::Chase, S_BOSS_RUN6),
+    state(SPR_BOSS, 2, 3, Action::Chase, S_BOSS_RUN7),
+    state(SPR_BOSS, 3, 3, Action::Chase, S_BOSS_RUN8),
+    state(SPR_BOSS, 3, 3, Action::Chase, S_BOSS_RUN1),
+    state(SPR_BOSS, 4, 8, Action::FaceTarget, S_BOSS_ATK2),
+    state(SPR_BOSS, 5, 8, Action::FaceTarget, S_BOSS_ATK3),
+    state(SPR_BOSS, 6, 8, Action::BruisAttack, S_BOSS_RUN1),
+    state(SPR_BOSS, 7, 2, Action::None, S_BOSS_PAIN2),
+    state(SPR_BOSS, 7, 2, Action::Pain, S_BOSS_RUN1),
+    state(SPR_BOSS, 8, 8, Action::None, S_BOSS_DIE2),
+    state(SPR_BOSS, 9, 8, Action::Scream, S_BOSS_DIE3),
+    state(SPR_BOSS, 10, 8, Action::None, S_BOSS_DIE4),
+    state(SPR_BOSS, 11, 8, Action::Fall, S_BOSS_DIE5),
+    state(SPR_BOSS, 12, 8, Action::None, S_BOSS_DIE6),
+    state(SPR_BOSS, 13, 8, Action::None, S_BOSS_DIE7),
+    state(SPR_BOSS, 14, -1, Action::BossDeath, S_NULL),
+    state(SPR_BOSS, 14, 8, Action::None, S_BOSS_RAISE2),
+    state(SPR_BOSS, 13, 8, Action::None, S_BOSS_RAISE3),
+    state(SPR_BOSS, 12, 8, Action::None, S_BOSS_RAISE4),
+    state(SPR_BOSS, 11, 8, Action::None, S_BOSS_RAISE5),
+    state(SPR_BOSS, 10, 8, Action::None, S_BOSS_RAISE6),
+    state(SPR_BOSS, 9, 8, Action::None, S_BOSS_RAISE7),
+    state(SPR_BOSS, 8, 8, Action::None, S_BOSS_RUN1),
+    state(SPR_BOS2, 0, 10, Action::Look, S_BOS2_STND2),
+    state(SPR_BOS2, 1, 10, Action::Look, S_BOS2_STND),
+    state(SPR_BOS2, 0, 3, Action::Chase, S_BOS2_RUN2),
+    state(SPR_BOS2, 0, 3, Action::Chase, S_BOS2_RUN3),
+    state(SPR_BOS2, 1, 3, Action::Chase, S_BOS2_RUN4),
+    state(SPR_BOS2, 1, 3, Action::Chase, S_BOS2_RUN5),
+    state(SPR_BOS2, 2, 3, Action::Chase, S_BOS2_RUN6),
+    state(SPR_BOS2, 2, 3, Action::Chase, S_BOS2_RUN7),
+    state(SPR_BOS2, 3, 3, Action::Chase, S_BOS2_RUN8),
+    state(SPR_BOS2, 3, 3, Action::Chase, S_BOS2_RUN1),
+    state(SPR_BOS2, 4, 8, Action::FaceTarget, S_BOS2_ATK2),
+    state(SPR_BOS2, 5, 8, Action::FaceTarget, S_BOS2_ATK3),
+    state(SPR_BOS2, 6, 8, Action::BruisAttack, S_BOS2_RUN1),
+    state(SPR_BOS2, 7, 2, Action::None, S_BOS2_PAIN2),
+    state(SPR_BOS2, 7, 2, Action::Pain, S_BOS2_RUN1),
+    state(SPR_BOS2, 8, 8, Action::None, S_BOS2_DIE2),
+    state(SPR_BOS2, 9, 8, Action::Scream, S_BOS2_DIE3),
+    state(SPR_BOS2, 10, 8, Action::None, S_BOS2_DIE4),
+    state(SPR_BOS2, 11, 8, Action::Fall, S_BOS2_DIE5),
+    state(SPR_BOS2, 12, 8, Action::None, S_BOS2_DIE6),
+    state(SPR_BOS2, 13, 8, Action::None, S_BOS2_DIE7),
+    state(SPR_BOS2, 14, -1, Action::None, S_NULL),
+    state(SPR_BOS2, 14, 8, Action::None, S_BOS2_RAISE2),
+    state(SPR_BOS2, 13, 8, Action::None, S_BOS2_RAISE3),
+    state(SPR_BOS2, 12, 8, Action::None, S_BOS2_RAISE4),
+    state(SPR_BOS2, 11, 8, Action::None, S_BOS2_RAISE5),
+    state(SPR_BOS2, 10, 8, Action::None, S_BOS2_RAISE6),
+    state(SPR_BOS2, 9, 8, Action::None, S_BOS2_RAISE7),
+    state(SPR_BOS2, 8, 8, Action::None, S_BOS2_RUN1),
+    state(SPR_SKUL, FF_FULLBRIGHT, 10, Action::Look, S_SKULL_STND2),
+    state(SPR_SKUL, FF_FULLBRIGHT | 1, 10, Action::Look, S_SKULL_STND),
+    state(SPR_SKUL, FF_FULLBRIGHT, 6, Action::Chase, S_SKULL_RUN2),
+    state(SPR_SKUL, FF_FULLBRIGHT | 1, 6, Action::Chase, S_SKULL_RUN1),
+    state(
+        SPR_SKUL,
+        FF_FULLBRIGHT | 2,
+        10,
+        Action::FaceTarget,
+        S_SKULL_ATK2,
+    ),
+    state(
+        SPR_SKUL,
+        FF_FULLBRIGHT | 3,
+        4,
+        Action::SkullAttack,
+        S_SKULL_ATK3,
+    ),
+    state(SPR_SKUL, FF_FULLBRIGHT | 2, 4, Action::None, S_SKULL_ATK4),
+    state(SPR_SKUL, FF_FULLBRIGHT | 3, 4, Action::None, S_SKULL_ATK3),
+    state(SPR_SKUL, FF_FULLBRIGHT | 4, 3, Action::None, S_SKULL_PAIN2),
+    state(SPR_SKUL, FF_FULLBRIGHT | 4, 3, Action::Pain, S_SKULL_RUN1),
+    state(SPR_SKUL, FF_FULLBRIGHT | 5, 6, Action::None, S_SKULL_DIE2),
+    state(SPR_SKUL, FF_FULLBRIGHT | 6, 6, Action::Scream, S_SKULL_DIE3),
+    state(SPR_SKUL, FF_FULLBRIGHT | 7, 6, Action::None, S_SKULL_DIE4),
+    state(SPR_SKUL, FF_FULLBRIGHT | 8, 6, Action::Fall, S_SKULL_DIE5),
+    state(SPR_SKUL, 9, 6, Action::None, S_SKULL_DIE6),
+    state(SPR_SKUL, 10, 6, Action::None, S_NULL),
+    state(SPR_SPID, 0, 10, Action::Look, S_SPID_STND2),
+    state(SPR_SPID, 1, 10, Action::Look, S_SPID_STND),
+    state(SPR_SPID, 0, 3, Action::Metal, S_SPID_RUN2),
+    state(SPR_SPID, 0, 3, Action::Chase, S_SPID_RUN3),
+    state(SPR_SPID, 1, 3, Action::Chase, S_SPID_RUN4),
+    state(SPR_SPID, 1, 3, Action::Chase, S_SPID_RUN5),
+    state(SPR_SPID, 2, 3, Action::Metal, S_SPID_RUN6),
+    state(SPR_SPID, 2, 3, Action::Chase, S_SPID_RUN7),
+    state(SPR_SPID, 3, 3, Action::Chase, S_SPID_RUN8),
+    state(SPR_SPID, 3, 3, Action::Chase, S_SPID_RUN9),
+    state(SPR_SPID, 4, 3, Action::Metal, S_SPID_RUN10),
+    state(SPR_SPID, 4, 3, Action::Chase, S_SPID_RUN11),
+    state(SPR_SPID, 5, 3, Action::Chase, S_SPID_RUN12),
+    state(SPR_SPID, 5, 3, Action::Chase, S_SPID_RUN1),
+    state(SPR_SPID, FF_FULLBRIGHT, 20, Action::FaceTarget, S_SPID_ATK2),
+    state(
+        SPR_SPID,
+        FF_FULLBRIGHT | 6,
+        4,
+        Action::SPosAttack,
+        S_SPID_ATK3,
+    ),
+    state(
+        SPR_SPID,
+        FF_FULLBRIGHT | 7,
+        4,
+        Action::SPosAttack,
+        S_SPID_ATK4,
+    ),
+    state(
+        SPR_SPID,
+        FF_FULLBRIGHT | 7,
+        1,
+        Action::SpidRefire,
+        S_SPID_ATK2,
+    ),
+    state(SPR_SPID, 8, 3, Action::None, S_SPID_PAIN2),
+    state(SPR_SPID, 8, 3, Action::Pain, S_SPID_RUN1),
+    state(SPR_SPID, 9, 20, Action::Scream, S_SPID_DIE2),
+    state(SPR_SPID, 10, 10, Action::Fall, S_SPID_DIE3),
+    state(SPR_SPID, 11, 10, Action::None, S_SPID_DIE4),
+    state(SPR_SPID, 12, 10, Action::None, S_SPID_DIE5),
+    state(SPR_SPID, 13, 10, Action::None, S_SPID_DIE6),
+    state(SPR_SPID, 14, 10, Action::None, S_SPID_DIE7),
+    state(SPR_SPID, 15, 10, Action::None, S_SPID_DIE8),
+    state(SPR_SPID, 16, 10, Action::None, S_SPID_DIE9),
+    state(SPR_SPID, 17, 10, Action::None, S_SPID_DIE10),
+    state(SPR_SPID, 18, 30, Action::None, S_SPID_DIE11),
+    state(SPR_SPID, 18, -1, Action::BossDeath, S_NULL),
+    state(SPR_BSPI, 0, 10, Action::Look, S_BSPI_STND2),
+    state(SPR_BSPI, 1, 10, Action::Look, S_BSPI_STND),
+    state(SPR_BSPI, 0, 20, Action::None, S_BSPI_RUN1),
+    state(SPR_BSPI, 0, 3, Action::BabyMetal, S_BSPI_RUN2),
+    state(SPR_BSPI, 0, 3, Action::Chase, S_BSPI_RUN3),
+    state(SPR_BSPI, 1, 3, Action::Chase, S_BSPI_RUN4),
+    state(SPR_BSPI, 1, 3, Action::Chase, S_BSPI_RUN5),
+    state(SPR_BSPI, 2, 3, Action::Chase, S_BSPI_RUN6),
+    state(SPR_BSPI, 2, 3, Action::Chase, S_BSPI_RUN7),
+    state(SPR_BSPI, 3, 3, Action::BabyMetal, S_BSPI_RUN8),
+    state(SPR_BSPI, 3, 3, Action::Chase, S_BSPI_RUN9),
+    state(SPR_BSPI, 4, 3, Action::Chase, S_BSPI_RUN10),
+    state(SPR_BSPI, 4, 3, Action::Chase, S_BSPI_RUN11),
+    state(SPR_BSPI, 5, 3, Action::Chase, S_BSPI_RUN12),
+    state(SPR_BSPI, 5, 3, Action::Chase, S_BSPI_RUN1),
+    state(SPR_BSPI, FF_FULLBRIGHT, 20, Action::FaceTarget, S_BSPI_ATK2),
+    state(
+        SPR_BSPI,
+        FF_FULLBRIGHT | 6,
+        4,
+        Action::BspiAttack,
+        S_BSPI_ATK3,
+    ),
+    state(SPR_BSPI, FF_FULLBRIGHT | 7, 4, Action::None, S_BSPI_ATK4),
+    state(
+        SPR_BSPI,
+        FF_FULLBRIGHT | 7,
+        1,
+        Action::SpidRefire,
+        S_BSPI_ATK2,
+    ),
+    state(SPR_BSPI, 8, 3, Action::None, S_BSPI_PAIN2),
+    state(SPR_BSPI, 8, 3, Action::Pain, S_BSPI_RUN1),
+    state(SPR_BSPI, 9, 20, Action::Scream, S_BSPI_DIE2),
+    state(SPR_BSPI, 10, 7, Action::Fall, S_BSPI_DIE3),
+    state(SPR_BSPI, 11, 7, Action::None, S_BSPI_DIE4),
+    state(SPR_BSPI, 12, 7, Action::None, S_BSPI_DIE5),
+    state(SPR_BSPI, 13, 7, Action::None, S_BSPI_DIE6),
+    state(SPR_BSPI, 14, 7, Action::None, S_BSPI_DIE7),
+    state(SPR_BSPI, 15, -1, Action::BossDeath, S_NULL),
+    state(SPR_BSPI, 15, 5, Action::None, S_BSPI_RAISE2),
+    state(SPR_BSPI, 14, 5, Action::None, S_BSPI_RAISE3),
+    state(SPR_BSPI, 13, 5, Action::None, S_BSPI_RAISE4),
+    state(SPR_BSPI, 12, 5, Action::None, S_BSPI_RAISE5),
+    state(SPR_BSPI, 11, 5, Action::None, S_BSPI_RAISE6),
+    state(SPR_BSPI, 10, 5, Action::None, S_BSPI_RAISE7),
+    state(SPR_BSPI, 9, 5, Action::None, S_BSPI_RUN1),
+    state(SPR_APLS, FF_FULLBRIGHT, 5, Action::None, S_ARACH_PLAZ2),
+    state(SPR_APLS, FF_FULLBRIGHT | 1, 5, Action::None, S_ARACH_PLAZ),
+    state(SPR_APBX, FF_FULLBRIGHT, 5, Action::None, S_ARACH_PLEX2),
+    state(SPR_APBX, FF_FULLBRIGHT | 1, 5, Action::None, S_ARACH_PLEX3),
+    state(SPR_APBX, FF_FULLBRIGHT | 2, 5, Action::None, S_ARACH_PLEX4),
+    state(SPR_APBX, FF_FULLBRIGHT | 3, 5, Action::None, S_ARACH_PLEX5),
+    state(SPR_APBX, FF_FULLBRIGHT | 4, 5, Action::None, S_NULL),
+    state(SPR_CYBR, 0, 10, Action::Look, S_CYBER_STND2),
+    state(SPR_CYBR, 1, 10, Action::Look, S_CYBER_STND),
+    state(SPR_CYBR, 0, 3, Action::Hoof, S_CYBER_RUN2),
+    state(SPR_CYBR, 0, 3, Action::Chase, S_CYBER_RUN3),
+    state(SPR_CYBR, 1, 3, Action::Chase, S_CYBER_RUN4),
+    state(SPR_CYBR, 1, 3, Action::Chase, S_CYBER_RUN5),
+    state(SPR_CYBR, 2, 3, Action::Chase, S_CYBER_RUN6),
+    state(SPR_CYBR, 2, 3, Action::Chase, S_CYBER_RUN7),
+    state(SPR_CYBR, 3, 3, Action::Metal, S_CYBER_RUN8),
+    state(SPR_CYBR, 3, 3, Action::Chase, S_CYBER_RUN1),
+    state(SPR_CYBR, 4, 6, Action::FaceTarget, S_CYBER_ATK2),
+    state(SPR_CYBR, 5, 12, Action::CyberAttack, S_CYBER_ATK3),
+    state(SPR_CYBR, 4, 12, Action::FaceTarget, S_CYBER_ATK4),
+    state(SPR_CYBR, 5, 12, Action::CyberAttack, S_CYBER_ATK5),
+    state(SPR_CYBR, 4, 12, Action::FaceTarget, S_CYBER_ATK6),
+    state(SPR_CYBR, 5, 12, Action::CyberAttack, S_CYBER_RUN1),
+    state(SPR_CYBR, 6, 10, Action::Pain, S_CYBER_RUN1),
+    state(SPR_CYBR, 7, 10, Action::None, S_CYBER_DIE2),
+    state(SPR_CYBR, 8, 10, Action::Scream, S_CYBER_DIE3),
+    state(SPR_CYBR, 9, 10, Action::None, S_CYBER_DIE4),
+    state(SPR_CYBR, 10, 10, Action::None, S_CYBER_DIE5),
+    state(SPR_CYBR, 11, 10, Action::None, S_CYBER_DIE6),
+    state(SPR_CYBR, 12, 10, Action::Fall, S_CYBER_DIE7),
+    state(SPR_CYBR, 13, 10, Action::None, S_CYBER_DIE8),
+    state(SPR_CYBR, 14, 10, Action::None, S_CYBER_DIE9),
+    state(SPR_CYBR, 15, 30, Action::None, S_CYBER_DIE10),
+    state(SPR_CYBR, 15, -1, Action::BossDeath, S_NULL),
+    state(SPR_PAIN, 0, 10, Action::Look, S_PAIN_STND),
+    state(SPR_PAIN, 0, 3, Action::Chase, S_PAIN_RUN2),
+    state(SPR_PAIN, 0, 3, Action::Chase, S_PAIN_RUN3),
+    state(SPR_PAIN, 1, 3, Action::Chase, S_PAIN_RUN4),
+    state(SPR_PAIN, 1, 3, Action::Chase, S_PAIN_RUN5),
+    state(SPR_PAIN, 2, 3, Action::Chase, S_PAIN_RUN6),
+    state(SPR_PAIN, 2, 3, Action::Chase, S_PAIN_RUN1),
+    state(SPR_PAIN, 3, 5, Action::FaceTarget, S_PAIN_ATK2),
+    state(SPR_PAIN, 4, 5, Action::FaceTarget, S_PAIN_ATK3),
+    state(
+        SPR_PAIN,
+        FF_FULLBRIGHT | 5,
+        5,
+        Action::FaceTarget,
+        S_PAIN_ATK4,
+    ),
+    state(
+        SPR_PAIN,
+        FF_FULLBRIGHT | 5,
+        0,
+        Action::PainAttack,
+        S_PAIN_RUN1,
+    ),
+    state(SPR_PAIN, 6, 6, Action::None, S_PAIN_PAIN2),
+    state(SPR_PAIN, 6, 6, Action::Pain, S_PAIN_RUN1),
+    state(SPR_PAIN, FF_FULLBRIGHT | 7, 8, Action::None, S_PAIN_DIE2),
+    state(SPR_PAIN, FF_FULLBRIGHT | 8, 8, Action::Scream, S_PAIN_DIE3),
+    state(SPR_PAIN, FF_FULLBRIGHT | 9, 8, Action::None, S_PAIN_DIE4),
+    state(SPR_PAIN, FF_FULLBRIGHT | 10, 8, Action::None, S_PAIN_DIE5),
+    state(
+        SPR_PAIN,
+        FF_FULLBRIGHT | 11,
+        8,
+        Action::PainDie,
+        S_PAIN_DIE6,
+    ),
+    state(SPR_PAIN, FF_FULLBRIGHT | 12, 8, Action::None, S_NULL),
+    state(SPR_PAIN, 12, 8, Action::None, S_PAIN_RAISE2),
+    state(SPR_PAIN, 11, 8, Action::None, S_PAIN_RAISE3),
+    state(SPR_PAIN, 10, 8, Action::None, S_PAIN_RAISE4),
+    state(SPR_PAIN, 9, 8, Action::None, S_PAIN_RAISE5),
+    state(SPR_PAIN, 8, 8, Action::None, S_PAIN_RAISE6),
+    state(SPR_PAIN, 7, 8, Action::None, S_PAIN_RUN1),
+    state(SPR_SSWV, 0, 10, Action::Look, S_SSWV_STND2),
+    state(SPR_SSWV, 1, 10, Action::Look, S_SSWV_STND),
+    state(SPR_SSWV, 0, 3, Action::Chase, S_SSWV_RUN2),
+    state(SPR_SSWV, 0, 3, Action::Chase, S_SSWV_RUN3),
+    state(SPR_SSWV, 1, 3, Action::Chase, S_SSWV_RUN4),
+    state(SPR_SSWV, 1, 3, Action::Chase, S_SSWV_RUN5),
+    state(SPR_SSWV, 2, 3, Action::Chase, S_SSWV_RUN6),
+    state(SPR_SSWV, 2, 3, Action::Chase, S_SSWV_RUN7),
+    state(SPR_SSWV, 3, 3, Action::Chase, S_SSWV_RUN8),
+    state(SPR_SSWV, 3, 3, Action::Chase, S_SSWV_RUN1),
+    state(SPR_SSWV, 4, 10, Action::FaceTarget, S_SSWV_ATK2),
+    state(SPR_SSWV, 5, 10, Action::FaceTarget, S_SSWV_ATK3),
+    state(
+        SPR_SSWV,
+        FF_FULLBRIGHT | 6,
+        4,
+        Action::CPosAttack,
+        S_SSWV_ATK4,
+    ),
+    state(SPR_SSWV, 5, 6, Action::FaceTarget, S_SSWV_ATK5),
+    state(
+        SPR_SSWV,
+        FF_FULLBRIGHT | 6,
+        4,
+        Action::CPosAttack,
+        S_SSWV_ATK6,
+    ),
+    state(SPR_SSWV, 5, 1, Action::CPosRefire, S_SSWV_ATK2),
+    state(SPR_SSWV, 7, 3, Action::None, S_SSWV_PAIN2),
+    state(SPR_SSWV, 7, 3, Action::Pain, S_SSWV_RUN1),
+    state(SPR_SSWV, 8, 5, Action::None, S_SSWV_DIE2),
+    state(SPR_SSWV, 9, 5, Action::Scream, S_SSWV_DIE3),
+    state(SPR_SSWV, 10, 5, Action::Fall, S_SSWV_DIE4),
+    state(SPR_SSWV, 11, 5, Action::None, S_SSWV_DIE5),
+    state(SPR_SSWV, 12, -1, Action::None, S_NULL),
+    state(SPR_SSWV, 13, 5, Action::None, S_SSWV_XDIE2),
+    state(SPR_SSWV, 14, 5, Action::XScream, S_SSWV_XDIE3),
+    state(SPR_SSWV, 15, 5, Action::Fall, S_SSWV_XDIE4),
+    state(SPR_SSWV, 16, 5, Action::None, S_SSWV_XDIE5),
+    state(SPR_SSWV, 17, 5, Action::None, S_SSWV_XDIE6),
+    state(SPR_SSWV, 18, 5, Action::None, S_SSWV_XDIE7),
+    state(SPR_SSWV, 19, 5, Action::None, S_SSWV_XDIE8),
+    state(SPR_SSWV, 20, 5, Action::None, S_SSWV_XDIE9),
+    state(SPR_SSWV, 21, -1, Action::None, S_NULL),
+    state(SPR_SSWV, 12, 5, Action::None, S_SSWV_RAISE2),
+    state(SPR_SSWV, 11, 5, Action::None, S_SSWV_RAISE3),
+    state(SPR_SSWV, 10, 5, Action::None, S_SSWV_RAISE4),
+    state(SPR_SSWV, 9, 5, Action::None, S_SSWV_RAISE5),
+    state(SPR_SSWV, 8, 5, Action::None, S_SSWV_RUN1),
+    state(SPR_KEEN, 0, -1, Action::None, S_KEENSTND),
+    state(SPR_KEEN, 0, 6, Action::None, S_COMMKEEN2),
+    state(SPR_KEEN, 1, 6, Action::None, S_COMMKEEN3),
+    state(SPR_KEEN, 2, 6, Action::Scream, S_COMMKEEN4),
+    state(SPR_KEEN, 3, 6, Action::None, S_COMMKEEN5),
+    state(SPR_KEEN, 4, 6, Action::None, S_COMMKEEN6),
+    state(SPR_KEEN, 5, 6, Action::None, S_COMMKEEN7),
+    state(SPR_KEEN, 6, 6, Action::None, S_COMMKEEN8),
+    state(SPR_KEEN, 7, 6, Action::None, S_COMMKEEN9),
+    state(SPR_KEEN, 8, 6, Action::None, S_COMMKEEN10),
+    state(SPR_KEEN, 9, 6, Action::None, S_COMMKEEN11),
+    state(SPR_KEEN, 10, 6, Action::KeenDie, S_COMMKEEN12),
+    state(SPR_KEEN, 11, -1, Action::None, S_NULL),
+    state(SPR_KEEN, 12, 4, Action::None, S_KEENPAIN2),
+    state(SPR_KEEN, 12, 8, Action::Pain, S_KEENSTND),
+    state(SPR_BBRN, 0, -1, Action::None, S_NULL),
+    state(SPR_BBRN, 1, 36, Action::BrainPain, S_BRAIN),
+    state(SPR_BBRN, 0, 100, Action::BrainScream, S_BRAIN_DIE2),
+    state(SPR_BBRN, 0, 10, Action::None, S_BRAIN_DIE3),
+    state(SPR_BBRN, 0, 10, Action::None, S_BRAIN_DIE4),
+    state(SPR_BBRN, 0, -1, Action::BrainDie, S_NULL),
+    state(SPR_SSWV, 0, 10, Action::Look, S_BRAINEYE),
+    state(SPR_SSWV, 0, 181, Action::BrainAwake, S_BRAINEYE1),
+    state(SPR_SSWV, 0, 150, Action::BrainSpit, S_BRAINEYE1),
+    state(SPR_BOSF, FF_FULLBRIGHT, 3, Action::SpawnSound, S_SPAWN2),
+    state(SPR_BOSF, FF_FULLBRIGHT | 1, 3, Action::SpawnFly, S_SPAWN3),
+    state(SPR_BOSF, FF_FULLBRIGHT | 2, 3, Action::SpawnFly, S_SPAWN4),
+    state(SPR_BOSF, FF_FULLBRIGHT | 3, 3, Action::SpawnFly, S_SPAWN1),
+    state(SPR_FIRE, FF_FULLBRIGHT, 4, Action::Fire, S_SPAWNFIRE2),
+    state(SPR_FIRE, FF_FULLBRIGHT | 1, 4, Action::Fire, S_SPAWNFIRE3),
+    state(SPR_FIRE, FF_FULLBRIGHT | 2, 4, Action::Fire, S_SPAWNFIRE4),
+    state(SPR_FIRE, FF_FULLBRIGHT | 3, 4, Action::Fire, S_SPAWNFIRE5),
+    state(SPR_FIRE, FF_FULLBRIGHT | 4, 4, Action::Fire, S_SPAWNFIRE6),
+    state(SPR_FIRE, FF_FULLBRIGHT | 5, 4, Action::Fire, S_SPAWNFIRE7),
+    state(SPR_FIRE, FF_FULLBRIGHT | 6, 4, Action::Fire, S_SPAWNFIRE8),
+    state(SPR_FIRE, FF_FULLBRIGHT | 7, 4, Action::Fire, S_NULL),
+    state(
+        SPR_MISL,
+        FF_FULLBRIGHT | 1,
+        10,
+        Action::None,
+        S_BRAINEXPLODE2,
+    ),
+    state(
+        SPR_MISL,
+        FF_FULLBRIGHT | 2,
+        10,
+        Action::None,
+        S_BRAINEXPLODE3,
+    ),
+    state(
+        SPR_MISL,
+        FF_FULLBRIGHT | 3,
+        10,
+        Action::BrainExplode,
+        S_NULL,
+    ),
+    state(SPR_ARM1, 0, 6, Action::None, S_ARM1A),
+    state(SPR_ARM1, FF_FULLBRIGHT | 1, 7, Action::None, S_ARM1),
+    state(SPR_ARM2, 0, 6, Action::None, S_ARM2A),
+    state(SPR_ARM2, FF_FULLBRIGHT | 1, 6, Action::None, S_ARM2),
+    state(SPR_BAR1, 0, 6, Action::None, S_BAR2),
+    state(SPR_BAR1, 1, 6, Action::None, S_BAR1),
+    state(SPR_BEXP, FF_FULLBRIGHT, 5, Action::None, S_BEXP2),
+    state(SPR_BEXP, FF_FULLBRIGHT | 1, 5, Action::Scream, S_BEXP3),
+    state(SPR_BEXP, FF_FULLBRIGHT | 2, 5, Action::None, S_BEXP4),
+    state(SPR_BEXP, FF_FULLBRIGHT | 3, 10, Action::Explode, S_BEXP5),
+    state(SPR_BEXP, FF_FULLBRIGHT | 4, 10, Action::None, S_NULL),
+    state(SPR_FCAN, FF_FULLBRIGHT, 4, Action::None, S_BBAR2),
+    state(SPR_FCAN, FF_FULLBRIGHT | 1, 4, Action::None, S_BBAR3),
+    state(SPR_FCAN, FF_FULLBRIGHT | 2, 4, Action::None, S_BBAR1),
+    state(SPR_BON1, 0, 6, Action::None, S_BON1A),
+    state(SPR_BON1, 1, 6, Action::None, S_BON1B),
+    state(SPR_BON1, 2, 6, Action::None, S_BON1C),
+    state(SPR_BON1, 3, 6, Action::None, S_BON1D),
+    state(SPR_BON1, 2, 6, Action::None, S_BON1E),
+    state(SPR_BON1, 1, 6, Action::None, S_BON1),
+    state(SPR_BON2, 0, 6, Action::None, S_BON2A),
+    state(SPR_BON2, 1, 6, Action::None, S_BON2B),
+    state(SPR_BON2, 2, 6, Action::None, S_BON2C),
+    state(SPR_BON2, 3, 6, Action::None, S_BON2D),
+    state(SPR_BON2, 2, 6, Action::None, S_BON2E),
+    state(SPR_BON2, 1, 6, Action::None, S_BON2),
+    state(SPR_BKEY, 0, 10, Action::None, S_BKEY2),
+    state(SPR_BKEY, FF_FULLBRIGHT | 1, 10, Action::None, S_BKEY),
+    state(SPR_RKEY, 0, 10, Action::None, S_RKEY2),
+    state(SPR_RKEY, FF_FULLBRIGHT | 1, 10, Action::None, S_RKEY),
+    state(SPR_YKEY, 0, 10, Action::None, S_YKEY2),
+    state(SPR_YKEY, FF_FULLBRIGHT | 1, 10, Action::None, S_YKEY),
+    state(SPR_BSKU, 0, 10, Action::None, S_BSKULL2),
+    state(SPR_BSKU, FF_FULLBRIGHT | 1, 10, Action::None, S_BSKULL),
+    state(SPR_RSKU, 0, 10, Action::None, S_RSKULL2),
+    state(SPR_RSKU, FF_FULLBRIGHT | 1, 10, Action::None, S_RSKULL),
+    state(SPR_YSKU, 0, 10, Action::None, S_YSKULL2),
+    state(SPR_YSKU, FF_FULLBRIGHT | 1, 10, Action::None, S_YSKULL),
+    state(SPR_STIM, 0, -1, Action::None, S_NULL),
+    state(SPR_MEDI, 0, -1, Action::None, S_NULL),
+    state(SPR_SOUL, FF_FULLBRIGHT, 6, Action::None, S_SOUL2),
+    state(SPR_SOUL, FF_FULLBRIGHT | 1, 6, Action::None, S_SOUL3),
+    state(SPR_SOUL, FF_FULLBRIGHT | 2, 6, Action::None, S_SOUL4),
+    state(SPR_SOUL, FF_FULLBRIGHT | 3, 6, Action::None, S_SOUL5),
+    state(SPR_SOUL, FF_FULLBRIGHT | 2, 6, Action::None, S_SOUL6),
+    state(SPR_SOUL, FF_FULLBRIGHT | 1, 6, Action::None, S_SOUL),
+    state(SPR_PINV, FF_FULLBRIGHT, 6, Action::None, S_PINV2),
+    state(SPR_PINV, FF_FULLBRIGHT | 1, 6, Action::None, S_PINV3),
+    state(SPR_PINV, FF_FULLBRIGHT | 2, 6, Action::None, S_PINV4),
+    state(SPR_PINV, FF_FULLBRIGHT | 3, 6, Action::None, S_PINV),
+    state(SPR_PSTR, FF_FULLBRIGHT, -1, Action::None, S_NULL),
+    state(SPR_PINS, FF_FULLBRIGHT, 6, Action::None, S_PINS2),
+    state(SPR_PINS, FF_FULLBRIGHT | 1, 6, Action::None, S_PINS3),
+    state(SPR_PINS, FF_FULLBRIGHT | 2, 6, Action::None, S_PINS4),
+    state(SPR_PINS, FF_FULLBRIGHT | 3, 6, Action::None, S_PINS),
+    state(SPR_MEGA, FF_FULLBRIGHT, 6, Action::None, S_MEGA2),
+    state(SPR_MEGA, FF_FULLBRIGHT | 1, 6, Action::None, S_MEGA3),
+    state(SPR_MEGA, FF_FULLBRIGHT | 2, 6, Action::None, S_MEGA4),
+    state(SPR_MEGA, FF_FULLBRIGHT | 3, 6, Action::None, S_MEGA),
+    state(SPR_SUIT, FF_FULLBRIGHT, -1, Action::None, S_NULL),
+    state(SPR_PMAP, FF_FULLBRIGHT, 6, Action::None, S_PMAP2),
+    state(SPR_PMAP, FF_FULLBRIGHT | 1, 6, Action::None, S_PMAP3),
+    state(SPR_PMAP, FF_FULLBRIGHT | 2, 6, Action::None, S_PMAP4),
+    state(SPR_PMAP, FF_FULLBRIGHT | 3, 6, Action::None, S_PMAP5),
+    state(SPR_PMAP, FF_FULLBRIGHT | 2, 6, Action::None, S_PMAP6),
+    state(SPR_PMAP, FF_FULLBRIGHT | 1, 6, Action::None, S_PMAP),
+    state(SPR_PVIS, FF_FULLBRIGHT, 6, Action::None, S_PVIS2),
+    state(SPR_PVIS, 1, 6, Action::None, S_PVIS),
+    state(SPR_CLIP, 0, -1, Action::None, S_NULL),
+    state(SPR_AMMO, 0, -1, Action::None, S_NULL),
+    state(SPR_ROCK, 0, -1, Action::None, S_NULL),
+    state(SPR_BROK, 0, -1, Action::None, S_NULL),
+    state(SPR_CELL, 0, -1, Action::None, S_NULL),
+    state(SPR_CELP, 0, -1, Action::None, S_NULL),
+    state(SPR_SHEL, 0, -1, Action::None, S_NULL),
+    state(SPR_SBOX, 0, -1, Action::None, S_NULL),
+    state(SPR_BPAK, 0, -1, Action::None, S_NULL),
+    state(SPR_BFUG, 0, -1, Action::None, S_NULL),
+    state(SPR_MGUN, 0, -1, Action::None, S_NULL),
+    state(SPR_CSAW, 0, -1, Action::None, S_NULL),
+    state(SPR_LAUN, 0, -1, Action::None, S_NULL),
+    state(SPR_PLAS, 0, -1, Action::None, S_NULL),
+    state(SPR_SHOT, 0, -1, Action::None, S_NULL),
+    state(SPR_SGN2, 0, -1, Action::None, S_NULL),
+    state(SPR_COLU, FF_FULLBRIGHT, -1, Action::None, S_NULL),
+    state(SPR_SMT2, 0, -1, Action::None, S_NULL),
+    state(SPR_GOR1, 0, 10, Action::None, S_BLOODYTWITCH2),
+    state(SPR_GOR1, 1, 15, Action::None, S_BLOODYTWITCH3),
+    state(SPR_GOR1, 2, 8, Action::None, S_BLOODYTWITCH4),
+    state(SPR_GOR1, 1, 6, Action::None, S_BLOODYTWITCH),
+    state(SPR_PLAY, 13, -1, Action::None, S_NULL),
+    state(SPR_PLAY, 18, -1, Action::None, S_NULL),
+    state(SPR_POL2, 0, -1, Action::None, S_NULL),
+    state(SPR_POL5, 0, -1, Action::None, S_NULL),
+    state(SPR_POL4, 0, -1, Action::None, S_NULL),
+    state(SPR_POL3, FF_FULLBRIGHT, 6, Action::None, S_HEADCANDLES2),
+    state(SPR_POL3, FF_FULLBRIGHT | 1, 6, Action::None, S_HEADCANDLES),
+    state(SPR_POL1, 0, -1, Action::None, S_NULL),
+    state(SPR_POL6, 0, 6, Action::None, S_LIVESTICK2),
+    state(SPR_POL6, 1, 8, Action::None, S_LIVESTICK),
+    state(SPR_GOR2, 0, -1, Action::None, S_NULL),
+    state(SPR_GOR3, 0, -1, Action::None, S_NULL),
+    state(SPR_GOR4, 0, -1, Action::None, S_NULL),
+    state(SPR_GOR5, 0, -1, Action::None, S_NULL),
+    state(SPR_SMIT, 0, -1, Action::None, S_NULL),
+    state(SPR_COL1, 0, -1, Action::None, S_NULL),
+    state(SPR_COL2, 0, -1, Action::None, S_NULL),
+    state(SPR_COL3, 0, -1, Action::None, S_NULL),
+    state(SPR_COL4, 0, -1, Action::None, S_NULL),
+    state(SPR_CAND, FF_FULLBRIGHT, -1, Action::None, S_NULL),
+    state(SPR_CBRA, FF_FULLBRIGHT, -1, Action::None, S_NULL),
+    state(SPR_COL6, 0, -1, Action::None, S_NULL),
+    state(SPR_TRE1, 0, -1, Action::None, S_NULL),
+    state(SPR_TRE2, 0, -1, Action::None, S_NULL),
+    state(SPR_ELEC, 0, -1, Action::None, S_NULL),
+    state(SPR_CEYE, FF_FULLBRIGHT, 6, Action::None, S_EVILEYE2),
+    state(SPR_CEYE, FF_FULLBRIGHT | 1, 6, Action::None, S_EVILEYE3),
+    state(SPR_CEYE, FF_FULLBRIGHT | 2, 6, Action::None, S_EVILEYE4),
+    state(SPR_CEYE, FF_FULLBRIGHT | 1, 6, Action::None, S_EVILEYE),
+    state(SPR_FSKU, FF_FULLBRIGHT, 6, Action::None, S_FLOATSKULL2),
+    state(SPR_FSKU, FF_FULLBRIGHT | 1, 6, Action::None, S_FLOATSKULL3),
+    state(SPR_FSKU, FF_FULLBRIGHT | 2, 6, Action::None, S_FLOATSKULL),
+    state(SPR_COL5, 0, 14, Action::None, S_HEARTCOL2),
+    state(SPR_COL5, 1, 14, Action::None, S_HEARTCOL),
+    state(SPR_TBLU, FF_FULLBRIGHT, 4, Action::None, S_BLUETORCH2),
+    state(SPR_TBLU, FF_FULLBRIGHT | 1, 4, Action::None, S_BLUETORCH3),
+    state(SPR_TBLU, FF_FULLBRIGHT | 2, 4, Action::None, S_BLUETORCH4),
+    state(SPR_TBLU, FF_FULLBRIGHT | 3, 4, Action::None, S_BLUETORCH),
+    state(SPR_TGRN, FF_FULLBRIGHT, 4, Action::None, S_GREENTORCH2),
+    state(SPR_TGRN, FF_FULLBRIGHT | 1, 4, Action::None, S_GREENTORCH3),
+    state(SPR_TGRN, FF_FULLBRIGHT | 2, 4, Action::None, S_GREENTORCH4),
+    state(SPR_TGRN, FF_FULLBRIGHT | 3, 4, Action::None, S_GREENTORCH),
+    state(SPR_TRED, FF_FULLBRIGHT, 4, Action::None, S_REDTORCH2),
+    state(SPR_TRED, FF_FULLBRIGHT | 1, 4, Action::None, S_REDTORCH3),
+    state(SPR_TRED, FF_FULLBRIGHT | 2, 4, Action::None, S_REDTORCH4),
+    state(SPR_TRED, FF_FULLBRIGHT | 3, 4, Action::None, S_REDTORCH),
+    state(SPR_SMBT, FF_FULLBRIGHT, 4, Action::None, S_BTORCHSHRT2),
+    state(SPR_SMBT, FF_FULLBRIGHT | 1, 4, Action::None, S_BTORCHSHRT3),
+    state(SPR_SMBT, FF_FULLBRIGHT | 2, 4, Action::None, S_BTORCHSHRT4),
+    state(SPR_SMBT, FF_FULLBRIGHT | 3, 4, Action::None, S_BTORCHSHRT),
+    state(SPR_SMGT, FF_FULLBRIGHT, 4, Action::None, S_GTORCHSHRT2),
+    state(SPR_SMGT, FF_FULLBRIGHT | 1, 4, Action::None, S_GTORCHSHRT3),
+    state(SPR_SMGT, FF_FULLBRIGHT | 2, 4, Action::None, S_GTORCHSHRT4),
+    state(SPR_SMGT, FF_FULLBRIGHT | 3, 4, Action::None, S_GTORCHSHRT),
+    state(SPR_SMRT, FF_FULLBRIGHT, 4, Action::None, S_RTORCHSHRT2),
+    state(SPR_SMRT, FF_FULLBRIGHT | 1, 4, Action::None, S_RTORCHSHRT3),
+    state(SPR_SMRT, FF_FULLBRIGHT | 2, 4, Action::None, S_RTORCHSHRT4),
+    state(SPR_SMRT, FF_FULLBRIGHT | 3, 4, Action::None, S_RTORCHSHRT),
+    state(SPR_HDB1, 0, -1, Action::None, S_NULL),
+    state(SPR_HDB2, 0, -1, Action::None, S_NULL),
+    state(SPR_HDB3, 0, -1, Action::None, S_NULL),
+    state(SPR_HDB4, 0, -1, Action::None, S_NULL),
+    state(SPR_HDB5, 0, -1, Action::None, S_NULL),
+    state(SPR_HDB6, 0, -1, Action::None, S_NULL),
+    state(SPR_POB1, 0, -1, Action::None, S_NULL),
+    state(SPR_POB2, 0, -1, Action::None, S_NULL),
+    state(SPR_BRS1, 0, -1, Action::None, S_NULL),
+    state(SPR_TLMP, FF_FULLBRIGHT, 4, Action::None, S_TECHLAMP2),
+    state(SPR_TLMP, FF_FULLBRIGHT | 1, 4, Action::None, S_TECHLAMP3),
+    state(SPR_TLMP, FF_FULLBRIGHT | 2, 4, Action::None, S_TECHLAMP4),
+    state(SPR_TLMP, FF_FULLBRIGHT | 3, 4, Action::None, S_TECHLAMP),
+    state(SPR_TLP2, FF_FULLBRIGHT, 4, Action::None, S_TECH2LAMP2),
+    state(SPR_TLP2, FF_FULLBRIGHT | 1, 4, Action::None, S_TECH2LAMP3),
+    state(SPR_TLP2, FF_FULLBRIGHT | 2, 4, Action::None, S_TECH2LAMP4),
+    state(SPR_TLP2, FF_FULLBRIGHT | 3, 4, Action::None, S_TECH2LAMP),
+];
+
+#[derive(Clone, Copy, Debug)]
+pub struct MobjInfo {
+    pub doomed_num: i32,
+    pub spawn_state: usize,
+    pub spawn_health: i32,
+    pub see_state: usize,
+    pub see_sound: usize,
+    pub reaction_time: i32,
+    pub attack_sound: usize,
+    pub pain_state: usize,
+    pub pain_chance: i32,
+    pub pain_sound: usize,
+    pub melee_state: usize,
+    pub missile_state: usize,
+    pub death_state: usize,
+    pub xdeath_state: usize,
+    pub death_sound: usize,
+    pub speed: i32,
+    pub radius: Fixed,
+    pub height: Fixed,
+    pub mass: i32,
+    pub damage: i32,
+    pub active_sound: usize,
+    pub flags: i32,
+    pub raise_state: usize,
+}
+
+pub const NUM_MOBJ_TYPES: usize = 137;
+
+pub const MT_PLAYER: usize = 0;
+pub const MT_POSSESSED: usize = 1;
+pub const MT_SHOTGUY: usize = 2;
+pub const MT_VILE: usize = 3;
+pub const MT_FIRE: usize = 4;
+pub const MT_UNDEAD: usize = 5;
+pub const MT_TRACER: usize = 6;
+pub const MT_SMOKE: usize = 7;
+pub const MT_FATSO: usize = 8;
+pub const MT_FATSHOT: usize = 9;
+pub const MT_CHAINGUY: usize = 10;
+pub const MT_TROOP: usize = 11;
+pub const MT_SERGEANT: usize = 12;
+pub const MT_SHADOWS: usize = 13;
+pub const MT_HEAD: usize = 14;
+pub const MT_BRUISER: usize = 15;
+pub const MT_BRUISERSHOT: usize = 16;
+pub const MT_KNIGHT: usize = 17;
+pub const MT_SKULL: usize = 18;
+pub const MT_SPIDER: usize = 19;
+pub const MT_BABY: usize = 20;
+pub const MT_CYBORG: usize = 21;
+pub const MT_PAIN: usize = 22;
+pub const MT_WOLFSS: usize = 23;
+pub const MT_KEEN: usize = 24;
+pub const MT_BOSSBRAIN: usize = 25;
+pub const MT_BOSSSPIT: usize = 26;
+pub const MT_BOSSTARGET: usize = 27;
+pub const MT_SPAWNSHOT: usize = 28;
+pub const MT_SPAWNFIRE: usize = 29;
+pub const MT_BARREL: usize = 30;
+pub const MT_TROOPSHOT: usize = 31;
+pub const MT_HEADSHOT: usize = 32;
+pub const MT_ROCKET: usize = 33;
+pub const MT_PLASMA: usize = 34;
+pub const MT_BFG: usize = 35;
+pub const MT_ARACHPLAZ: usize = 36;
+pub const MT_PUFF: usize = 37;
+pub const MT_BLOOD: usize = 38;
+pub const MT_TFOG: usize = 39;
+pub const MT_IFOG: usize = 40;
+pub const MT_TELEPORTMAN: usize = 41;
+pub const MT_EXTRABFG: usize = 42;
+pub const MT_MISC0: usize = 43;
+pub const MT_MISC1: usize = 44;
+pub const MT_MISC2: usize = 45;
+pub const MT_MISC3: usize = 46;
+pub const MT_MISC4: usize = 47;
+pub const MT_MISC5: usize = 48;
+pub const MT_MISC6: usize = 49;
+pub const MT_MISC7: usize = 50;
+pub const MT_MISC8: usize = 51;
+pub const MT_MISC9: usize = 52;
+pub const MT_MISC10: usize = 53;
+pub const MT_MISC11: usize = 54;
+pub const MT_MISC12: usize = 55;
+pub const MT_INV: usize = 56;
+pub const MT_MISC13: usize = 57;
+pub const MT_INS: usize = 58;
+pub const MT_MISC14: usize = 59;
+pub const MT_MISC15: usize = 60;
+pub const MT_MISC16: usize = 61;
+pub const MT_MEGA: usize = 62;
+pub const MT_CLIP: usize = 63;
+pub const MT_MISC17: usize = 64;
+pub const MT_MISC18: usize = 65;
+pub const MT_MISC19: usize = 66;
+pub const MT_MISC20: usize = 67;
+pub const MT_MISC21: usize = 68;
+pub const MT_MISC22: usize = 69;
+pub const MT_MISC23: usize = 70;
+pub const MT_MISC24: usize = 71;
+pub const MT_MISC25: usize = 72;
+pub const MT_CHAINGUN: usize = 73;
+pub const MT_MISC26: usize = 74;
+pub const MT_MISC27: usize = 75;
+pub const MT_MISC28: usize = 76;
+pub const MT_SHOTGUN: usize = 77;
+pub const MT_SUPERSHOTGUN: usize = 78;
+pub const MT_MISC29: usize = 79;
+pub const MT_MISC30: usize = 80;
+pub const MT_MISC31: usize = 81;
+pub const MT_MISC32: usize = 82;
+pub const MT_MISC33: usize = 83;
+pub const MT_MISC34: usize = 84;
+pub const MT_MISC35: usize = 85;
+pub const MT_MISC36: usize = 86;
+pub const MT_MISC37: usize = 87;
+pub const MT_MISC38: usize = 88;
+pub const MT_MISC39: usize = 89;
+pub const MT_MISC40: usize = 90;
+pub const MT_MISC41: usize = 91;
+pub const MT_MISC42: usize = 92;
+pub const MT_MISC43: usize = 93;
+pub const MT_MISC44: usize = 94;
+pub const MT_MISC45: usize = 95;
+pub const MT_MISC46: usize = 96;
+pub const MT_MISC47: usize = 97;
+pub const MT_MISC48: usize = 98;
+pub const MT_MISC49: usize = 99;
+pub const MT_MISC50: usize = 100;
+pub const MT_MISC51: usize = 101;
+pub const MT_MISC52: usize = 102;
+pub const MT_MISC53: usize = 103;
+pub const MT_MISC54: usize = 104;
+pub const MT_MISC55: usize = 105;
+pub const MT_MISC56: usize = 106;
+pub const MT_MISC57: usize = 107;
+pub const MT_MISC58: usize = 108;
+pub const MT_MISC59: usize = 109;
+pub const MT_MISC60: usize = 110;
+pub const MT_MISC61: usize = 111;
+pub const MT_MISC62: usize = 112;
+pub const MT_MISC63: usize = 113;
+pub const MT_MISC64: usize = 114;
+pub const MT_MISC65: usize = 115;
+pub const MT_MISC66: usize = 116;
+pub const MT_MISC67: usize = 117;
+pub const MT_MISC68: usize = 118;
+pub const MT_MISC69: usize = 119;
+pub const MT_MISC70: usize = 120;
+pub const MT_MISC71: usize = 121;
+pub const MT_MISC72: usize = 122;
+pub const MT_MISC73: usize = 123;
+pub const MT_MISC74: usize = 124;
+pub const MT_MISC75: usize = 125;
+pub const MT_MISC76: usize = 126;
+pub const MT_MISC77: usize = 127;
+pub const MT_MISC78: usize = 128;
+pub const MT_MISC79: usize = 129;
+pub const MT_MISC80: usize = 130;
+pub const MT_MISC81: usize = 131;
+pub const MT_MISC82: usize = 132;
+pub const MT_MISC83: usize = 133;
+pub const MT_MISC84: usize = 134;
+pub const MT_MISC85: usize = 135;
+pub const MT_MISC86: usize = 136;
+
+pub static MOBJ_INFO: [MobjInfo; NUM_MOBJ_TYPES] = [
+    // MT_PLAYER
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_PLAY,
+        spawn_health: 100,
+        see_state: S_PLAY_RUN1,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 0,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_PLAY_PAIN,
+        pain_chance: 255,
+        pain_sound: crate::sounds::SFX_PLPAIN,
+        melee_state: S_NULL,
+        missile_state: S_PLAY_ATK1,
+        death_state: S_PLAY_DIE1,
+        xdeath_state: S_PLAY_XDIE1,
+        death_sound: crate::sounds::SFX_PLDETH,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(56),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_DROPOFF | MF_PICKUP | MF_NOTDMATCH,
+        raise_state: S_NULL,
+    },
+    // MT_POSSESSED
+    MobjInfo {
+        doomed_num: 3004,
+        spawn_state: S_POSS_STND,
+        spawn_health: 20,
+        see_state: S_POSS_RUN1,
+        see_sound: crate::sounds::SFX_POSIT1,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_PISTOL,
+        pain_state: S_POSS_PAIN,
+        pain_chance: 200,
+        pain_sound: crate::sounds::SFX_POPAIN,
+        melee_state: S_NULL,
+        missile_state: S_POSS_ATK1,
+        death_state: S_POSS_DIE1,
+        xdeath_state: S_POSS_XDIE1,
+        death_sound: crate::sounds::SFX_PODTH1,
+        speed: 8,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(56),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_POSACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_POSS_RAISE1,
+    },
+    // MT_SHOTGUY
+    MobjInfo {
+        doomed_num: 9,
+        spawn_state: S_SPOS_STND,
+        spawn_health: 30,
+        see_state: S_SPOS_RUN1,
+        see_sound: crate::sounds::SFX_POSIT2,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_SPOS_PAIN,
+        pain_chance: 170,
+        pain_sound: crate::sounds::SFX_POPAIN,
+        melee_state: S_NULL,
+        missile_state: S_SPOS_ATK1,
+        death_state: S_SPOS_DIE1,
+        xdeath_state: S_SPOS_XDIE1,
+        death_sound: crate::sounds::SFX_PODTH2,
+        speed: 8,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(56),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_POSACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_SPOS_RAISE1,
+    },
+    // MT_VILE
+    MobjInfo {
+        doomed_num: 64,
+        spawn_state: S_VILE_STND,
+        spawn_health: 700,
+        see_state: S_VILE_RUN1,
+        see_sound: crate::sounds::SFX_VILSIT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_VILE_PAIN,
+        pain_chance: 10,
+        pain_sound: crate::sounds::SFX_VIPAIN,
+        melee_state: S_NULL,
+        missile_state: S_VILE_ATK1,
+        death_state: S_VILE_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_VILDTH,
+        speed: 15,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(56),
+        mass: 500,
+        damage: 0,
+        active_sound: crate::sounds::SFX_VILACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_NULL,
+    },
+    // MT_FIRE
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_FIRE1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_UNDEAD
+    MobjInfo {
+        doomed_num: 66,
+        spawn_state: S_SKEL_STND,
+        spawn_health: 300,
+        see_state: S_SKEL_RUN1,
+        see_sound: crate::sounds::SFX_SKESIT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_SKEL_PAIN,
+        pain_chance: 100,
+        pain_sound: crate::sounds::SFX_POPAIN,
+        melee_state: S_SKEL_FIST1,
+        missile_state: S_SKEL_MISS1,
+        death_state: S_SKEL_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_SKEDTH,
+        speed: 10,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(56),
+        mass: 500,
+        damage: 0,
+        active_sound: crate::sounds::SFX_SKEACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_SKEL_RAISE1,
+    },
+    // MT_TRACER
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_TRACER,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_SKEATK,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_TRACEEXP1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_BAREXP,
+        speed: 10 << FRACBITS,
+        radius: Fixed::from_int(11),
+        height: Fixed::from_int(8),
+        mass: 100,
+        damage: 10,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_SMOKE
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_SMOKE1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_FATSO
+    MobjInfo {
+        doomed_num: 67,
+        spawn_state: S_FATT_STND,
+        spawn_health: 600,
+        see_state: S_FATT_RUN1,
+        see_sound: crate::sounds::SFX_MANSIT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_FATT_PAIN,
+        pain_chance: 80,
+        pain_sound: crate::sounds::SFX_MNPAIN,
+        melee_state: S_NULL,
+        missile_state: S_FATT_ATK1,
+        death_state: S_FATT_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_MANDTH,
+        speed: 8,
+        radius: Fixed::from_int(48),
+        height: Fixed::from_int(64),
+        mass: 1000,
+        damage: 0,
+        active_sound: crate::sounds::SFX_POSACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_FATT_RAISE1,
+    },
+    // MT_FATSHOT
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_FATSHOT1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_FIRSHT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_FATSHOTX1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_FIRXPL,
+        speed: 20 << FRACBITS,
+        radius: Fixed::from_int(6),
+        height: Fixed::from_int(8),
+        mass: 100,
+        damage: 8,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_CHAINGUY
+    MobjInfo {
+        doomed_num: 65,
+        spawn_state: S_CPOS_STND,
+        spawn_health: 70,
+        see_state: S_CPOS_RUN1,
+        see_sound: crate::sounds::SFX_POSIT2,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_CPOS_PAIN,
+        pain_chance: 170,
+        pain_sound: crate::sounds::SFX_POPAIN,
+        melee_state: S_NULL,
+        missile_state: S_CPOS_ATK1,
+        death_state: S_CPOS_DIE1,
+        xdeath_state: S_CPOS_XDIE1,
+        death_sound: crate::sounds::SFX_PODTH2,
+        speed: 8,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(56),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_POSACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_CPOS_RAISE1,
+    },
+    // MT_TROOP
+    MobjInfo {
+        doomed_num: 3001,
+        spawn_state: S_TROO_STND,
+        spawn_health: 60,
+        see_state: S_TROO_RUN1,
+        see_sound: crate::sounds::SFX_BGSIT1,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_TROO_PAIN,
+        pain_chance: 200,
+        pain_sound: crate::sounds::SFX_POPAIN,
+        melee_state: S_TROO_ATK1,
+        missile_state: S_TROO_ATK1,
+        death_state: S_TROO_DIE1,
+        xdeath_state: S_TROO_XDIE1,
+        death_sound: crate::sounds::SFX_BGDTH1,
+        speed: 8,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(56),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_BGACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_TROO_RAISE1,
+    },
+    // MT_SERGEANT
+    MobjInfo {
+        doomed_num: 3002,
+        spawn_state: S_SARG_STND,
+        spawn_health: 150,
+        see_state: S_SARG_RUN1,
+        see_sound: crate::sounds::SFX_SGTSIT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_SGTATK,
+        pain_state: S_SARG_PAIN,
+        pain_chance: 180,
+        pain_sound: crate::sounds::SFX_DMPAIN,
+        melee_state: S_SARG_ATK1,
+        missile_state: S_NULL,
+        death_state: S_SARG_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_SGTDTH,
+        speed: 10,
+        radius: Fixed::from_int(30),
+        height: Fixed::from_int(56),
+        mass: 400,
+        damage: 0,
+        active_sound: crate::sounds::SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_SARG_RAISE1,
+    },
+    // MT_SHADOWS
+    MobjInfo {
+        doomed_num: 58,
+        spawn_state: S_SARG_STND,
+        spawn_health: 150,
+        see_state: S_SARG_RUN1,
+        see_sound: crate::sounds::SFX_SGTSIT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_SGTATK,
+        pain_state: S_SARG_PAIN,
+        pain_chance: 180,
+        pain_sound: crate::sounds::SFX_DMPAIN,
+        melee_state: S_SARG_ATK1,
+        missile_state: S_NULL,
+        death_state: S_SARG_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_SGTDTH,
+        speed: 10,
+        radius: Fixed::from_int(30),
+        height: Fixed::from_int(56),
+        mass: 400,
+        damage: 0,
+        active_sound: crate::sounds::SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_SHADOW | MF_COUNTKILL,
+        raise_state: S_SARG_RAISE1,
+    },
+    // MT_HEAD
+    MobjInfo {
+        doomed_num: 3005,
+        spawn_state: S_HEAD_STND,
+        spawn_health: 400,
+        see_state: S_HEAD_RUN1,
+        see_sound: crate::sounds::SFX_CACSIT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_HEAD_PAIN,
+        pain_chance: 128,
+        pain_sound: crate::sounds::SFX_DMPAIN,
+        melee_state: S_NULL,
+        missile_state: S_HEAD_ATK1,
+        death_state: S_HEAD_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_CACDTH,
+        speed: 8,
+        radius: Fixed::from_int(31),
+        height: Fixed::from_int(56),
+        mass: 400,
+        damage: 0,
+        active_sound: crate::sounds::SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_FLOAT | MF_NOGRAVITY | MF_COUNTKILL,
+        raise_state: S_HEAD_RAISE1,
+    },
+    // MT_BRUISER
+    MobjInfo {
+        doomed_num: 3003,
+        spawn_state: S_BOSS_STND,
+        spawn_health: 1000,
+        see_state: S_BOSS_RUN1,
+        see_sound: crate::sounds::SFX_BRSSIT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_BOSS_PAIN,
+        pain_chance: 50,
+        pain_sound: crate::sounds::SFX_DMPAIN,
+        melee_state: S_BOSS_ATK1,
+        missile_state: S_BOSS_ATK1,
+        death_state: S_BOSS_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_BRSDTH,
+        speed: 8,
+        radius: Fixed::from_int(24),
+        height: Fixed::from_int(64),
+        mass: 1000,
+        damage: 0,
+        active_sound: crate::sounds::SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_BOSS_RAISE1,
+    },
+    // MT_BRUISERSHOT
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_BRBALL1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_FIRSHT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_BRBALLX1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_FIRXPL,
+        speed: 15 << FRACBITS,
+        radius: Fixed::from_int(6),
+        height: Fixed::from_int(8),
+        mass: 100,
+        damage: 8,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_KNIGHT
+    MobjInfo {
+        doomed_num: 69,
+        spawn_state: S_BOS2_STND,
+        spawn_health: 500,
+        see_state: S_BOS2_RUN1,
+        see_sound: crate::sounds::SFX_KNTSIT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_BOS2_PAIN,
+        pain_chance: 50,
+        pain_sound: crate::sounds::SFX_DMPAIN,
+        melee_state: S_BOS2_ATK1,
+        missile_state: S_BOS2_ATK1,
+        death_state: S_BOS2_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_KNTDTH,
+        speed: 8,
+        radius: Fixed::from_int(24),
+        height: Fixed::from_int(64),
+        mass: 1000,
+        damage: 0,
+        active_sound: crate::sounds::SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_BOS2_RAISE1,
+    },
+    // MT_SKULL
+    MobjInfo {
+        doomed_num: 3006,
+        spawn_state: S_SKULL_STND,
+        spawn_health: 100,
+        see_state: S_SKULL_RUN1,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_SKLATK,
+        pain_state: S_SKULL_PAIN,
+        pain_chance: 256,
+        pain_sound: crate::sounds::SFX_DMPAIN,
+        melee_state: S_NULL,
+        missile_state: S_SKULL_ATK1,
+        death_state: S_SKULL_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_FIRXPL,
+        speed: 8,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(56),
+        mass: 50,
+        damage: 3,
+        active_sound: crate::sounds::SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_FLOAT | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_SPIDER
+    MobjInfo {
+        doomed_num: 7,
+        spawn_state: S_SPID_STND,
+        spawn_health: 3000,
+        see_state: S_SPID_RUN1,
+        see_sound: crate::sounds::SFX_SPISIT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_SHOTGN,
+        pain_state: S_SPID_PAIN,
+        pain_chance: 40,
+        pain_sound: crate::sounds::SFX_DMPAIN,
+        melee_state: S_NULL,
+        missile_state: S_SPID_ATK1,
+        death_state: S_SPID_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_SPIDTH,
+        speed: 12,
+        radius: Fixed::from_int(128),
+        height: Fixed::from_int(100),
+        mass: 1000,
+        damage: 0,
+        active_sound: crate::sounds::SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_NULL,
+    },
+    // MT_BABY
+    MobjInfo {
+        doomed_num: 68,
+        spawn_state: S_BSPI_STND,
+        spawn_health: 500,
+        see_state: S_BSPI_SIGHT,
+        see_sound: crate::sounds::SFX_BSPSIT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_BSPI_PAIN,
+        pain_chance: 128,
+        pain_sound: crate::sounds::SFX_DMPAIN,
+        melee_state: S_NULL,
+        missile_state: S_BSPI_ATK1,
+        death_state: S_BSPI_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_BSPDTH,
+        speed: 12,
+        radius: Fixed::from_int(64),
+        height: Fixed::from_int(64),
+        mass: 600,
+        damage: 0,
+        active_sound: crate::sounds::SFX_BSPACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_BSPI_RAISE1,
+    },
+    // MT_CYBORG
+    MobjInfo {
+        doomed_num: 16,
+        spawn_state: S_CYBER_STND,
+        spawn_health: 4000,
+        see_state: S_CYBER_RUN1,
+        see_sound: crate::sounds::SFX_CYBSIT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_CYBER_PAIN,
+        pain_chance: 20,
+        pain_sound: crate::sounds::SFX_DMPAIN,
+        melee_state: S_NULL,
+        missile_state: S_CYBER_ATK1,
+        death_state: S_CYBER_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_CYBDTH,
+        speed: 16,
+        radius: Fixed::from_int(40),
+        height: Fixed::from_int(110),
+        mass: 1000,
+        damage: 0,
+        active_sound: crate::sounds::SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_NULL,
+    },
+    // MT_PAIN
+    MobjInfo {
+        doomed_num: 71,
+        spawn_state: S_PAIN_STND,
+        spawn_health: 400,
+        see_state: S_PAIN_RUN1,
+        see_sound: crate::sounds::SFX_PESIT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_PAIN_PAIN,
+        pain_chance: 128,
+        pain_sound: crate::sounds::SFX_PEPAIN,
+        melee_state: S_NULL,
+        missile_state: S_PAIN_ATK1,
+        death_state: S_PAIN_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_PEDTH,
+        speed: 8,
+        radius: Fixed::from_int(31),
+        height: Fixed::from_int(56),
+        mass: 400,
+        damage: 0,
+        active_sound: crate::sounds::SFX_DMACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_FLOAT | MF_NOGRAVITY | MF_COUNTKILL,
+        raise_state: S_PAIN_RAISE1,
+    },
+    // MT_WOLFSS
+    MobjInfo {
+        doomed_num: 84,
+        spawn_state: S_SSWV_STND,
+        spawn_health: 50,
+        see_state: S_SSWV_RUN1,
+        see_sound: crate::sounds::SFX_SSSIT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_SSWV_PAIN,
+        pain_chance: 170,
+        pain_sound: crate::sounds::SFX_POPAIN,
+        melee_state: S_NULL,
+        missile_state: S_SSWV_ATK1,
+        death_state: S_SSWV_DIE1,
+        xdeath_state: S_SSWV_XDIE1,
+        death_sound: crate::sounds::SFX_SSDTH,
+        speed: 8,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(56),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_POSACT,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_SSWV_RAISE1,
+    },
+    // MT_KEEN
+    MobjInfo {
+        doomed_num: 72,
+        spawn_state: S_KEENSTND,
+        spawn_health: 100,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_KEENPAIN,
+        pain_chance: 256,
+        pain_sound: crate::sounds::SFX_KEENPN,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_COMMKEEN,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_KEENDT,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(72),
+        mass: 10000000,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY | MF_SHOOTABLE | MF_COUNTKILL,
+        raise_state: S_NULL,
+    },
+    // MT_BOSSBRAIN
+    MobjInfo {
+        doomed_num: 88,
+        spawn_state: S_BRAIN,
+        spawn_health: 250,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_BRAIN_PAIN,
+        pain_chance: 255,
+        pain_sound: crate::sounds::SFX_BOSPN,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_BRAIN_DIE1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_BOSDTH,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 10000000,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID | MF_SHOOTABLE,
+        raise_state: S_NULL,
+    },
+    // MT_BOSSSPIT
+    MobjInfo {
+        doomed_num: 89,
+        spawn_state: S_BRAINEYE,
+        spawn_health: 1000,
+        see_state: S_BRAINEYESEE,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(32),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOSECTOR,
+        raise_state: S_NULL,
+    },
+    // MT_BOSSTARGET
+    MobjInfo {
+        doomed_num: 87,
+        spawn_state: S_NULL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(32),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOSECTOR,
+        raise_state: S_NULL,
+    },
+    // MT_SPAWNSHOT
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_SPAWN1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_BOSPIT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_FIRXPL,
+        speed: 10 << FRACBITS,
+        radius: Fixed::from_int(6),
+        height: Fixed::from_int(32),
+        mass: 100,
+        damage: 3,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY | MF_NOCLIP,
+        raise_state: S_NULL,
+    },
+    // MT_SPAWNFIRE
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_SPAWNFIRE1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_BARREL
+    MobjInfo {
+        doomed_num: 2035,
+        spawn_state: S_BAR1,
+        spawn_health: 20,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_BEXP,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_BAREXP,
+        speed: 0,
+        radius: Fixed::from_int(10),
+        height: Fixed::from_int(42),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID | MF_SHOOTABLE | MF_NOBLOOD,
+        raise_state: S_NULL,
+    },
+    // MT_TROOPSHOT
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_TBALL1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_FIRSHT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_TBALLX1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_FIRXPL,
+        speed: 10 << FRACBITS,
+        radius: Fixed::from_int(6),
+        height: Fixed::from_int(8),
+        mass: 100,
+        damage: 3,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_HEADSHOT
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_RBALL1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_FIRSHT,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_RBALLX1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_FIRXPL,
+        speed: 10 << FRACBITS,
+        radius: Fixed::from_int(6),
+        height: Fixed::from_int(8),
+        mass: 100,
+        damage: 5,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_ROCKET
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_ROCKET,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_RLAUNC,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_EXPLODE1,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_BAREXP,
+        speed: 20 << FRACBITS,
+        radius: Fixed::from_int(11),
+        height: Fixed::from_int(8),
+        mass: 100,
+        damage: 20,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_PLASMA
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_PLASBALL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_PLASMA,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_PLASEXP,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_FIRXPL,
+        speed: 25 << FRACBITS,
+        radius: Fixed::from_int(13),
+        height: Fixed::from_int(8),
+        mass: 100,
+        damage: 5,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_BFG
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_BFGSHOT,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_BFGLAND,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_RXPLOD,
+        speed: 25 << FRACBITS,
+        radius: Fixed::from_int(13),
+        height: Fixed::from_int(8),
+        mass: 100,
+        damage: 100,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_ARACHPLAZ
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_ARACH_PLAZ,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_PLASMA,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_ARACH_PLEX,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_FIRXPL,
+        speed: 25 << FRACBITS,
+        radius: Fixed::from_int(13),
+        height: Fixed::from_int(8),
+        mass: 100,
+        damage: 5,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_MISSILE | MF_DROPOFF | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_PUFF
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_PUFF1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_BLOOD
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_BLOOD1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP,
+        raise_state: S_NULL,
+    },
+    // MT_TFOG
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_TFOG,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_IFOG
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_IFOG,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_TELEPORTMAN
+    MobjInfo {
+        doomed_num: 14,
+        spawn_state: S_NULL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOSECTOR,
+        raise_state: S_NULL,
+    },
+    // MT_EXTRABFG
+    MobjInfo {
+        doomed_num: -1,
+        spawn_state: S_BFGEXP,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC0
+    MobjInfo {
+        doomed_num: 2018,
+        spawn_state: S_ARM1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC1
+    MobjInfo {
+        doomed_num: 2019,
+        spawn_state: S_ARM2,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC2
+    MobjInfo {
+        doomed_num: 2014,
+        spawn_state: S_BON1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_MISC3
+    MobjInfo {
+        doomed_num: 2015,
+        spawn_state: S_BON2,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_MISC4
+    MobjInfo {
+        doomed_num: 5,
+        spawn_state: S_BKEY,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL | MF_NOTDMATCH,
+        raise_state: S_NULL,
+    },
+    // MT_MISC5
+    MobjInfo {
+        doomed_num: 13,
+        spawn_state: S_RKEY,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL | MF_NOTDMATCH,
+        raise_state: S_NULL,
+    },
+    // MT_MISC6
+    MobjInfo {
+        doomed_num: 6,
+        spawn_state: S_YKEY,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL | MF_NOTDMATCH,
+        raise_state: S_NULL,
+    },
+    // MT_MISC7
+    MobjInfo {
+        doomed_num: 39,
+        spawn_state: S_YSKULL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL | MF_NOTDMATCH,
+        raise_state: S_NULL,
+    },
+    // MT_MISC8
+    MobjInfo {
+        doomed_num: 38,
+        spawn_state: S_RSKULL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL | MF_NOTDMATCH,
+        raise_state: S_NULL,
+    },
+    // MT_MISC9
+    MobjInfo {
+        doomed_num: 40,
+        spawn_state: S_BSKULL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL | MF_NOTDMATCH,
+        raise_state: S_NULL,
+    },
+    // MT_MISC10
+    MobjInfo {
+        doomed_num: 2011,
+        spawn_state: S_STIM,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC11
+    MobjInfo {
+        doomed_num: 2012,
+        spawn_state: S_MEDI,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC12
+    MobjInfo {
+        doomed_num: 2013,
+        spawn_state: S_SOUL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_INV
+    MobjInfo {
+        doomed_num: 2022,
+        spawn_state: S_PINV,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_MISC13
+    MobjInfo {
+        doomed_num: 2023,
+        spawn_state: S_PSTR,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_INS
+    MobjInfo {
+        doomed_num: 2024,
+        spawn_state: S_PINS,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_MISC14
+    MobjInfo {
+        doomed_num: 2025,
+        spawn_state: S_SUIT,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC15
+    MobjInfo {
+        doomed_num: 2026,
+        spawn_state: S_PMAP,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_MISC16
+    MobjInfo {
+        doomed_num: 2045,
+        spawn_state: S_PVIS,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_MEGA
+    MobjInfo {
+        doomed_num: 83,
+        spawn_state: S_MEGA,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL | MF_COUNTITEM,
+        raise_state: S_NULL,
+    },
+    // MT_CLIP
+    MobjInfo {
+        doomed_num: 2007,
+        spawn_state: S_CLIP,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC17
+    MobjInfo {
+        doomed_num: 2048,
+        spawn_state: S_AMMO,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC18
+    MobjInfo {
+        doomed_num: 2010,
+        spawn_state: S_ROCK,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC19
+    MobjInfo {
+        doomed_num: 2046,
+        spawn_state: S_BROK,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC20
+    MobjInfo {
+        doomed_num: 2047,
+        spawn_state: S_CELL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC21
+    MobjInfo {
+        doomed_num: 17,
+        spawn_state: S_CELP,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC22
+    MobjInfo {
+        doomed_num: 2008,
+        spawn_state: S_SHEL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC23
+    MobjInfo {
+        doomed_num: 2049,
+        spawn_state: S_SBOX,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC24
+    MobjInfo {
+        doomed_num: 8,
+        spawn_state: S_BPAK,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC25
+    MobjInfo {
+        doomed_num: 2006,
+        spawn_state: S_BFUG,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_CHAINGUN
+    MobjInfo {
+        doomed_num: 2002,
+        spawn_state: S_MGUN,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC26
+    MobjInfo {
+        doomed_num: 2005,
+        spawn_state: S_CSAW,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC27
+    MobjInfo {
+        doomed_num: 2003,
+        spawn_state: S_LAUN,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC28
+    MobjInfo {
+        doomed_num: 2004,
+        spawn_state: S_PLAS,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_SHOTGUN
+    MobjInfo {
+        doomed_num: 2001,
+        spawn_state: S_SHOT,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_SUPERSHOTGUN
+    MobjInfo {
+        doomed_num: 82,
+        spawn_state: S_SHOT2,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPECIAL,
+        raise_state: S_NULL,
+    },
+    // MT_MISC29
+    MobjInfo {
+        doomed_num: 85,
+        spawn_state: S_TECHLAMP,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC30
+    MobjInfo {
+        doomed_num: 86,
+        spawn_state: S_TECH2LAMP,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC31
+    MobjInfo {
+        doomed_num: 2028,
+        spawn_state: S_COLU,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC32
+    MobjInfo {
+        doomed_num: 30,
+        spawn_state: S_TALLGRNCOL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC33
+    MobjInfo {
+        doomed_num: 31,
+        spawn_state: S_SHRTGRNCOL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC34
+    MobjInfo {
+        doomed_num: 32,
+        spawn_state: S_TALLREDCOL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC35
+    MobjInfo {
+        doomed_num: 33,
+        spawn_state: S_SHRTREDCOL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC36
+    MobjInfo {
+        doomed_num: 37,
+        spawn_state: S_SKULLCOL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC37
+    MobjInfo {
+        doomed_num: 36,
+        spawn_state: S_HEARTCOL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC38
+    MobjInfo {
+        doomed_num: 41,
+        spawn_state: S_EVILEYE,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC39
+    MobjInfo {
+        doomed_num: 42,
+        spawn_state: S_FLOATSKULL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC40
+    MobjInfo {
+        doomed_num: 43,
+        spawn_state: S_TORCHTREE,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC41
+    MobjInfo {
+        doomed_num: 44,
+        spawn_state: S_BLUETORCH,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC42
+    MobjInfo {
+        doomed_num: 45,
+        spawn_state: S_GREENTORCH,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC43
+    MobjInfo {
+        doomed_num: 46,
+        spawn_state: S_REDTORCH,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC44
+    MobjInfo {
+        doomed_num: 55,
+        spawn_state: S_BTORCHSHRT,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC45
+    MobjInfo {
+        doomed_num: 56,
+        spawn_state: S_GTORCHSHRT,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC46
+    MobjInfo {
+        doomed_num: 57,
+        spawn_state: S_RTORCHSHRT,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC47
+    MobjInfo {
+        doomed_num: 47,
+        spawn_state: S_STALAGTITE,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC48
+    MobjInfo {
+        doomed_num: 48,
+        spawn_state: S_TECHPILLAR,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC49
+    MobjInfo {
+        doomed_num: 34,
+        spawn_state: S_CANDLESTIK,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC50
+    MobjInfo {
+        doomed_num: 35,
+        spawn_state: S_CANDELABRA,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC51
+    MobjInfo {
+        doomed_num: 49,
+        spawn_state: S_BLOODYTWITCH,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(68),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC52
+    MobjInfo {
+        doomed_num: 50,
+        spawn_state: S_MEAT2,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(84),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC53
+    MobjInfo {
+        doomed_num: 51,
+        spawn_state: S_MEAT3,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(84),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC54
+    MobjInfo {
+        doomed_num: 52,
+        spawn_state: S_MEAT4,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(68),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC55
+    MobjInfo {
+        doomed_num: 53,
+        spawn_state: S_MEAT5,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(52),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC56
+    MobjInfo {
+        doomed_num: 59,
+        spawn_state: S_MEAT2,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(84),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC57
+    MobjInfo {
+        doomed_num: 60,
+        spawn_state: S_MEAT4,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(68),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC58
+    MobjInfo {
+        doomed_num: 61,
+        spawn_state: S_MEAT3,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(52),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC59
+    MobjInfo {
+        doomed_num: 62,
+        spawn_state: S_MEAT5,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(52),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC60
+    MobjInfo {
+        doomed_num: 63,
+        spawn_state: S_BLOODYTWITCH,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(68),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC61
+    MobjInfo {
+        doomed_num: 22,
+        spawn_state: S_HEAD_DIE6,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC62
+    MobjInfo {
+        doomed_num: 15,
+        spawn_state: S_PLAY_DIE7,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC63
+    MobjInfo {
+        doomed_num: 18,
+        spawn_state: S_POSS_DIE5,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC64
+    MobjInfo {
+        doomed_num: 21,
+        spawn_state: S_SARG_DIE6,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC65
+    MobjInfo {
+        doomed_num: 23,
+        spawn_state: S_SKULL_DIE6,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC66
+    MobjInfo {
+        doomed_num: 20,
+        spawn_state: S_TROO_DIE5,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC67
+    MobjInfo {
+        doomed_num: 19,
+        spawn_state: S_SPOS_DIE5,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC68
+    MobjInfo {
+        doomed_num: 10,
+        spawn_state: S_PLAY_XDIE9,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC69
+    MobjInfo {
+        doomed_num: 12,
+        spawn_state: S_PLAY_XDIE9,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC70
+    MobjInfo {
+        doomed_num: 28,
+        spawn_state: S_HEADSONSTICK,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC71
+    MobjInfo {
+        doomed_num: 24,
+        spawn_state: S_GIBS,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: 0,
+        raise_state: S_NULL,
+    },
+    // MT_MISC72
+    MobjInfo {
+        doomed_num: 27,
+        spawn_state: S_HEADONASTICK,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC73
+    MobjInfo {
+        doomed_num: 29,
+        spawn_state: S_HEADCANDLES,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC74
+    MobjInfo {
+        doomed_num: 25,
+        spawn_state: S_DEADSTICK,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC75
+    MobjInfo {
+        doomed_num: 26,
+        spawn_state: S_LIVESTICK,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC76
+    MobjInfo {
+        doomed_num: 54,
+        spawn_state: S_BIGTREE,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(32),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC77
+    MobjInfo {
+        doomed_num: 70,
+        spawn_state: S_BBAR1,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID,
+        raise_state: S_NULL,
+    },
+    // MT_MISC78
+    MobjInfo {
+        doomed_num: 73,
+        spawn_state: S_HANGNOGUTS,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(88),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC79
+    MobjInfo {
+        doomed_num: 74,
+        spawn_state: S_HANGBNOBRAIN,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(88),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC80
+    MobjInfo {
+        doomed_num: 75,
+        spawn_state: S_HANGTLOOKDN,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(64),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC81
+    MobjInfo {
+        doomed_num: 76,
+        spawn_state: S_HANGTSKULL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(64),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC82
+    MobjInfo {
+        doomed_num: 77,
+        spawn_state: S_HANGTLOOKUP,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(64),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC83
+    MobjInfo {
+        doomed_num: 78,
+        spawn_state: S_HANGTNOBRAIN,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(16),
+        height: Fixed::from_int(64),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_SOLID | MF_SPAWNCEILING | MF_NOGRAVITY,
+        raise_state: S_NULL,
+    },
+    // MT_MISC84
+    MobjInfo {
+        doomed_num: 79,
+        spawn_state: S_COLONGIBS,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP,
+        raise_state: S_NULL,
+    },
+    // MT_MISC85
+    MobjInfo {
+        doomed_num: 80,
+        spawn_state: S_SMALLPOOL,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP,
+        raise_state: S_NULL,
+    },
+    // MT_MISC86
+    MobjInfo {
+        doomed_num: 81,
+        spawn_state: S_BRAINSTEM,
+        spawn_health: 1000,
+        see_state: S_NULL,
+        see_sound: crate::sounds::SFX_NONE,
+        reaction_time: 8,
+        attack_sound: crate::sounds::SFX_NONE,
+        pain_state: S_NULL,
+        pain_chance: 0,
+        pain_sound: crate::sounds::SFX_NONE,
+        melee_state: S_NULL,
+        missile_state: S_NULL,
+        death_state: S_NULL,
+        xdeath_state: S_NULL,
+        death_sound: crate::sounds::SFX_NONE,
+        speed: 0,
+        radius: Fixed::from_int(20),
+        height: Fixed::from_int(16),
+        mass: 100,
+        damage: 0,
+        active_sound: crate::sounds::SFX_NONE,
+        flags: MF_NOBLOCKMAP,
+        raise_state: S_NULL,
+    },
+];
+
+#[derive(Clone)]
+pub struct Info {
+    pub states: Vec<StateInfo>,
+    pub mobj_info: Vec<MobjInfo>,
+    pub weapon_info: Vec<crate::items::WeaponInfo>,
+    pub max_ammo: [i32; crate::items::NUM_AMMO],
+    pub clip_ammo: [i32; crate::items::NUM_AMMO],
+    pub sfx: Vec<crate::sounds::SfxInfo>,
+}
+
+impl Default for Info {
+    fn default() -> Self {
+        Self {
+            states: STATES.to_vec(),
+            mobj_info: MOBJ_INFO.to_vec(),
+            weapon_info: crate::items::WEAPON_INFO.to_vec(),
+            max_ammo: crate::items::MAX_AMMO,
+            clip_ammo: crate::items::CLIP_AMMO,
+            sfx: crate::sounds::SFX.to_vec(),
+        }
+    }
+}
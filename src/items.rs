@@ -0,0 +1,116 @@
+use crate::info::*;
+
+pub const AM_CLIP: usize = 0;
+pub const AM_SHELL: usize = 1;
+pub const AM_CELL: usize = 2;
+pub const AM_MISL: usize = 3;
+pub const NUM_AMMO: usize = 4;
+pub const AM_NOAMMO: usize = 5;
+
+pub const WP_FIST: usize = 0;
+pub const WP_PISTOL: usize = 1;
+pub const WP_SHOTGUN: usize = 2;
+pub const WP_CHAINGUN: usize = 3;
+pub const WP_MISSILE: usize = 4;
+pub const WP_PLASMA: usize = 5;
+pub const WP_BFG: usize = 6;
+pub const WP_CHAINSAW: usize = 7;
+pub const WP_SUPERSHOTGUN: usize = 8;
+pub const NUM_WEAPONS: usize = 9;
+
+pub const MAX_AMMO: [i32; NUM_AMMO] = [200, 50, 300, 50];
+pub const CLIP_AMMO: [i32; NUM_AMMO] = [10, 4, 20, 1];
+
+#[derive(Clone, Copy, Debug)]
+pub struct WeaponInfo {
+    pub ammo: usize,
+    pub up_state: usize,
+    pub down_state: usize,
+    pub ready_state: usize,
+    pub attack_state: usize,
+    pub flash_state: usize,
+}
+
+pub const WEAPON_INFO: [WeaponInfo; NUM_WEAPONS] = [
+    // fist
+    WeaponInfo {
+        ammo: AM_NOAMMO,
+        up_state: S_PUNCHUP,
+        down_state: S_PUNCHDOWN,
+        ready_state: S_PUNCH,
+        attack_state: S_PUNCH1,
+        flash_state: S_NULL,
+    },
+    // pistol
+    WeaponInfo {
+        ammo: AM_CLIP,
+        up_state: S_PISTOLUP,
+        down_state: S_PISTOLDOWN,
+        ready_state: S_PISTOL,
+        attack_state: S_PISTOL1,
+        flash_state: S_PISTOLFLASH,
+    },
+    // shotgun
+    WeaponInfo {
+        ammo: AM_SHELL,
+        up_state: S_SGUNUP,
+        down_state: S_SGUNDOWN,
+        ready_state: S_SGUN,
+        attack_state: S_SGUN1,
+        flash_state: S_SGUNFLASH1,
+    },
+    // chaingun
+    WeaponInfo {
+        ammo: AM_CLIP,
+        up_state: S_CHAINUP,
+        down_state: S_CHAINDOWN,
+        ready_state: S_CHAIN,
+        attack_state: S_CHAIN1,
+        flash_state: S_CHAINFLASH1,
+    },
+    // missile launcher
+    WeaponInfo {
+        ammo: AM_MISL,
+        up_state: S_MISSILEUP,
+        down_state: S_MISSILEDOWN,
+        ready_state: S_MISSILE,
+        attack_state: S_MISSILE1,
+        flash_state: S_MISSILEFLASH1,
+    },
+    // plasma rifle
+    WeaponInfo {
+        ammo: AM_CELL,
+        up_state: S_PLASMAUP,
+        down_state: S_PLASMADOWN,
+        ready_state: S_PLASMA,
+        attack_state: S_PLASMA1,
+        flash_state: S_PLASMAFLASH1,
+    },
+    // bfg 9000
+    WeaponInfo {
+        ammo: AM_CELL,
+        up_state: S_BFGUP,
+        down_state: S_BFGDOWN,
+        ready_state: S_BFG,
+        attack_state: S_BFG1,
+        flash_state: S_BFGFLASH1,
+    },
+    // chainsaw
+    WeaponInfo {
+        ammo: AM_NOAMMO,
+        up_state: S_SAWUP,
+        down_state: S_SAWDOWN,
+        ready_state: S_SAW,
+        attack_state: S_SAW1,
+        flash_state: S_NULL,
+    },
+    // super shotgun
+    WeaponInfo {
+        ammo: AM_SHELL,
+        up_state: S_DSGUNUP,
+        down_state: S_DSGUNDOWN,
+        ready_state: S_DSGUN,
+        attack_state: S_DSGUN1,
+        flash_state: S_DSGUNFLASH1,
+    },
+];
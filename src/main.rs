@@ -1,16 +1,29 @@
+pub mod angle;
+pub mod data;
 pub mod defs;
+pub mod deh;
 pub mod doom;
 pub mod english;
 pub mod fixed;
+pub mod info;
+pub mod items;
 pub mod keys;
+pub mod map;
 pub mod meta;
 pub mod misc;
+pub mod music;
 pub mod net;
 pub mod options;
+pub mod play;
+pub mod random;
+pub mod sha1;
+pub mod sounds;
 pub mod state;
 pub mod strings;
 pub mod system;
+pub mod tables;
 pub mod types;
+pub mod wad;
 
 fn main() {
     let mut state: Box<state::State> = Box::default();
@@ -21,6 +34,14 @@ fn main() {
         return;
     }
 
+    if state.args.get(1).map(|arg| arg == "wad").unwrap_or(false) {
+        state.wad_tool();
+    }
+
+    if state.check_parm_with_args("--check-wad", 1).is_some() {
+        state.check_wad_mode();
+    }
+
     state.add_loose_files();
     state.find_response_file();
 
@@ -0,0 +1,348 @@
+pub mod blockmap;
+pub mod error;
+pub mod extended;
+pub mod lumps;
+pub mod nodes;
+pub mod point;
+pub mod stats;
+#[cfg(test)]
+pub mod testing;
+pub mod udmf;
+pub mod validate;
+
+use crate::fixed::Fixed;
+use error::MapError;
+
+pub const NF_SUBSECTOR: u16 = 0x8000;
+pub const NO_INDEX: u16 = 0xffff;
+
+pub const BOXTOP: usize = 0;
+pub const BOXBOTTOM: usize = 1;
+pub const BOXLEFT: usize = 2;
+pub const BOXRIGHT: usize = 3;
+
+pub const MAPBLOCKUNITS: i64 = 128;
+
+pub const MTF_EASY: i16 = 1;
+pub const MTF_NORMAL: i16 = 2;
+pub const MTF_HARD: i16 = 4;
+pub const MTF_AMBUSH: i16 = 8;
+pub const MTF_NOTSINGLE: i16 = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Vertex {
+    pub x: Fixed,
+    pub y: Fixed,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Thing {
+    pub x: Fixed,
+    pub y: Fixed,
+    pub angle: i16,
+    pub kind: i16,
+    pub options: i16,
+}
+
+// A Hexen style line special, which takes its arguments from the line
+// rather than acting on the sectors with the line's tag.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HexenSpecial {
+    pub special: i32,
+    pub args: [i32; 5],
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct LineDef {
+    pub v1: usize,
+    pub v2: usize,
+    pub flags: i16,
+    // Always 0 on lines with a Hexen special, so nothing takes one for a
+    // Doom line type.
+    pub special: i16,
+    pub tag: i16,
+    pub sidenum: [Option<usize>; 2],
+    pub hexen_special: Option<HexenSpecial>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SideDef {
+    pub texture_offset: Fixed,
+    pub row_offset: Fixed,
+    pub top_texture: String,
+    pub bottom_texture: String,
+    pub mid_texture: String,
+    pub sector: usize,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Sector {
+    pub floor_height: Fixed,
+    pub ceiling_height: Fixed,
+    pub floor_pic: String,
+    pub ceiling_pic: String,
+    pub light_level: i16,
+    pub special: i16,
+    pub tag: i16,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Seg {
+    pub v1: usize,
+    pub v2: usize,
+    // BAM, with the lump's 16 bits in the high half as vanilla keeps it.
+    pub angle: crate::angle::Angle,
+    pub linedef: usize,
+    pub side: usize,
+    pub offset: Fixed,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SubSector {
+    pub num_segs: usize,
+    pub first_seg: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NodeChild {
+    Node(usize),
+    SubSector(usize),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Node {
+    pub x: Fixed,
+    pub y: Fixed,
+    pub dx: Fixed,
+    pub dy: Fixed,
+    pub bbox: [[Fixed; 4]; 2],
+    pub children: [NodeChild; 2],
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Blockmap {
+    pub origin_x: Fixed,
+    pub origin_y: Fixed,
+    pub columns: usize,
+    pub rows: usize,
+    // Linedefs per block, row by row. Vanilla lists start with a 0, which
+    // is kept, as vanilla checks linedef 0 against every block because of it.
+    pub lines: Vec<Vec<usize>>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct Map {
+    pub name: String,
+    pub things: Vec<Thing>,
+    pub linedefs: Vec<LineDef>,
+    pub sidedefs: Vec<SideDef>,
+    pub vertexes: Vec<Vertex>,
+    pub segs: Vec<Seg>,
+    pub subsectors: Vec<SubSector>,
+    pub nodes: Vec<Node>,
+    pub node_format: extended::NodeFormat,
+    pub sectors: Vec<Sector>,
+    pub reject: Vec<u8>,
+    pub blockmap: Blockmap,
+    pub warnings: Vec<String>,
+}
+
+impl Map {
+    pub fn load(
+        wad: &crate::wad::Wad,
+        name: &str,
+        rebuild_blockmap: bool,
+    ) -> Result<Self, MapError> {
+        let header = wad
+            .check_num_for_name(name)
+            .filter(|&lump| crate::data::is_map_header(wad, lump))
+            .ok_or_else(|| MapError::NoSuchMap(name.to_owned()))?;
+        Map::load_header(wad, header, rebuild_blockmap)
+    }
+
+    pub fn load_header(
+        wad: &crate::wad::Wad,
+        header: usize,
+        rebuild_blockmap: bool,
+    ) -> Result<Self, MapError> {
+        let range = crate::data::map_lump_range(wad, header);
+        let name = wad.lumps[header].name();
+
+        let find = |lump_name: &str| {
+            range
+                .clone()
+                .find(|&lump| wad.lumps[lump].name().eq_ignore_ascii_case(lump_name))
+        };
+        let read = |lump_name: &'static str| -> Result<Option<Vec<u8>>, MapError> {
+            match find(lump_name) {
+                Some(lump) => Ok(Some(wad.read_lump(lump)?)),
+                None => Ok(None),
+            }
+        };
+        let required = |lump_name: &'static str| -> Result<Vec<u8>, MapError> {
+            read(lump_name)?.ok_or_else(|| MapError::MissingLump {
+                map: name.clone(),
+                lump: lump_name,
+            })
+        };
+
+        let mut map = Map {
+            name: name.clone(),
+            ..Map::default()
+        };
+        if let Some(textmap) = read("TEXTMAP")? {
+            map.load_textmap(&textmap)?;
+            map.load_znodes(&read("ZNODES")?.unwrap_or_default());
+        } else {
+            map.things = lumps::decode_things(&name, &required("THINGS")?)?;
+            map.linedefs = lumps::decode_linedefs(&name, &required("LINEDEFS")?)?;
+            map.sidedefs = lumps::decode_sidedefs(&name, &required("SIDEDEFS")?)?;
+            map.vertexes = lumps::decode_vertexes(&name, &required("VERTEXES")?)?;
+            map.sectors = lumps::decode_sectors(&name, &required("SECTORS")?)?;
+            map.decode_bsp(
+                &read("SEGS")?.unwrap_or_default(),
+                &read("SSECTORS")?.unwrap_or_default(),
+                &read("NODES")?.unwrap_or_default(),
+            )?;
+        }
+        map.reject = read("REJECT")?.unwrap_or_default();
+        map.validate()?;
+
+        // A map with a single subsector needs no nodes; any other map without
+        // them has not been through a node builder yet.
+        if map.nodes.is_empty() && map.subsectors.len() != 1 {
+            map.build_nodes();
+            map.warnings.push(format!(
+                "map has no nodes, built {} nodes and {} subsectors",
+                map.nodes.len(),
+                map.subsectors.len()
+            ));
+        }
+
+        if rebuild_blockmap {
+            map.build_blockmap();
+        } else {
+            map.load_blockmap(&read("BLOCKMAP")?.unwrap_or_default());
+        }
+        map.check_reject();
+
+        Ok(map)
+    }
+
+    // Vanilla reads past a short REJECT into whatever follows it in memory;
+    // pad it with zeros, which means every sector can see every other.
+    fn check_reject(&mut self) {
+        let expected = (self.sectors.len() * self.sectors.len()).div_ceil(8);
+        if self.reject.len() < expected {
+            if !self.reject.is_empty() {
+                self.warnings.push(format!(
+                    "REJECT is {} bytes, expected {}",
+                    self.reject.len(),
+                    expected
+                ));
+            }
+            self.reject.resize(expected, 0);
+        }
+    }
+
+    pub fn is_rejected(&self, sector1: usize, sector2: usize) -> bool {
+        let bit = sector1 * self.sectors.len() + sector2;
+        self.reject
+            .get(bit / 8)
+            .map(|byte| byte & (1 << (bit % 8)) != 0)
+            .unwrap_or(false)
+    }
+}
+
+impl crate::state::State {
+    pub fn load_map(&self, name: &str) -> Map {
+        let map = Map::load(&self.wad, name, self.rebuild_blockmap_parm)
+            .unwrap_or_else(|e| self.error(e.to_string()));
+
+        for warning in &map.warnings {
+            eprintln!("{}: {}", map.name, warning);
+        }
+
+        map
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::testing::MapLumps;
+    use super::*;
+
+    #[test]
+    fn loads_a_room() {
+        let mut lumps = MapLumps::room(256);
+        lumps.thing(64, 96, 90, 1, 7);
+        let map = lumps.load(&[]).unwrap();
+
+        assert_eq!(map.name, "MAP01");
+        assert_eq!(map.vertexes.len(), 4);
+        assert_eq!(map.linedefs.len(), 4);
+        assert_eq!(map.sectors[0].ceiling_height, Fixed::from_int(128));
+        assert_eq!(
+            map.things,
+            [Thing {
+                x: Fixed::from_int(64),
+                y: Fixed::from_int(96),
+                angle: 90,
+                kind: 1,
+                options: 7,
+            }]
+        );
+        assert_eq!(map.linedefs[1].sidenum, [Some(1), None]);
+        assert_eq!(map.reject.len(), 1);
+    }
+
+    #[test]
+    fn lumps_must_be_whole_records() {
+        let mut lumps = MapLumps::room(256);
+        lumps.linedefs.push(0);
+        match lumps.load(&[]) {
+            Err(MapError::BadLumpSize {
+                lump: "LINEDEFS",
+                size: 57,
+                record_size: 14,
+                ..
+            }) => {}
+            other => panic!("{:?}", other.map(|m| m.name)),
+        }
+    }
+
+    #[test]
+    fn missing_lumps_are_named() {
+        let lumps = MapLumps::room(256)
+            .lumps()
+            .into_iter()
+            .filter(|(name, _)| *name != "SIDEDEFS")
+            .collect::<Vec<_>>();
+        let wad = testing::write_wad("MAP01", &lumps);
+        match Map::load(&wad, "MAP01", false) {
+            Err(MapError::MissingLump {
+                lump: "SIDEDEFS", ..
+            }) => {}
+            other => panic!("{:?}", other.map(|m| m.name)),
+        }
+        assert!(matches!(
+            Map::load(&wad, "MAP02", false),
+            Err(MapError::NoSuchMap(_))
+        ));
+    }
+
+    #[test]
+    fn short_reject_is_padded() {
+        let mut lumps = MapLumps::room(256);
+        lumps.sector(0, 128).sector(0, 128).sidedef(2);
+        let map = lumps.load(&[("REJECT", vec![0xff])]).unwrap();
+
+        assert_eq!(map.reject, [0xff, 0]);
+        assert!(map.is_rejected(0, 2));
+        assert!(!map.is_rejected(2, 2));
+        assert_eq!(
+            map.warnings.last().unwrap(),
+            "REJECT is 1 bytes, expected 2"
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use crate::fixed::Fixed;
+
+impl super::Map {
+    // Uses the BLOCKMAP lump when it is sound and covers the whole map, and
+    // builds a new blockmap otherwise.
+    pub fn load_blockmap(&mut self, data: &[u8]) {
+        let why = if data.is_empty() {
+            String::from("map has no BLOCKMAP")
+        } else {
+            match super::lumps::decode_blockmap(data, self.linedefs.len()) {
+                Ok(blockmap) => match self.check_blockmap_coverage(&blockmap) {
+                    Ok(()) => {
+                        self.blockmap = blockmap;
+                        return;
+                    }
+                    Err(why) => format!("BLOCKMAP {}", why),
+                },
+                Err(why) => format!("BLOCKMAP {}", why),
+            }
+        };
+
+        self.build_blockmap();
+        self.warnings.push(format!("{}, rebuilt it", why));
+    }
+
+    // Blockmaps made for maps past the vanilla limits can be too small for
+    // the map, with linedefs outside of every block.
+    fn check_blockmap_coverage(&self, blockmap: &super::Blockmap) -> Result<(), String> {
+        let (min_x, min_y, max_x, max_y) = match self.line_bounds() {
+            Some(bounds) => bounds,
+            None => return Ok(()),
+        };
+        let left = blockmap.origin_x.to_int() as i64;
+        let bottom = blockmap.origin_y.to_int() as i64;
+        let right = left + blockmap.columns as i64 * super::MAPBLOCKUNITS;
+        let top = bottom + blockmap.rows as i64 * super::MAPBLOCKUNITS;
+
+        if min_x < left || min_y < bottom || max_x >= right || max_y >= top {
+            return Err(format!(
+                "covers ({}, {})-({}, {}), but the map spans ({}, {})-({}, {})",
+                left, bottom, right, top, min_x, min_y, max_x, max_y
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn line_bounds(&self) -> Option<(i64, i64, i64, i64)> {
+        let mut points = self
+            .linedefs
+            .iter()
+            .flat_map(|line| [line.v1, line.v2])
+            .map(|v| self.point(v));
+        let (x, y) = points.next()?;
+
+        Some(
+            points.fold((x, y, x, y), |(min_x, min_y, max_x, max_y), (x, y)| {
+                (min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y))
+            }),
+        )
+    }
+
+    fn point(&self, v: usize) -> (i64, i64) {
+        let v = &self.vertexes[v];
+        (v.x.to_int() as i64, v.y.to_int() as i64)
+    }
+
+    // Puts every linedef in each block it passes through or touches, in
+    // linedef order, after the 0 that vanilla's builders start each list
+    // with. Blocks start at the bottom left of the map.
+    pub fn build_blockmap(&mut self) {
+        let (min_x, min_y, max_x, max_y) = self.line_bounds().unwrap_or((0, 0, 0, 0));
+        let columns = ((max_x - min_x) / super::MAPBLOCKUNITS + 1) as usize;
+        let rows = ((max_y - min_y) / super::MAPBLOCKUNITS + 1) as usize;
+        let leading = if self.linedefs.is_empty() {
+            vec![]
+        } else {
+            vec![0]
+        };
+        let mut lines = vec![leading; columns * rows];
+
+        for (i, line) in self.linedefs.iter().enumerate() {
+            let (x1, y1) = self.point(line.v1);
+            let (x2, y2) = self.point(line.v2);
+            let block_x = |x: i64| ((x - min_x) / super::MAPBLOCKUNITS) as usize;
+            let block_y = |y: i64| ((y - min_y) / super::MAPBLOCKUNITS) as usize;
+
+            for row in block_y(y1.min(y2))..=block_y(y1.max(y2)) {
+                for column in block_x(x1.min(x2))..=block_x(x1.max(x2)) {
+                    let left = min_x + column as i64 * super::MAPBLOCKUNITS;
+                    let bottom = min_y + row as i64 * super::MAPBLOCKUNITS;
+                    if line_touches_block((x1, y1), (x2, y2), left, bottom) {
+                        lines[row * columns + column].push(i);
+                    }
+                }
+            }
+        }
+
+        self.blockmap = super::Blockmap {
+            origin_x: Fixed::from_int(min_x as i32),
+            origin_y: Fixed::from_int(min_y as i32),
+            columns,
+            rows,
+            lines,
+        };
+    }
+}
+
+// The line's bounding box already overlaps the block, so it touches the
+// block unless all four corners are on the same side of it.
+fn line_touches_block((x1, y1): (i64, i64), (x2, y2): (i64, i64), left: i64, bottom: i64) -> bool {
+    let right = left + super::MAPBLOCKUNITS;
+    let top = bottom + super::MAPBLOCKUNITS;
+    let side = |x: i64, y: i64| ((x2 - x1) * (y - y1) - (y2 - y1) * (x - x1)).signum();
+
+    let corners = [
+        side(left, bottom),
+        side(left, top),
+        side(right, bottom),
+        side(right, top),
+    ];
+    !(corners.iter().all(|&s| s > 0) || corners.iter().all(|&s| s < 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::lumps::decode_blockmap;
+    use super::super::testing::{write_wad, MapLumps};
+    use super::super::{Blockmap, Map};
+    use super::*;
+
+    // A BLOCKMAP lump with the lists as given, so with the leading 0 only
+    // when the list has one.
+    fn lump(origin: (i16, i16), columns: u16, rows: u16, lists: &[Vec<u16>]) -> Vec<u8> {
+        let mut words = vec![origin.0 as u16, origin.1 as u16, columns, rows];
+        let mut offset = 4 + lists.len();
+        for list in lists {
+            words.push(offset as u16);
+            offset += list.len() + 1;
+        }
+        for list in lists {
+            words.extend_from_slice(list);
+            words.push(0xffff);
+        }
+        words
+            .iter()
+            .flat_map(|w| w.to_le_bytes().to_vec())
+            .collect()
+    }
+
+    #[test]
+    fn lines_touch_the_blocks_they_cross() {
+        assert!(line_touches_block((0, 200), (200, 0), 0, 0));
+        // Through the top right corner only.
+        assert!(line_touches_block((0, 256), (256, 0), 0, 0));
+        // Its box overlaps the block, but it passes beyond the corner.
+        assert!(!line_touches_block((0, 260), (260, 0), 0, 0));
+        // Along an edge.
+        assert!(line_touches_block((0, 128), (500, 128), 0, 0));
+    }
+
+    #[test]
+    fn builds_a_blockmap_of_the_room() {
+        let mut map = MapLumps::room(256).load(&[]).unwrap();
+        map.build_blockmap();
+
+        let blockmap = &map.blockmap;
+        assert_eq!((blockmap.origin_x, blockmap.origin_y), (Fixed(0), Fixed(0)));
+        assert_eq!((blockmap.columns, blockmap.rows), (3, 3));
+        // Linedefs 0 to 3 are the bottom, right, top and left walls, after
+        // the 0 every list starts with.
+        let expected: [&[usize]; 9] = [
+            &[0, 0, 3],
+            &[0, 0],
+            &[0, 0, 1],
+            &[0, 3],
+            &[0],
+            &[0, 1],
+            &[0, 2, 3],
+            &[0, 2],
+            &[0, 1, 2],
+        ];
+        assert_eq!(blockmap.lines, expected);
+    }
+
+    fn rebuilt(map: &Map) -> bool {
+        map.warnings.iter().any(|w| w.ends_with("rebuilt it"))
+    }
+
+    #[test]
+    fn keeps_a_sound_blockmap_unless_told_to_rebuild() {
+        let mut built = MapLumps::room(256).load(&[]).unwrap();
+        built.build_blockmap();
+        let lists: Vec<Vec<u16>> = built
+            .blockmap
+            .lines
+            .iter()
+            .map(|list| list.iter().map(|&line| line as u16).collect())
+            .collect();
+
+        let mut lumps = MapLumps::room(256).lumps();
+        lumps.push(("BLOCKMAP", lump((0, 0), 3, 3, &lists)));
+        let map = Map::load(&write_wad("MAP01", &lumps), "MAP01", false).unwrap();
+        assert_eq!(map.blockmap, built.blockmap);
+        assert!(!rebuilt(&map));
+
+        // This one doesn't reach the top of the room.
+        let mut lumps = MapLumps::room(256).lumps();
+        lumps.push(("BLOCKMAP", lump((0, 0), 3, 1, &lists[..3])));
+        let wad = write_wad("MAP01", &lumps);
+
+        let map = Map::load(&wad, "MAP01", false).unwrap();
+        assert_eq!(map.blockmap, built.blockmap);
+        assert!(rebuilt(&map));
+
+        // Asked for, the blockmap is built once and without a warning.
+        let map = Map::load(&wad, "MAP01", true).unwrap();
+        assert_eq!(map.blockmap, built.blockmap);
+        assert!(!rebuilt(&map));
+    }
+
+    #[test]
+    fn lists_may_run_past_16_bit_offsets() {
+        let data = lump((0, 0), 1, 1, &[vec![0; 70_000]]);
+        let blockmap: Blockmap = decode_blockmap(&data, 1).unwrap();
+        assert_eq!(blockmap.lines[0].len(), 70_000);
+    }
+
+    #[test]
+    fn wrapped_offsets_are_rejected() {
+        // The second list is at word 70008, which lump() wraps to 4472 as
+        // those builders do. That is inside the first list, so it still
+        // decodes, but nothing reaches the real second list.
+        let data = lump((0, 0), 2, 1, &[vec![0; 70_001], vec![0]]);
+        assert_eq!(u16::from_le_bytes([data[10], data[11]]), 4472);
+        assert!(decode_blockmap(&data, 1).is_err());
+    }
+
+    #[test]
+    fn keeps_the_leading_zero() {
+        let data = lump((0, 0), 2, 1, &[vec![0, 1], vec![1]]);
+        let blockmap = decode_blockmap(&data, 2).unwrap();
+        assert_eq!(blockmap.lines, [vec![0, 1], vec![1]]);
+    }
+
+    #[test]
+    fn offsets_must_fit_in_the_lump() {
+        // The header claims 100 by 100 blocks, but there are only two.
+        let mut data = lump((0, 0), 2, 1, &[vec![0], vec![0]]);
+        data[4..8].copy_from_slice(&[100, 0, 100, 0]);
+        let why = decode_blockmap(&data, 1).unwrap_err();
+        assert!(why.contains("100 by 100"), "{}", why);
+    }
+}
@@ -0,0 +1,96 @@
+#[derive(Debug)]
+pub enum MapError {
+    Wad(crate::wad::error::WadError),
+    NoSuchMap(String),
+    MissingLump {
+        map: String,
+        lump: &'static str,
+    },
+    BadLumpSize {
+        map: String,
+        lump: &'static str,
+        size: usize,
+        record_size: usize,
+    },
+    BadReference {
+        map: String,
+        lump: &'static str,
+        index: usize,
+        field: &'static str,
+        value: usize,
+        limit: usize,
+    },
+    BadRecord {
+        map: String,
+        lump: &'static str,
+        index: usize,
+        why: String,
+    },
+    BadNodes {
+        map: String,
+        lump: &'static str,
+        why: String,
+    },
+    Udmf {
+        map: String,
+        line: usize,
+        why: String,
+    },
+}
+
+impl std::error::Error for MapError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Wad(source) => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl From<crate::wad::error::WadError> for MapError {
+    fn from(e: crate::wad::error::WadError) -> Self {
+        Self::Wad(e)
+    }
+}
+
+impl std::fmt::Display for MapError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Wad(e) => write!(f, "{}", e),
+            Self::NoSuchMap(map) => write!(f, "Map {} not found", map),
+            Self::MissingLump { map, lump } => write!(f, "Map {} has no {} lump", map, lump),
+            Self::BadLumpSize {
+                map,
+                lump,
+                size,
+                record_size,
+            } => write!(
+                f,
+                "Map {}: {} is {} bytes, not a multiple of {}",
+                map, lump, size, record_size
+            ),
+            Self::BadReference {
+                map,
+                lump,
+                index,
+                field,
+                value,
+                limit,
+            } => write!(
+                f,
+                "Map {}: {} {} refers to {} {}, but there are only {}",
+                map, lump, index, field, value, limit
+            ),
+            Self::BadRecord {
+                map,
+                lump,
+                index,
+                why,
+            } => write!(f, "Map {}: {} {} {}", map, lump, index, why),
+            Self::BadNodes { map, lump, why } => write!(f, "Map {}: {} {}", map, lump, why),
+            Self::Udmf { map, line, why } => {
+                write!(f, "Map {}: TEXTMAP line {}: {}", map, line, why)
+            }
+        }
+    }
+}
@@ -0,0 +1,494 @@
+use std::io::Read;
+
+use super::error::MapError;
+use super::lumps::{read_fixed, read_u16, records};
+
+pub const DEEPBSP_SIGNATURE: &[u8] = b"xNd4\0\0\0\0";
+pub const DEEPBSP_SEG_SIZE: usize = 16;
+pub const DEEPBSP_SUBSECTOR_SIZE: usize = 8;
+pub const DEEPBSP_NODE_SIZE: usize = 32;
+
+pub const NF_SUBSECTOR_32: u32 = 0x8000_0000;
+
+// ZDoom's GL node formats keep minisegs, which belong to no linedef and
+// have no place in the vanilla structures.
+const ZDOOM_GL_SIGNATURES: [&[u8]; 6] = [b"XGLN", b"ZGLN", b"XGL2", b"ZGL2", b"XGL3", b"ZGL3"];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NodeFormat {
+    #[default]
+    Vanilla,
+    DeePBsp,
+    ZDoom,
+    ZDoomCompressed,
+    Built,
+}
+
+impl std::fmt::Display for NodeFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Vanilla => "vanilla",
+            Self::DeePBsp => "DeePBSP (xNd4)",
+            Self::ZDoom => "ZDoom (XNOD)",
+            Self::ZDoomCompressed => "ZDoom compressed (ZNOD)",
+            Self::Built => "built on load",
+        })
+    }
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    position: usize,
+}
+
+impl Reader<'_> {
+    fn bytes<const N: usize>(&mut self) -> Result<[u8; N], String> {
+        let bytes = self
+            .data
+            .get(self.position..self.position + N)
+            .ok_or_else(|| format!("is truncated at byte {}", self.position))?;
+        self.position += N;
+        let mut out = [0; N];
+        out.copy_from_slice(bytes);
+        Ok(out)
+    }
+
+    fn u8(&mut self) -> Result<u8, String> {
+        self.bytes::<1>().map(|b| b[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, String> {
+        self.bytes().map(u16::from_le_bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, String> {
+        self.bytes().map(u32::from_le_bytes)
+    }
+
+    fn i16(&mut self) -> Result<i16, String> {
+        self.bytes().map(i16::from_le_bytes)
+    }
+
+    fn i32(&mut self) -> Result<i32, String> {
+        self.bytes().map(i32::from_le_bytes)
+    }
+
+    fn count(&mut self) -> Result<usize, String> {
+        self.u32().map(|n| n as usize)
+    }
+}
+
+fn gl_nodes(data: &[u8]) -> Option<String> {
+    ZDOOM_GL_SIGNATURES
+        .iter()
+        .find(|s| data.starts_with(s))
+        .map(|s| {
+            format!(
+                "has ZDoom GL nodes ({}), which are not supported",
+                String::from_utf8_lossy(s)
+            )
+        })
+}
+
+fn child_32(child: u32) -> super::NodeChild {
+    if child & NF_SUBSECTOR_32 != 0 {
+        super::NodeChild::SubSector((child & !NF_SUBSECTOR_32) as usize)
+    } else {
+        super::NodeChild::Node(child as usize)
+    }
+}
+
+impl super::Map {
+    // SEGS, SSECTORS and NODES come in several formats; the vanilla one has
+    // no signature, the others say what they are at the start of a lump.
+    pub fn decode_bsp(
+        &mut self,
+        segs: &[u8],
+        subsectors: &[u8],
+        nodes: &[u8],
+    ) -> Result<(), MapError> {
+        let map = self.name.clone();
+        let bad_nodes = |lump, why| MapError::BadNodes {
+            map: map.clone(),
+            lump,
+            why,
+        };
+
+        for (lump, data) in [("SSECTORS", subsectors), ("NODES", nodes)] {
+            if let Some(why) = gl_nodes(data) {
+                return Err(bad_nodes(lump, why));
+            }
+        }
+
+        if nodes.starts_with(DEEPBSP_SIGNATURE) {
+            self.node_format = NodeFormat::DeePBsp;
+            self.segs = decode_deepbsp_segs(&self.name, segs)?;
+            self.subsectors = decode_deepbsp_subsectors(&self.name, subsectors)?;
+            self.nodes = decode_deepbsp_nodes(&self.name, &nodes[DEEPBSP_SIGNATURE.len()..])?;
+        } else if nodes.starts_with(b"XNOD") || nodes.starts_with(b"ZNOD") {
+            self.decode_zdoom(nodes)
+                .map_err(|why| bad_nodes("NODES", why))?;
+        } else {
+            self.node_format = NodeFormat::Vanilla;
+            self.segs = super::lumps::decode_segs(&self.name, segs)?;
+            self.subsectors = super::lumps::decode_subsectors(&self.name, subsectors)?;
+            self.nodes = super::lumps::decode_nodes(&self.name, nodes)?;
+        }
+
+        Ok(())
+    }
+
+    // The XNOD and ZNOD formats, found in NODES in binary maps and in ZNODES
+    // in UDMF ones.
+    pub fn decode_zdoom(&mut self, data: &[u8]) -> Result<(), String> {
+        if let Some(why) = gl_nodes(data) {
+            return Err(why);
+        }
+
+        if data.starts_with(b"XNOD") {
+            self.node_format = NodeFormat::ZDoom;
+            self.decode_zdoom_nodes(&data[4..])
+                .map_err(|why| format!("XNOD data {}", why))
+        } else if data.starts_with(b"ZNOD") {
+            self.node_format = NodeFormat::ZDoomCompressed;
+            let mut inflated = vec![];
+            flate2::read::ZlibDecoder::new(&data[4..])
+                .read_to_end(&mut inflated)
+                .map_err(|e| format!("ZNOD data cannot be inflated: {}", e))?;
+            self.decode_zdoom_nodes(&inflated)
+                .map_err(|why| format!("ZNOD data {}", why))
+        } else {
+            Err(String::from("has no XNOD or ZNOD signature"))
+        }
+    }
+
+    // ZDoom nodes carry their own vertexes, segs and subsectors, and leave the
+    // SEGS and SSECTORS lumps empty.
+    fn decode_zdoom_nodes(&mut self, data: &[u8]) -> Result<(), String> {
+        let mut r = Reader { data, position: 0 };
+
+        let original_vertexes = r.count()?;
+        if original_vertexes > self.vertexes.len() {
+            return Err(format!(
+                "keeps {} vertexes, but VERTEXES only has {}",
+                original_vertexes,
+                self.vertexes.len()
+            ));
+        }
+        self.vertexes.truncate(original_vertexes);
+        for _ in 0..r.count()? {
+            let x = crate::fixed::Fixed(r.i32()?);
+            let y = crate::fixed::Fixed(r.i32()?);
+            self.vertexes.push(super::Vertex { x, y });
+        }
+
+        let mut first_seg = 0;
+        self.subsectors.clear();
+        for _ in 0..r.count()? {
+            let num_segs = r.count()?;
+            self.subsectors.push(super::SubSector {
+                num_segs,
+                first_seg,
+            });
+            first_seg += num_segs;
+        }
+
+        let num_segs = r.count()?;
+        if num_segs != first_seg {
+            return Err(format!(
+                "has {} segs, but its subsectors use {}",
+                num_segs, first_seg
+            ));
+        }
+        self.segs.clear();
+        for i in 0..num_segs {
+            let v1 = r.count()?;
+            let v2 = r.count()?;
+            let linedef = r.u16()? as usize;
+            let side = r.u8()? as usize;
+            let (angle, offset) = self
+                .seg_geometry(v1, linedef, side)
+                .ok_or_else(|| format!("seg {} has a bad vertex or linedef", i))?;
+            self.segs.push(super::Seg {
+                v1,
+                v2,
+                angle,
+                linedef,
+                side,
+                offset,
+            });
+        }
+
+        self.nodes.clear();
+        for _ in 0..r.count()? {
+            let mut coords = [crate::fixed::Fixed::default(); 12];
+            for coord in coords.iter_mut() {
+                *coord = crate::fixed::Fixed::from_int(r.i16()? as i32);
+            }
+            let children = [child_32(r.u32()?), child_32(r.u32()?)];
+            self.nodes.push(super::Node {
+                x: coords[0],
+                y: coords[1],
+                dx: coords[2],
+                dy: coords[3],
+                bbox: [
+                    [coords[4], coords[5], coords[6], coords[7]],
+                    [coords[8], coords[9], coords[10], coords[11]],
+                ],
+                children,
+            });
+        }
+
+        Ok(())
+    }
+
+    // ZDoom segs don't store an angle or offset, so work them out from the
+    // linedef as ZDoom does.
+    fn seg_geometry(
+        &self,
+        v1: usize,
+        linedef: usize,
+        side: usize,
+    ) -> Option<(crate::angle::Angle, crate::fixed::Fixed)> {
+        let line = self.linedefs.get(linedef)?;
+        let start = self.vertexes.get(line.v1)?;
+        let end = self.vertexes.get(line.v2)?;
+        let seg_start = self.vertexes.get(v1)?;
+
+        let angle =
+            super::nodes::line_angle((end.x - start.x).0 as f64, (end.y - start.y).0 as f64, side);
+        let from = if side == 0 { start } else { end };
+        let offset = ((seg_start.x - from.x).0 as f64).hypot((seg_start.y - from.y).0 as f64);
+
+        Some((angle, crate::fixed::Fixed(offset.round() as i32)))
+    }
+}
+
+fn decode_deepbsp_segs(map: &str, data: &[u8]) -> Result<Vec<super::Seg>, MapError> {
+    Ok(records(map, "SEGS", data, DEEPBSP_SEG_SIZE)?
+        .map(|r| super::Seg {
+            v1: u32::from_le_bytes([r[0], r[1], r[2], r[3]]) as usize,
+            v2: u32::from_le_bytes([r[4], r[5], r[6], r[7]]) as usize,
+            angle: crate::angle::Angle((read_u16(&r[8..]) as u32) << 16),
+            linedef: read_u16(&r[10..]) as usize,
+            side: read_u16(&r[12..]) as usize,
+            offset: crate::fixed::Fixed::from_int(read_u16(&r[14..]) as i32),
+        })
+        .collect())
+}
+
+fn decode_deepbsp_subsectors(map: &str, data: &[u8]) -> Result<Vec<super::SubSector>, MapError> {
+    Ok(records(map, "SSECTORS", data, DEEPBSP_SUBSECTOR_SIZE)?
+        .map(|r| super::SubSector {
+            num_segs: u32::from_le_bytes([r[0], r[1], r[2], r[3]]) as usize,
+            first_seg: u32::from_le_bytes([r[4], r[5], r[6], r[7]]) as usize,
+        })
+        .collect())
+}
+
+fn decode_deepbsp_nodes(map: &str, data: &[u8]) -> Result<Vec<super::Node>, MapError> {
+    let bbox = |bytes: &[u8]| {
+        let mut bbox = [crate::fixed::Fixed::default(); 4];
+        for (i, coord) in bbox.iter_mut().enumerate() {
+            *coord = read_fixed(&bytes[i * 2..]);
+        }
+        bbox
+    };
+    let child =
+        |bytes: &[u8]| child_32(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]));
+
+    Ok(records(map, "NODES", data, DEEPBSP_NODE_SIZE)?
+        .map(|r| super::Node {
+            x: read_fixed(&r[0..]),
+            y: read_fixed(&r[2..]),
+            dx: read_fixed(&r[4..]),
+            dy: read_fixed(&r[6..]),
+            bbox: [bbox(&r[8..]), bbox(&r[16..])],
+            children: [child(&r[24..]), child(&r[28..])],
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::error::MapError;
+    use super::super::testing::{write_wad, MapLumps};
+    use super::super::{lumps, Map, NodeChild};
+    use super::*;
+
+    // A room with pillars, through the node builder, so there are split
+    // vertexes for the formats to carry.
+    fn pillars() -> MapLumps {
+        let mut lumps = MapLumps::room(1024);
+        lumps.pillar(100, 100, 64).pillar(600, 300, 128);
+        lumps
+    }
+
+    fn child(child: NodeChild) -> u32 {
+        match child {
+            NodeChild::Node(node) => node as u32,
+            NodeChild::SubSector(subsector) => subsector as u32 | NF_SUBSECTOR_32,
+        }
+    }
+
+    fn push_node(out: &mut Vec<u8>, node: &super::super::Node) {
+        let coords = [node.x, node.y, node.dx, node.dy];
+        for c in coords.iter().chain(node.bbox.iter().flatten()) {
+            out.extend_from_slice(&(c.to_int() as i16).to_le_bytes());
+        }
+        for &c in &node.children {
+            out.extend_from_slice(&child(c).to_le_bytes());
+        }
+    }
+
+    fn deepbsp(map: &Map) -> Vec<(&'static str, Vec<u8>)> {
+        let mut segs = vec![];
+        for seg in &map.segs {
+            segs.extend_from_slice(&(seg.v1 as u32).to_le_bytes());
+            segs.extend_from_slice(&(seg.v2 as u32).to_le_bytes());
+            segs.extend_from_slice(&((seg.angle.0 >> 16) as u16).to_le_bytes());
+            segs.extend_from_slice(&(seg.linedef as u16).to_le_bytes());
+            segs.extend_from_slice(&(seg.side as u16).to_le_bytes());
+            segs.extend_from_slice(&(seg.offset.to_int() as u16).to_le_bytes());
+        }
+
+        let mut subsectors = vec![];
+        for subsector in &map.subsectors {
+            subsectors.extend_from_slice(&(subsector.num_segs as u32).to_le_bytes());
+            subsectors.extend_from_slice(&(subsector.first_seg as u32).to_le_bytes());
+        }
+
+        let mut nodes = DEEPBSP_SIGNATURE.to_vec();
+        for node in &map.nodes {
+            push_node(&mut nodes, node);
+        }
+
+        vec![
+            ("VERTEXES", lumps::encode_vertexes(&map.vertexes)),
+            ("SEGS", segs),
+            ("SSECTORS", subsectors),
+            ("NODES", nodes),
+        ]
+    }
+
+    // The XNOD data after the signature, with the first num_original
+    // vertexes left to VERTEXES.
+    fn zdoom(map: &Map, num_original: usize) -> Vec<u8> {
+        let mut out = vec![];
+        let count = |out: &mut Vec<u8>, n: usize| out.extend_from_slice(&(n as u32).to_le_bytes());
+
+        count(&mut out, num_original);
+        count(&mut out, map.vertexes.len() - num_original);
+        for v in &map.vertexes[num_original..] {
+            out.extend_from_slice(&v.x.0.to_le_bytes());
+            out.extend_from_slice(&v.y.0.to_le_bytes());
+        }
+
+        count(&mut out, map.subsectors.len());
+        for subsector in &map.subsectors {
+            count(&mut out, subsector.num_segs);
+        }
+
+        count(&mut out, map.segs.len());
+        for seg in &map.segs {
+            count(&mut out, seg.v1);
+            count(&mut out, seg.v2);
+            out.extend_from_slice(&(seg.linedef as u16).to_le_bytes());
+            out.push(seg.side as u8);
+        }
+
+        count(&mut out, map.nodes.len());
+        for node in &map.nodes {
+            push_node(&mut out, node);
+        }
+        out
+    }
+
+    fn znod(map: &Map, num_original: usize) -> Vec<u8> {
+        use std::io::Write;
+
+        let mut encoder =
+            flate2::write::ZlibEncoder::new(b"ZNOD".to_vec(), flate2::Compression::default());
+        encoder.write_all(&zdoom(map, num_original)).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    fn load(extra: Vec<(&'static str, Vec<u8>)>) -> Result<Map, MapError> {
+        let mut lumps = pillars().lumps();
+        for (name, data) in extra {
+            match lumps.iter_mut().find(|(lump, _)| *lump == name) {
+                Some(lump) => lump.1 = data,
+                None => lumps.push((name, data)),
+            }
+        }
+        Map::load(&write_wad("MAP01", &lumps), "MAP01", false)
+    }
+
+    fn assert_same_bsp(map: &Map, built: &Map) {
+        assert_eq!(map.vertexes, built.vertexes);
+        assert_eq!(map.segs, built.segs);
+        assert_eq!(map.subsectors, built.subsectors);
+        assert_eq!(map.nodes, built.nodes);
+    }
+
+    #[test]
+    fn reads_deepbsp_nodes() {
+        let built = pillars().load(&[]).unwrap();
+        assert!(built.vertexes.len() > 12);
+
+        let map = load(deepbsp(&built)).unwrap();
+        assert_eq!(map.node_format, NodeFormat::DeePBsp);
+        assert_same_bsp(&map, &built);
+    }
+
+    #[test]
+    fn reads_zdoom_nodes() {
+        let built = pillars().load(&[]).unwrap();
+        let empty = || vec![("SEGS", vec![]), ("SSECTORS", vec![])];
+
+        let mut xnod = b"XNOD".to_vec();
+        xnod.extend_from_slice(&zdoom(&built, 12));
+        let mut lumps = empty();
+        lumps.push(("NODES", xnod));
+        let map = load(lumps).unwrap();
+        assert_eq!(map.node_format, NodeFormat::ZDoom);
+        assert_same_bsp(&map, &built);
+
+        let mut lumps = empty();
+        lumps.push(("NODES", znod(&built, 12)));
+        let map = load(lumps).unwrap();
+        assert_eq!(map.node_format, NodeFormat::ZDoomCompressed);
+        assert_same_bsp(&map, &built);
+    }
+
+    #[test]
+    fn truncated_nodes_are_errors() {
+        let built = pillars().load(&[]).unwrap();
+
+        let mut lumps = deepbsp(&built);
+        lumps[3].1.pop();
+        match load(lumps) {
+            Err(MapError::BadLumpSize { lump: "NODES", .. }) => {}
+            other => panic!("{:?}", other.map(|_| ())),
+        }
+
+        let mut data = built.clone();
+        let mut xnod = b"XNOD".to_vec();
+        xnod.extend_from_slice(&zdoom(&built, 12));
+        xnod.truncate(xnod.len() - 3);
+        let why = data.decode_zdoom(&xnod).unwrap_err();
+        assert!(why.starts_with("XNOD data is truncated"), "{}", why);
+
+        // Cut short before it is inflated.
+        let mut zipped = znod(&built, 12);
+        zipped.truncate(zipped.len() / 2);
+        let why = data.decode_zdoom(&zipped).unwrap_err();
+        assert!(why.starts_with("ZNOD data"), "{}", why);
+
+        // Too many vertexes kept from VERTEXES.
+        let mut xnod = b"XNOD".to_vec();
+        xnod.extend_from_slice(&zdoom(&built, 12));
+        xnod[4..8].copy_from_slice(&1000u32.to_le_bytes());
+        assert!(data.decode_zdoom(&xnod).is_err());
+    }
+}
@@ -0,0 +1,324 @@
+use super::error::MapError;
+use crate::fixed::Fixed;
+use crate::wad::format::read_i16;
+
+pub const THING_SIZE: usize = 10;
+pub const LINEDEF_SIZE: usize = 14;
+pub const SIDEDEF_SIZE: usize = 30;
+pub const VERTEX_SIZE: usize = 4;
+pub const SEG_SIZE: usize = 12;
+pub const SUBSECTOR_SIZE: usize = 4;
+pub const NODE_SIZE: usize = 28;
+pub const SECTOR_SIZE: usize = 26;
+
+pub fn records<'a>(
+    map: &str,
+    lump: &'static str,
+    data: &'a [u8],
+    record_size: usize,
+) -> Result<std::slice::ChunksExact<'a, u8>, MapError> {
+    let records = data.chunks_exact(record_size);
+    if !records.remainder().is_empty() {
+        return Err(MapError::BadLumpSize {
+            map: map.to_owned(),
+            lump,
+            size: data.len(),
+            record_size,
+        });
+    }
+    Ok(records)
+}
+
+pub fn read_u16(bytes: &[u8]) -> u16 {
+    u16::from_le_bytes([bytes[0], bytes[1]])
+}
+
+pub fn read_fixed(bytes: &[u8]) -> Fixed {
+    Fixed::from_int(read_i16(bytes) as i32)
+}
+
+// Indices are read unsigned, as limit-removing ports do, which only ever
+// accepts more maps than vanilla does.
+fn read_index(bytes: &[u8]) -> usize {
+    read_u16(bytes) as usize
+}
+
+fn read_optional_index(bytes: &[u8]) -> Option<usize> {
+    match read_u16(bytes) {
+        super::NO_INDEX => None,
+        index => Some(index as usize),
+    }
+}
+
+fn read_name(bytes: &[u8]) -> String {
+    crate::wad::name_to_string(&bytes[..crate::wad::LUMP_NAME_LENGTH])
+}
+
+pub fn decode_things(map: &str, data: &[u8]) -> Result<Vec<super::Thing>, MapError> {
+    Ok(records(map, "THINGS", data, THING_SIZE)?
+        .map(|r| super::Thing {
+            x: read_fixed(&r[0..]),
+            y: read_fixed(&r[2..]),
+            angle: read_i16(&r[4..]),
+            kind: read_i16(&r[6..]),
+            options: read_i16(&r[8..]),
+        })
+        .collect())
+}
+
+pub fn decode_linedefs(map: &str, data: &[u8]) -> Result<Vec<super::LineDef>, MapError> {
+    Ok(records(map, "LINEDEFS", data, LINEDEF_SIZE)?
+        .map(|r| super::LineDef {
+            v1: read_index(&r[0..]),
+            v2: read_index(&r[2..]),
+            flags: read_i16(&r[4..]),
+            special: read_i16(&r[6..]),
+            tag: read_i16(&r[8..]),
+            sidenum: [read_optional_index(&r[10..]), read_optional_index(&r[12..])],
+            hexen_special: None,
+        })
+        .collect())
+}
+
+pub fn decode_sidedefs(map: &str, data: &[u8]) -> Result<Vec<super::SideDef>, MapError> {
+    Ok(records(map, "SIDEDEFS", data, SIDEDEF_SIZE)?
+        .map(|r| super::SideDef {
+            texture_offset: read_fixed(&r[0..]),
+            row_offset: read_fixed(&r[2..]),
+            top_texture: read_name(&r[4..]),
+            bottom_texture: read_name(&r[12..]),
+            mid_texture: read_name(&r[20..]),
+            sector: read_index(&r[28..]),
+        })
+        .collect())
+}
+
+pub fn decode_vertexes(map: &str, data: &[u8]) -> Result<Vec<super::Vertex>, MapError> {
+    Ok(records(map, "VERTEXES", data, VERTEX_SIZE)?
+        .map(|r| super::Vertex {
+            x: read_fixed(&r[0..]),
+            y: read_fixed(&r[2..]),
+        })
+        .collect())
+}
+
+pub fn decode_segs(map: &str, data: &[u8]) -> Result<Vec<super::Seg>, MapError> {
+    Ok(records(map, "SEGS", data, SEG_SIZE)?
+        .map(|r| super::Seg {
+            v1: read_index(&r[0..]),
+            v2: read_index(&r[2..]),
+            angle: crate::angle::Angle((read_u16(&r[4..]) as u32) << 16),
+            linedef: read_index(&r[6..]),
+            side: read_index(&r[8..]),
+            offset: read_fixed(&r[10..]),
+        })
+        .collect())
+}
+
+pub fn decode_subsectors(map: &str, data: &[u8]) -> Result<Vec<super::SubSector>, MapError> {
+    Ok(records(map, "SSECTORS", data, SUBSECTOR_SIZE)?
+        .map(|r| super::SubSector {
+            num_segs: read_index(&r[0..]),
+            first_seg: read_index(&r[2..]),
+        })
+        .collect())
+}
+
+pub fn decode_nodes(map: &str, data: &[u8]) -> Result<Vec<super::Node>, MapError> {
+    let child = |bytes: &[u8]| {
+        let child = read_u16(bytes);
+        if child & super::NF_SUBSECTOR != 0 {
+            super::NodeChild::SubSector((child & !super::NF_SUBSECTOR) as usize)
+        } else {
+            super::NodeChild::Node(child as usize)
+        }
+    };
+    let bbox = |bytes: &[u8]| {
+        let mut bbox = [Fixed::default(); 4];
+        for (i, coord) in bbox.iter_mut().enumerate() {
+            *coord = read_fixed(&bytes[i * 2..]);
+        }
+        bbox
+    };
+
+    Ok(records(map, "NODES", data, NODE_SIZE)?
+        .map(|r| super::Node {
+            x: read_fixed(&r[0..]),
+            y: read_fixed(&r[2..]),
+            dx: read_fixed(&r[4..]),
+            dy: read_fixed(&r[6..]),
+            bbox: [bbox(&r[8..]), bbox(&r[16..])],
+            children: [child(&r[24..]), child(&r[26..])],
+        })
+        .collect())
+}
+
+pub fn decode_sectors(map: &str, data: &[u8]) -> Result<Vec<super::Sector>, MapError> {
+    Ok(records(map, "SECTORS", data, SECTOR_SIZE)?
+        .map(|r| super::Sector {
+            floor_height: read_fixed(&r[0..]),
+            ceiling_height: read_fixed(&r[2..]),
+            floor_pic: read_name(&r[4..]),
+            ceiling_pic: read_name(&r[12..]),
+            light_level: read_i16(&r[20..]),
+            special: read_i16(&r[22..]),
+            tag: read_i16(&r[24..]),
+        })
+        .collect())
+}
+
+// A bad BLOCKMAP is not fatal, it can be rebuilt from the linedefs, so
+// this only says what is wrong with it.
+pub fn decode_blockmap(data: &[u8], num_lines: usize) -> Result<super::Blockmap, String> {
+    let word = |index: usize| {
+        data.get(index * 2..index * 2 + 2)
+            .map(read_u16)
+            .ok_or_else(|| format!("word {} is past the end of the lump", index))
+    };
+
+    if data.len() < 8 {
+        return Err(format!("is {} bytes, too short for a header", data.len()));
+    }
+    let columns = word(2)? as usize;
+    let rows = word(3)? as usize;
+    if columns == 0 || rows == 0 {
+        return Err(format!("has {} by {} blocks", columns, rows));
+    }
+    let header = 4 + columns * rows;
+    if data.len() / 2 < header {
+        return Err(format!(
+            "is {} bytes, too short for the offsets of {} by {} blocks",
+            data.len(),
+            columns,
+            rows
+        ));
+    }
+
+    let mut lines = Vec::with_capacity(columns * rows);
+    let mut furthest = header;
+    for block in 0..columns * rows {
+        let mut index = word(4 + block)? as usize;
+        let mut list = vec![];
+        if index < header {
+            return Err(format!("block {} list starts inside the header", block));
+        }
+
+        loop {
+            match word(index)? {
+                super::NO_INDEX => break,
+                line if (line as usize) < num_lines => list.push(line as usize),
+                line => return Err(format!("block {} refers to linedef {}", block, line)),
+            }
+            index += 1;
+        }
+        furthest = furthest.max(index);
+        lines.push(list);
+    }
+
+    // Offsets are 16 bits, and builders for big maps let them wrap around,
+    // which leaves the lists they should point to past the 64K word mark
+    // where nothing reaches them. A list that only starts below the mark
+    // and runs on past it is fine.
+    let words = data.len() / 2;
+    if words > super::NO_INDEX as usize + 1 && furthest + 1 < words {
+        return Err(format!(
+            "has lists past word {} that no block reaches, its offsets have wrapped",
+            furthest
+        ));
+    }
+
+    Ok(super::Blockmap {
+        origin_x: read_fixed(&data[0..]),
+        origin_y: read_fixed(&data[2..]),
+        columns,
+        rows,
+        lines,
+    })
+}
+
+// The vanilla lumps only have 16 bits for every index, and node children
+// lose one of those to NF_SUBSECTOR.
+fn write_index(out: &mut Vec<u8>, lump: &str, index: usize, limit: usize) -> Result<(), String> {
+    if index >= limit {
+        return Err(format!("too many entries for {} ({})", lump, index));
+    }
+    out.extend_from_slice(&(index as u16).to_le_bytes());
+    Ok(())
+}
+
+fn write_fixed(out: &mut Vec<u8>, value: Fixed) {
+    out.extend_from_slice(&(value.to_int() as i16).to_le_bytes());
+}
+
+pub fn encode_vertexes(vertexes: &[super::Vertex]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(vertexes.len() * VERTEX_SIZE);
+    for v in vertexes {
+        write_fixed(&mut out, v.x);
+        write_fixed(&mut out, v.y);
+    }
+    out
+}
+
+pub fn encode_segs(segs: &[super::Seg], num_vertexes: usize) -> Result<Vec<u8>, String> {
+    if num_vertexes > super::NO_INDEX as usize {
+        return Err(format!("too many entries for VERTEXES ({})", num_vertexes));
+    }
+
+    let mut out = Vec::with_capacity(segs.len() * SEG_SIZE);
+    for seg in segs {
+        write_index(&mut out, "VERTEXES", seg.v1, super::NO_INDEX as usize)?;
+        write_index(&mut out, "VERTEXES", seg.v2, super::NO_INDEX as usize)?;
+        out.extend_from_slice(&((seg.angle.0 >> 16) as u16).to_le_bytes());
+        write_index(&mut out, "LINEDEFS", seg.linedef, super::NO_INDEX as usize)?;
+        out.extend_from_slice(&(seg.side as u16).to_le_bytes());
+        write_fixed(&mut out, seg.offset);
+    }
+    Ok(out)
+}
+
+pub fn encode_subsectors(subsectors: &[super::SubSector]) -> Result<Vec<u8>, String> {
+    let mut out = Vec::with_capacity(subsectors.len() * SUBSECTOR_SIZE);
+    for subsector in subsectors {
+        write_index(
+            &mut out,
+            "SEGS",
+            subsector.num_segs,
+            super::NO_INDEX as usize,
+        )?;
+        write_index(
+            &mut out,
+            "SEGS",
+            subsector.first_seg,
+            super::NO_INDEX as usize,
+        )?;
+    }
+    Ok(out)
+}
+
+pub fn encode_nodes(nodes: &[super::Node]) -> Result<Vec<u8>, String> {
+    let limit = super::NF_SUBSECTOR as usize;
+
+    let mut out = Vec::with_capacity(nodes.len() * NODE_SIZE);
+    for node in nodes {
+        write_fixed(&mut out, node.x);
+        write_fixed(&mut out, node.y);
+        write_fixed(&mut out, node.dx);
+        write_fixed(&mut out, node.dy);
+        for bbox in node.bbox.iter() {
+            for &coord in bbox.iter() {
+                write_fixed(&mut out, coord);
+            }
+        }
+        for child in node.children.iter() {
+            match *child {
+                super::NodeChild::Node(n) => write_index(&mut out, "NODES", n, limit)?,
+                super::NodeChild::SubSector(s) => {
+                    write_index(&mut out, "SSECTORS", s, limit)?;
+                    let len = out.len();
+                    out[len - 1] |= (super::NF_SUBSECTOR >> 8) as u8;
+                }
+            }
+        }
+    }
+    Ok(out)
+}
@@ -0,0 +1,457 @@
+use crate::angle::Angle;
+use crate::fixed::{Fixed, FRACUNIT};
+
+// Splitting a seg costs this much more than an uneven split.
+const SPLIT_COST: i64 = 8;
+// Above this many segs only an evenly spread sample of them is tried as
+// partition lines, which keeps big maps from taking quadratic time.
+const MAX_CANDIDATES: usize = 256;
+
+#[derive(Clone, Copy)]
+struct BuildSeg {
+    v1: usize,
+    v2: usize,
+    linedef: usize,
+    side: usize,
+}
+
+enum Side {
+    Front,
+    Back,
+    // The point the partition crosses the seg at, with the part from v1 to
+    // there in front if the flag is set.
+    Split((i64, i64), bool),
+}
+
+// Coordinates are in fixed point units, so vertexes off the map grid keep
+// their fractions.
+struct Builder<'a> {
+    map: &'a super::Map,
+    vertexes: Vec<(i64, i64)>,
+    split_vertexes: std::collections::BTreeMap<(i64, i64), usize>,
+    segs: Vec<super::Seg>,
+    subsectors: Vec<super::SubSector>,
+    nodes: Vec<super::Node>,
+}
+
+// The BAM angle of a seg on the given side of a linedef running dx, dy.
+pub fn line_angle(dx: f64, dy: f64, side: usize) -> Angle {
+    let mut angle = (dy.atan2(dx) * 32768.0 / std::f64::consts::PI).round() as i32 as u16;
+    if side == 1 {
+        angle = angle.wrapping_add(0x8000);
+    }
+    Angle((angle as u32) << 16)
+}
+
+impl super::Map {
+    // Builds SEGS, SSECTORS and NODES from the linedefs. The result only
+    // depends on the map data, so rebuilding the same map always gives the
+    // same lumps.
+    pub fn build_nodes(&mut self) {
+        // Vertexes past the last one a linedef uses were made by whatever
+        // built the nodes before, and are made again here.
+        let num_vertexes = self
+            .linedefs
+            .iter()
+            .map(|line| line.v1.max(line.v2) + 1)
+            .max()
+            .unwrap_or(0);
+        self.vertexes.truncate(num_vertexes);
+
+        let mut builder = Builder {
+            map: self,
+            vertexes: self
+                .vertexes
+                .iter()
+                .map(|v| (v.x.0 as i64, v.y.0 as i64))
+                .collect(),
+            split_vertexes: std::collections::BTreeMap::new(),
+            segs: vec![],
+            subsectors: vec![],
+            nodes: vec![],
+        };
+
+        let mut segs = vec![];
+        for (i, line) in self.linedefs.iter().enumerate() {
+            if builder.vertexes[line.v1] == builder.vertexes[line.v2] {
+                continue;
+            }
+            if line.sidenum[0].is_some() {
+                segs.push(BuildSeg {
+                    v1: line.v1,
+                    v2: line.v2,
+                    linedef: i,
+                    side: 0,
+                });
+            }
+            if line.sidenum[1].is_some() {
+                segs.push(BuildSeg {
+                    v1: line.v2,
+                    v2: line.v1,
+                    linedef: i,
+                    side: 1,
+                });
+            }
+        }
+
+        if !segs.is_empty() {
+            builder.build(segs);
+        }
+
+        let Builder {
+            vertexes,
+            segs,
+            subsectors,
+            nodes,
+            ..
+        } = builder;
+        self.vertexes = vertexes
+            .into_iter()
+            .map(|(x, y)| super::Vertex {
+                x: Fixed(x as i32),
+                y: Fixed(y as i32),
+            })
+            .collect();
+        self.segs = segs;
+        self.subsectors = subsectors;
+        self.nodes = nodes;
+        self.node_format = super::extended::NodeFormat::Built;
+    }
+}
+
+impl Builder<'_> {
+    fn build(&mut self, segs: Vec<BuildSeg>) -> (super::NodeChild, [i64; 4]) {
+        let bbox = self.bbox(&segs);
+
+        let partition = match self.choose_partition(&segs) {
+            Some(partition) => partition,
+            None => {
+                self.subsectors.push(super::SubSector {
+                    num_segs: segs.len(),
+                    first_seg: self.segs.len(),
+                });
+                for seg in &segs {
+                    let seg = self.make_seg(seg);
+                    self.segs.push(seg);
+                }
+                return (super::NodeChild::SubSector(self.subsectors.len() - 1), bbox);
+            }
+        };
+
+        let (front, back) = self.split(&segs, &partition);
+        let (front_child, front_bbox) = self.build(front);
+        let (back_child, back_bbox) = self.build(back);
+
+        let (x, y) = self.vertexes[partition.v1];
+        let (x2, y2) = self.vertexes[partition.v2];
+        let to_fixed = |v: i64| Fixed(v as i32);
+        self.nodes.push(super::Node {
+            x: to_fixed(x),
+            y: to_fixed(y),
+            dx: to_fixed(x2 - x),
+            dy: to_fixed(y2 - y),
+            bbox: [
+                [
+                    to_fixed(front_bbox[super::BOXTOP]),
+                    to_fixed(front_bbox[super::BOXBOTTOM]),
+                    to_fixed(front_bbox[super::BOXLEFT]),
+                    to_fixed(front_bbox[super::BOXRIGHT]),
+                ],
+                [
+                    to_fixed(back_bbox[super::BOXTOP]),
+                    to_fixed(back_bbox[super::BOXBOTTOM]),
+                    to_fixed(back_bbox[super::BOXLEFT]),
+                    to_fixed(back_bbox[super::BOXRIGHT]),
+                ],
+            ],
+            children: [front_child, back_child],
+        });
+
+        (super::NodeChild::Node(self.nodes.len() - 1), bbox)
+    }
+
+    fn bbox(&self, segs: &[BuildSeg]) -> [i64; 4] {
+        let mut bbox = [i64::MIN, i64::MAX, i64::MAX, i64::MIN];
+        for seg in segs {
+            for &v in [seg.v1, seg.v2].iter() {
+                let (x, y) = self.vertexes[v];
+                bbox[super::BOXTOP] = bbox[super::BOXTOP].max(y);
+                bbox[super::BOXBOTTOM] = bbox[super::BOXBOTTOM].min(y);
+                bbox[super::BOXLEFT] = bbox[super::BOXLEFT].min(x);
+                bbox[super::BOXRIGHT] = bbox[super::BOXRIGHT].max(x);
+            }
+        }
+        bbox
+    }
+
+    // Points on the right of the partition, where vanilla's R_PointOnSide
+    // gives 0, are in front of it. Fixed point deltas take 33 bits, so their
+    // products don't fit in an i64.
+    fn cross(&self, partition: &BuildSeg, v: usize) -> i128 {
+        let (px, py) = self.vertexes[partition.v1];
+        let (px2, py2) = self.vertexes[partition.v2];
+        let (x, y) = self.vertexes[v];
+        (px2 - px) as i128 * (y - py) as i128 - (py2 - py) as i128 * (x - px) as i128
+    }
+
+    fn classify(&self, partition: &BuildSeg, seg: &BuildSeg) -> Side {
+        let a = self.cross(partition, seg.v1);
+        let b = self.cross(partition, seg.v2);
+
+        if a == 0 && b == 0 {
+            let (px, py) = self.vertexes[partition.v1];
+            let (px2, py2) = self.vertexes[partition.v2];
+            let (x, y) = self.vertexes[seg.v1];
+            let (x2, y2) = self.vertexes[seg.v2];
+            let dot = (px2 - px) as i128 * (x2 - x) as i128 + (py2 - py) as i128 * (y2 - y) as i128;
+            return if dot > 0 { Side::Front } else { Side::Back };
+        }
+
+        if a <= 0 && b <= 0 {
+            return Side::Front;
+        } else if a >= 0 && b >= 0 {
+            return Side::Back;
+        }
+
+        let (x1, y1) = self.vertexes[seg.v1];
+        let (x2, y2) = self.vertexes[seg.v2];
+        let t = a as f64 / (a - b) as f64;
+        let point = (
+            x1 as f64 + t * (x2 - x1) as f64,
+            y1 as f64 + t * (y2 - y1) as f64,
+        );
+
+        // Segs between whole map units are split at whole map units too, so
+        // the new vertexes survive being written to a VERTEXES lump; others
+        // are split as finely as fixed point goes.
+        let unit = FRACUNIT.0 as i64;
+        let on_grid = [x1, y1, x2, y2].iter().all(|v| v % unit == 0);
+        let round = |v: f64| {
+            if on_grid {
+                (v / unit as f64).round() as i64 * unit
+            } else {
+                v.round() as i64
+            }
+        };
+        let point = (round(point.0), round(point.1));
+
+        // Rounding can land on an end of the seg, which then lies wholly on
+        // the other end's side.
+        if point == (x1, y1) {
+            if b < 0 {
+                Side::Front
+            } else {
+                Side::Back
+            }
+        } else if point == (x2, y2) {
+            if a < 0 {
+                Side::Front
+            } else {
+                Side::Back
+            }
+        } else {
+            Side::Split(point, a < 0)
+        }
+    }
+
+    fn choose_partition(&self, segs: &[BuildSeg]) -> Option<BuildSeg> {
+        let step = segs.len().div_ceil(MAX_CANDIDATES);
+        let mut best: Option<(i64, BuildSeg)> = None;
+        let mut tried_lines = std::collections::BTreeSet::new();
+
+        for candidate in segs.iter().step_by(step.max(1)) {
+            if !tried_lines.insert(candidate.linedef) {
+                continue;
+            }
+
+            let (mut front, mut back, mut splits) = (0i64, 0i64, 0i64);
+            for seg in segs {
+                match self.classify(candidate, seg) {
+                    Side::Front => front += 1,
+                    Side::Back => back += 1,
+                    Side::Split(..) => splits += 1,
+                }
+            }
+            if back == 0 && splits == 0 {
+                continue;
+            }
+
+            let cost = splits * SPLIT_COST + (front - back).abs();
+            if best.map(|(best_cost, _)| cost < best_cost).unwrap_or(true) {
+                best = Some((cost, *candidate));
+            }
+        }
+
+        // With a sample of candidates, none of them dividing the segs does
+        // not yet mean the subsector is convex.
+        if best.is_none() && step > 1 {
+            return segs
+                .iter()
+                .find(|candidate| {
+                    segs.iter()
+                        .any(|seg| !matches!(self.classify(candidate, seg), Side::Front))
+                })
+                .copied();
+        }
+
+        best.map(|(_, partition)| partition)
+    }
+
+    fn split(&mut self, segs: &[BuildSeg], partition: &BuildSeg) -> (Vec<BuildSeg>, Vec<BuildSeg>) {
+        let mut front = vec![];
+        let mut back = vec![];
+
+        for seg in segs {
+            match self.classify(partition, seg) {
+                Side::Front => front.push(*seg),
+                Side::Back => back.push(*seg),
+                Side::Split(point, first_in_front) => {
+                    let v = self.split_vertex(point);
+                    let first = BuildSeg { v2: v, ..*seg };
+                    let second = BuildSeg { v1: v, ..*seg };
+                    if first_in_front {
+                        front.push(first);
+                        back.push(second);
+                    } else {
+                        back.push(first);
+                        front.push(second);
+                    }
+                }
+            }
+        }
+
+        (front, back)
+    }
+
+    fn split_vertex(&mut self, point: (i64, i64)) -> usize {
+        let vertexes = &mut self.vertexes;
+        *self.split_vertexes.entry(point).or_insert_with(|| {
+            vertexes.push(point);
+            vertexes.len() - 1
+        })
+    }
+
+    fn make_seg(&self, seg: &BuildSeg) -> super::Seg {
+        let line = &self.map.linedefs[seg.linedef];
+        let (lx1, ly1) = self.vertexes[line.v1];
+        let (lx2, ly2) = self.vertexes[line.v2];
+
+        // Segs take the angle of their whole linedef rather than of the
+        // rounded piece, as other node builders do.
+        let angle = line_angle((lx2 - lx1) as f64, (ly2 - ly1) as f64, seg.side);
+
+        let (sx, sy) = if seg.side == 0 {
+            (lx1, ly1)
+        } else {
+            (lx2, ly2)
+        };
+        let (x, y) = self.vertexes[seg.v1];
+        let offset = Fixed(((x - sx) as f64).hypot((y - sy) as f64).round() as i32);
+
+        super::Seg {
+            v1: seg.v1,
+            v2: seg.v2,
+            angle,
+            linedef: seg.linedef,
+            side: seg.side,
+            offset,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::lumps;
+    use super::super::testing::MapLumps;
+    use super::super::Map;
+    use crate::fixed::Fixed;
+
+    fn encode(map: &Map) -> [Vec<u8>; 4] {
+        [
+            lumps::encode_vertexes(&map.vertexes),
+            lumps::encode_segs(&map.segs, map.vertexes.len()).unwrap(),
+            lumps::encode_subsectors(&map.subsectors).unwrap(),
+            lumps::encode_nodes(&map.nodes).unwrap(),
+        ]
+    }
+
+    fn pillars() -> MapLumps {
+        let mut lumps = MapLumps::room(1024);
+        lumps
+            .pillar(100, 100, 64)
+            .pillar(600, 300, 128)
+            .pillar(300, 700, 32);
+        lumps
+    }
+
+    #[test]
+    fn builds_the_same_lumps_every_time() {
+        let map = pillars().load(&[]).unwrap();
+        assert_eq!(map.node_format, super::super::extended::NodeFormat::Built);
+        assert!(map.nodes.len() > 1);
+
+        let again = pillars().load(&[]).unwrap();
+        assert_eq!(encode(&map), encode(&again));
+
+        // Building over nodes that were already built gives them back too,
+        // split vertexes included.
+        let mut rebuilt = map.clone();
+        rebuilt.build_nodes();
+        assert_eq!(encode(&map), encode(&rebuilt));
+    }
+
+    #[test]
+    fn built_nodes_cover_every_linedef() {
+        let map = pillars().load(&[]).unwrap();
+        for (i, line) in map.linedefs.iter().enumerate() {
+            assert!(map.segs.iter().any(|seg| seg.linedef == i && seg.side == 0));
+            assert!(line.sidenum[1].is_none());
+        }
+        // Every subsector is in the one sector, and points inside the room
+        // land in one.
+        assert!(map
+            .subsectors
+            .iter()
+            .enumerate()
+            .all(|(i, _)| map.subsector_sector(i) == 0));
+        let x = Fixed::from_int(900);
+        assert!(map.point_in_subsector(x, x) < map.subsectors.len());
+    }
+
+    #[test]
+    fn keeps_vertexes_off_the_map_grid() {
+        let mut map = pillars().load(&[]).unwrap();
+        // Nudge the first pillar by half a unit, and squash the second into
+        // a quarter unit square, whose lines all round to one point.
+        let half = Fixed(0x8000);
+        for v in &mut map.vertexes[4..8] {
+            v.x += half;
+            v.y += half;
+        }
+        let corner = map.vertexes[8];
+        for (i, v) in map.vertexes[8..12].iter_mut().enumerate() {
+            v.x = corner.x + Fixed(0x4000 * (i == 1 || i == 2) as i32);
+            v.y = corner.y + Fixed(0x4000 * (i >= 2) as i32);
+        }
+        let original = map.vertexes[..16].to_vec();
+
+        map.build_nodes();
+        assert_eq!(&map.vertexes[..16], &original[..]);
+        for i in 0..map.linedefs.len() {
+            assert!(map.segs.iter().any(|seg| seg.linedef == i));
+        }
+
+        // The first pillar's left side, linedef 7, is now at x = 100.5, so
+        // it bounds the subsector a quarter unit to the left of it, and not
+        // the one a quarter unit to the right.
+        let has_left_side = |x: f64| {
+            let subsector = map.point_in_subsector(Fixed::from_f64(x), Fixed::from_f64(130.0));
+            let subsector = map.subsectors[subsector];
+            map.segs[subsector.first_seg..subsector.first_seg + subsector.num_segs]
+                .iter()
+                .any(|seg| seg.linedef == 7)
+        };
+        assert!(has_left_side(100.25));
+        assert!(!has_left_side(100.75));
+    }
+}
@@ -0,0 +1,67 @@
+use crate::fixed::Fixed;
+
+impl super::Node {
+    // R_PointOnSide: 0 for the front of the partition, 1 for the back. The
+    // partition deltas lose their fractions before the multiply, as vanilla's
+    // do, so points right by the line land on the same side they do there.
+    pub fn point_on_side(&self, x: Fixed, y: Fixed) -> usize {
+        if self.dx.0 == 0 {
+            return if x <= self.x {
+                (self.dy.0 > 0) as usize
+            } else {
+                (self.dy.0 < 0) as usize
+            };
+        }
+        if self.dy.0 == 0 {
+            return if y <= self.y {
+                (self.dx.0 < 0) as usize
+            } else {
+                (self.dx.0 > 0) as usize
+            };
+        }
+
+        let dx = x - self.x;
+        let dy = y - self.y;
+
+        // The signs alone decide it when the cross product's terms differ.
+        if (self.dy.0 ^ self.dx.0 ^ dx.0 ^ dy.0) < 0 {
+            return ((self.dy.0 ^ dx.0) < 0) as usize;
+        }
+
+        let left = Fixed(self.dy.to_int()) * dx;
+        let right = dy * Fixed(self.dx.to_int());
+        if right < left {
+            0
+        } else {
+            1
+        }
+    }
+}
+
+impl super::Map {
+    // R_PointInSubsector. The root node is the last one.
+    pub fn point_in_subsector(&self, x: Fixed, y: Fixed) -> usize {
+        if self.nodes.is_empty() {
+            return 0;
+        }
+
+        let mut child = super::NodeChild::Node(self.nodes.len() - 1);
+        loop {
+            match child {
+                super::NodeChild::SubSector(subsector) => return subsector,
+                super::NodeChild::Node(node) => {
+                    let node = &self.nodes[node];
+                    child = node.children[node.point_on_side(x, y)];
+                }
+            }
+        }
+    }
+
+    // A subsector is in the sector of the side its first seg is on; validate
+    // has made sure that side exists.
+    pub fn subsector_sector(&self, subsector: usize) -> usize {
+        let seg = &self.segs[self.subsectors[subsector].first_seg];
+        let side = self.linedefs[seg.linedef].sidenum[seg.side].unwrap();
+        self.sidedefs[side].sector
+    }
+}
@@ -0,0 +1,445 @@
+use crate::info::{MF_COUNTITEM, MF_COUNTKILL};
+use crate::map::{MTF_EASY, MTF_HARD, MTF_NORMAL, MTF_NOTSINGLE};
+
+// Skill levels share thing flags in pairs, so the report has one column
+// per flag rather than one per skill.
+pub const SKILL_CLASSES: [(&str, i16); 3] =
+    [("1-2", MTF_EASY), ("3", MTF_NORMAL), ("4-5", MTF_HARD)];
+
+const KEYS: [usize; 6] = [
+    crate::info::MT_MISC4,
+    crate::info::MT_MISC5,
+    crate::info::MT_MISC6,
+    crate::info::MT_MISC7,
+    crate::info::MT_MISC8,
+    crate::info::MT_MISC9,
+];
+
+const WEAPONS: [usize; 7] = [
+    crate::info::MT_SHOTGUN,
+    crate::info::MT_SUPERSHOTGUN,
+    crate::info::MT_CHAINGUN,
+    crate::info::MT_MISC25,
+    crate::info::MT_MISC26,
+    crate::info::MT_MISC27,
+    crate::info::MT_MISC28,
+];
+
+const SECRET_SECTOR: i16 = 9;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SkillStats {
+    pub monsters: usize,
+    pub monster_hp: i64,
+    pub items: usize,
+    pub weapons: usize,
+    pub keys: usize,
+}
+
+impl SkillStats {
+    fn rows(&self) -> [(&'static str, i64); 5] {
+        [
+            ("Monsters", self.monsters as i64),
+            ("Monster HP", self.monster_hp),
+            ("Items", self.items as i64),
+            ("Weapons", self.weapons as i64),
+            ("Keys", self.keys as i64),
+        ]
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MapStats {
+    pub name: String,
+    pub node_format: String,
+    pub counts: Vec<(&'static str, usize)>,
+    pub bounds: (i64, i64, i64, i64),
+    pub skills: [SkillStats; 3],
+    pub unknown_things: usize,
+    pub secret_sectors: usize,
+    pub specials: Vec<(i16, usize)>,
+    pub warnings: Vec<String>,
+}
+
+impl super::Map {
+    // Counts what a single player game starts with on each skill.
+    pub fn stats(&self, info: &crate::info::Info) -> MapStats {
+        let mut stats = MapStats {
+            name: self.name.clone(),
+            node_format: self.node_format.to_string(),
+            counts: vec![
+                ("things", self.things.len()),
+                ("linedefs", self.linedefs.len()),
+                ("sidedefs", self.sidedefs.len()),
+                ("vertexes", self.vertexes.len()),
+                ("sectors", self.sectors.len()),
+                ("segs", self.segs.len()),
+                ("subsectors", self.subsectors.len()),
+                ("nodes", self.nodes.len()),
+            ],
+            bounds: self.line_bounds().unwrap_or_default(),
+            warnings: self.warnings.clone(),
+            ..MapStats::default()
+        };
+
+        for thing in &self.things {
+            let kind = match info
+                .mobj_info
+                .iter()
+                .position(|m| m.doomed_num == thing.kind as i32)
+            {
+                Some(kind) => kind,
+                None => {
+                    // Player and deathmatch starts have no mobj type.
+                    if !matches!(thing.kind, 1..=4 | 11) {
+                        stats.unknown_things += 1;
+                    }
+                    continue;
+                }
+            };
+            if thing.options & MTF_NOTSINGLE != 0 {
+                continue;
+            }
+
+            let mobj = &info.mobj_info[kind];
+            for (skill, (_, bit)) in stats.skills.iter_mut().zip(SKILL_CLASSES.iter()) {
+                if thing.options & bit == 0 {
+                    continue;
+                }
+                if mobj.flags & MF_COUNTKILL != 0 {
+                    skill.monsters += 1;
+                    skill.monster_hp += mobj.spawn_health as i64;
+                }
+                if mobj.flags & MF_COUNTITEM != 0 {
+                    skill.items += 1;
+                }
+                if WEAPONS.contains(&kind) {
+                    skill.weapons += 1;
+                }
+                if KEYS.contains(&kind) {
+                    skill.keys += 1;
+                }
+            }
+        }
+
+        stats.secret_sectors = self
+            .sectors
+            .iter()
+            .filter(|s| s.special == SECRET_SECTOR)
+            .count();
+
+        let mut specials = std::collections::BTreeMap::new();
+        for line in self.linedefs.iter().filter(|l| l.special != 0) {
+            *specials.entry(line.special).or_insert(0) += 1;
+        }
+        stats.specials = specials.into_iter().collect();
+
+        stats
+    }
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from("\"");
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+impl MapStats {
+    pub fn table(&self) -> String {
+        let mut out = format!("Map {} (nodes: {})\n", self.name, self.node_format);
+
+        for (what, count) in &self.counts {
+            out += &format!("  {:<12}{:>8}\n", what, count);
+        }
+        let (min_x, min_y, max_x, max_y) = self.bounds;
+        out += &format!(
+            "Bounds: ({}, {}) to ({}, {}), {} x {}\n\n",
+            min_x,
+            min_y,
+            max_x,
+            max_y,
+            max_x - min_x,
+            max_y - min_y
+        );
+
+        out += &format!("{:<14}", "Skill");
+        for (name, _) in SKILL_CLASSES.iter() {
+            out += &format!("{:>10}", name);
+        }
+        out += "\n";
+        let rows = self.skills.iter().map(SkillStats::rows).collect::<Vec<_>>();
+        for (row, (name, _)) in rows[0].iter().enumerate() {
+            out += &format!("{:<14}", name);
+            for skill in &rows {
+                out += &format!("{:>10}", skill[row].1);
+            }
+            out += "\n";
+        }
+        if self.unknown_things > 0 {
+            out += &format!("Unknown things: {}\n", self.unknown_things);
+        }
+
+        out += &format!("\nSecret sectors: {}\n", self.secret_sectors);
+        if self.specials.is_empty() {
+            out += "Linedef specials: none\n";
+        } else {
+            out += "Linedef specials:\n";
+            for (special, count) in &self.specials {
+                out += &format!("  {:>5}  x{}\n", special, count);
+            }
+        }
+
+        out
+    }
+
+    pub fn json(&self) -> String {
+        let object = |fields: Vec<String>| format!("{{{}}}", fields.join(", "));
+        let (min_x, min_y, max_x, max_y) = self.bounds;
+
+        let fields = vec![
+            format!("\"map\": {}", json_string(&self.name)),
+            format!("\"node_format\": {}", json_string(&self.node_format)),
+            format!(
+                "\"counts\": {}",
+                object(
+                    self.counts
+                        .iter()
+                        .map(|(what, count)| format!("\"{}\": {}", what, count))
+                        .collect()
+                )
+            ),
+            format!(
+                "\"bounds\": {{\"min_x\": {}, \"min_y\": {}, \"max_x\": {}, \"max_y\": {}}}",
+                min_x, min_y, max_x, max_y
+            ),
+            format!(
+                "\"skills\": {}",
+                object(
+                    SKILL_CLASSES
+                        .iter()
+                        .zip(self.skills.iter())
+                        .map(|((name, _), s)| {
+                            format!(
+                                "\"{}\": {{\"monsters\": {}, \"monster_hp\": {}, \"items\": {}, \"weapons\": {}, \"keys\": {}}}",
+                                name, s.monsters, s.monster_hp, s.items, s.weapons, s.keys
+                            )
+                        })
+                        .collect()
+                )
+            ),
+            format!("\"unknown_things\": {}", self.unknown_things),
+            format!("\"secret_sectors\": {}", self.secret_sectors),
+            format!(
+                "\"linedef_specials\": {}",
+                object(
+                    self.specials
+                        .iter()
+                        .map(|(special, count)| format!("\"{}\": {}", special, count))
+                        .collect()
+                )
+            ),
+            format!(
+                "\"warnings\": [{}]",
+                self.warnings
+                    .iter()
+                    .map(|w| json_string(w))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        ];
+
+        format!("{{\n  {}\n}}", fields.join(",\n  "))
+    }
+}
+
+impl crate::state::State {
+    pub fn map_info_mode(&self, name: &str, json: bool) -> ! {
+        let stats = self.load_map(name).stats(&self.info);
+
+        if json {
+            println!("{}", stats.json());
+        } else {
+            print!("{}", stats.table());
+        }
+
+        std::process::exit(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testing::MapLumps;
+    use super::*;
+
+    // Just enough of a JSON parser to say whether the text is one value,
+    // with nothing after it.
+    fn is_json(text: &str) -> bool {
+        fn value(s: &[u8], i: &mut usize) -> bool {
+            skip_space(s, i);
+            let ok = match s.get(*i) {
+                Some(b'{') => list(s, i, b'}', |s, i| {
+                    string(s, i) && colon(s, i) && value(s, i)
+                }),
+                Some(b'[') => list(s, i, b']', value),
+                Some(b'"') => string(s, i),
+                Some(c) if c.is_ascii_digit() || *c == b'-' => {
+                    *i += 1;
+                    while s.get(*i).is_some_and(|c| c.is_ascii_digit()) {
+                        *i += 1;
+                    }
+                    true
+                }
+                _ => false,
+            };
+            skip_space(s, i);
+            ok
+        }
+
+        fn skip_space(s: &[u8], i: &mut usize) {
+            while s.get(*i).is_some_and(|c| c.is_ascii_whitespace()) {
+                *i += 1;
+            }
+        }
+
+        fn colon(s: &[u8], i: &mut usize) -> bool {
+            skip_space(s, i);
+            *i += 1;
+            s.get(*i - 1) == Some(&b':')
+        }
+
+        fn string(s: &[u8], i: &mut usize) -> bool {
+            skip_space(s, i);
+            if s.get(*i) != Some(&b'"') {
+                return false;
+            }
+            *i += 1;
+            loop {
+                match s.get(*i) {
+                    Some(b'"') => break,
+                    Some(b'\\') => match s.get(*i + 1) {
+                        Some(b'"') | Some(b'\\') => *i += 2,
+                        Some(b'u') if s.len() > *i + 5 => {
+                            if !s[*i + 2..*i + 6].iter().all(u8::is_ascii_hexdigit) {
+                                return false;
+                            }
+                            *i += 6;
+                        }
+                        _ => return false,
+                    },
+                    Some(c) if *c >= 0x20 => *i += 1,
+                    _ => return false,
+                }
+            }
+            *i += 1;
+            true
+        }
+
+        fn list(
+            s: &[u8],
+            i: &mut usize,
+            close: u8,
+            item: impl Fn(&[u8], &mut usize) -> bool,
+        ) -> bool {
+            *i += 1;
+            skip_space(s, i);
+            if s.get(*i) == Some(&close) {
+                *i += 1;
+                return true;
+            }
+            loop {
+                if !item(s, i) {
+                    return false;
+                }
+                skip_space(s, i);
+                *i += 1;
+                match s.get(*i - 1) {
+                    Some(b',') => {}
+                    Some(c) if *c == close => return true,
+                    _ => return false,
+                }
+            }
+        }
+
+        let mut i = 0;
+        value(text.as_bytes(), &mut i) && i == text.len()
+    }
+
+    fn fixture() -> MapLumps {
+        let mut lumps = MapLumps::room(512);
+        lumps
+            .thing(64, 64, 0, 1, 7)
+            // A zombieman on every skill and an imp on the hard ones.
+            .thing(100, 100, 0, 3004, 7)
+            .thing(120, 100, 0, 3001, MTF_HARD)
+            // Multiplayer only, so never counted.
+            .thing(140, 100, 0, 3001, 7 | MTF_NOTSINGLE)
+            .thing(160, 100, 0, 2001, MTF_EASY | MTF_NORMAL)
+            .thing(180, 100, 0, 5, 7)
+            .thing(200, 100, 0, 2014, MTF_EASY)
+            .thing(220, 100, 0, 9999, 7);
+
+        // A second sector, which is secret.
+        lumps.sector(0, 128);
+        let secret = lumps.sectors.len() - 4;
+        lumps.sectors[secret..secret + 2].copy_from_slice(&SECRET_SECTOR.to_le_bytes());
+        lumps
+    }
+
+    #[test]
+    fn counts_each_skill() {
+        let map = fixture().load(&[]).unwrap();
+        let stats = map.stats(&crate::info::Info::default());
+
+        let skill = |monsters, monster_hp, items, weapons, keys| SkillStats {
+            monsters,
+            monster_hp,
+            items,
+            weapons,
+            keys,
+        };
+        assert_eq!(
+            stats.skills,
+            [
+                skill(1, 20, 1, 1, 1),
+                skill(1, 20, 0, 1, 1),
+                skill(2, 80, 0, 0, 1),
+            ]
+        );
+        assert_eq!(stats.unknown_things, 1);
+        assert_eq!(stats.secret_sectors, 1);
+        assert_eq!(stats.counts[0], ("things", 8));
+        assert_eq!(stats.bounds, (0, 0, 512, 512));
+        assert!(stats.specials.is_empty());
+    }
+
+    #[test]
+    fn json_is_well_formed() {
+        let map = fixture().load(&[]).unwrap();
+        let mut stats = map.stats(&crate::info::Info::default());
+        stats.specials = vec![(1, 2), (11, 1)];
+        stats.warnings.push(String::from("a \"quoted\"\tC:\\path"));
+
+        let json = stats.json();
+        assert!(is_json(&json), "{}", json);
+        assert!(json.contains("\"4-5\": {\"monsters\": 2, \"monster_hp\": 80,"));
+        assert!(json.contains("\"linedef_specials\": {\"1\": 2, \"11\": 1}"));
+        assert!(json.contains(r#""a \"quoted\"\u0009C:\\path""#));
+
+        assert!(!is_json("{\"a\": 1,}"));
+        assert!(!is_json("[\"tab\t\"]"));
+    }
+
+    #[test]
+    fn escapes_json_strings() {
+        assert_eq!(json_string("E1M1"), "\"E1M1\"");
+        assert_eq!(json_string("\"\\\n\u{1f}é"), r#""\"\\\u000a\u001fé""#);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::wad::writer::WadWriter;
+
+// Map lumps built record by record, for tests that need a map to load.
+#[derive(Default)]
+pub struct MapLumps {
+    pub things: Vec<u8>,
+    pub linedefs: Vec<u8>,
+    pub sidedefs: Vec<u8>,
+    pub vertexes: Vec<u8>,
+    pub sectors: Vec<u8>,
+}
+
+fn push_i16(data: &mut Vec<u8>, values: &[i16]) {
+    for value in values {
+        data.extend_from_slice(&value.to_le_bytes());
+    }
+}
+
+fn push_name(data: &mut Vec<u8>, name: &str) {
+    data.extend_from_slice(&crate::wad::normalize_name(name.as_bytes()));
+}
+
+impl MapLumps {
+    // A square room of one sector, with its corners as the first four
+    // vertexes, going round anticlockwise from the origin.
+    pub fn room(size: i16) -> Self {
+        let mut lumps = MapLumps::default();
+        lumps.sector(0, 128);
+        for &(x, y) in &[(0, 0), (size, 0), (size, size), (0, size)] {
+            lumps.vertex(x, y);
+        }
+        // Going round the other way keeps the room on the right of each line.
+        for i in 0..4 {
+            lumps.sidedef(0);
+            lumps.linedef((i + 1) % 4, i, 0, [i, 0xffff]);
+        }
+        lumps
+    }
+
+    // A square pillar in sector 0, so the map needs more than one subsector.
+    pub fn pillar(&mut self, x: i16, y: i16, size: i16) -> &mut Self {
+        let first = (self.vertexes.len() / 4) as u16;
+        for &(dx, dy) in &[(0, 0), (size, 0), (size, size), (0, size)] {
+            self.vertex(x + dx, y + dy);
+        }
+        for i in 0..4 {
+            let side = (self.sidedefs.len() / 30) as u16;
+            self.sidedef(0);
+            self.linedef(first + i, first + (i + 1) % 4, 0, [side, 0xffff]);
+        }
+        self
+    }
+
+    pub fn thing(&mut self, x: i16, y: i16, angle: i16, kind: i16, options: i16) -> &mut Self {
+        push_i16(&mut self.things, &[x, y, angle, kind, options]);
+        self
+    }
+
+    pub fn vertex(&mut self, x: i16, y: i16) -> &mut Self {
+        push_i16(&mut self.vertexes, &[x, y]);
+        self
+    }
+
+    pub fn linedef(&mut self, v1: u16, v2: u16, special: i16, sides: [u16; 2]) -> &mut Self {
+        push_i16(
+            &mut self.linedefs,
+            &[
+                v1 as i16,
+                v2 as i16,
+                0,
+                special,
+                0,
+                sides[0] as i16,
+                sides[1] as i16,
+            ],
+        );
+        self
+    }
+
+    pub fn sidedef(&mut self, sector: u16) -> &mut Self {
+        push_i16(&mut self.sidedefs, &[0, 0]);
+        for _ in 0..3 {
+            push_name(&mut self.sidedefs, "-");
+        }
+        push_i16(&mut self.sidedefs, &[sector as i16]);
+        self
+    }
+
+    pub fn sector(&mut self, floor: i16, ceiling: i16) -> &mut Self {
+        push_i16(&mut self.sectors, &[floor, ceiling]);
+        push_name(&mut self.sectors, "FLOOR");
+        push_name(&mut self.sectors, "CEIL");
+        push_i16(&mut self.sectors, &[160, 0, 0]);
+        self
+    }
+
+    pub fn lumps(&self) -> Vec<(&'static str, Vec<u8>)> {
+        vec![
+            ("THINGS", self.things.clone()),
+            ("LINEDEFS", self.linedefs.clone()),
+            ("SIDEDEFS", self.sidedefs.clone()),
+            ("VERTEXES", self.vertexes.clone()),
+            ("SECTORS", self.sectors.clone()),
+        ]
+    }
+
+    pub fn load(
+        &self,
+        extra: &[(&'static str, Vec<u8>)],
+    ) -> Result<super::Map, super::error::MapError> {
+        let mut lumps = self.lumps();
+        lumps.extend_from_slice(extra);
+        super::Map::load(&write_wad("MAP01", &lumps), "MAP01", false)
+    }
+}
+
+// Writes a map out as a PWAD and reads it back in, so it goes through the
+// same loader real maps do.
+pub fn write_wad(map: &str, lumps: &[(&'static str, Vec<u8>)]) -> crate::wad::Wad {
+    static NEXT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+    let mut writer = WadWriter::new(crate::wad::file::WadKind::Pwad);
+    writer.add_marker(map);
+    for (name, data) in lumps {
+        writer.add_lump(name, data.clone());
+    }
+
+    let path = std::env::temp_dir().join(format!(
+        "ez_doom_test_{}_{}.wad",
+        std::process::id(),
+        NEXT.fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+    ));
+    let path = path.to_str().unwrap();
+    writer.write(path).unwrap();
+
+    let mut wad = crate::wad::Wad::default();
+    let result = wad.add_file(path);
+    std::fs::remove_file(path).unwrap();
+    result.unwrap();
+    wad
+}
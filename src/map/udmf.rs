@@ -0,0 +1,312 @@
+pub mod parser;
+
+use super::error::MapError;
+use crate::fixed::Fixed;
+use parser::{Block, Error, Field, Value};
+
+const NAMESPACES: [&str; 2] = ["doom", "zdoom"];
+
+const LINE_ARGS: [&str; 5] = ["arg0", "arg1", "arg2", "arg3", "arg4"];
+
+const LINE_FLAGS: [(&str, i16); 10] = [
+    ("blocking", 0x0001),
+    ("blockmonsters", 0x0002),
+    ("twosided", 0x0004),
+    ("dontpegtop", 0x0008),
+    ("dontpegbottom", 0x0010),
+    ("secret", 0x0020),
+    ("blocksound", 0x0040),
+    ("dontdraw", 0x0080),
+    ("mapped", 0x0100),
+    ("passuse", 0x0200),
+];
+
+const THING_SKILLS: [(&str, i16); 5] = [
+    ("skill1", 0x0001),
+    ("skill2", 0x0001),
+    ("skill3", 0x0002),
+    ("skill4", 0x0004),
+    ("skill5", 0x0004),
+];
+
+// Set when the field is false, as the binary format has "not in" flags.
+const THING_NOT_FLAGS: [(&str, i16); 3] = [("single", 0x0010), ("dm", 0x0020), ("coop", 0x0040)];
+
+const THING_FLAGS: [(&str, i16); 2] = [("ambush", 0x0008), ("friend", 0x0080)];
+
+impl Block {
+    fn field(&self, key: &str) -> Option<&Field> {
+        self.fields.iter().rev().find(|f| f.key == key)
+    }
+
+    fn missing(&self, key: &str) -> Error {
+        (self.line, format!("{} has no {}", self.kind, key))
+    }
+
+    fn wrong_type(&self, field: &Field, what: &str) -> Error {
+        (
+            field.line,
+            format!("{} {} must be {}", self.kind, field.key, what),
+        )
+    }
+
+    fn int(&self, key: &str, default: i64) -> Result<i64, Error> {
+        match self.field(key) {
+            None => Ok(default),
+            Some(Field {
+                value: Value::Int(n),
+                ..
+            }) => Ok(*n),
+            Some(field) => Err(self.wrong_type(field, "an integer")),
+        }
+    }
+
+    fn required_int(&self, key: &str) -> Result<i64, Error> {
+        self.field(key).ok_or_else(|| self.missing(key))?;
+        self.int(key, 0)
+    }
+
+    fn index(&self, key: &str) -> Result<usize, Error> {
+        match self.required_int(key)? {
+            n if n >= 0 => Ok(n as usize),
+            _ => Err(self.wrong_type(self.field(key).unwrap(), "an index")),
+        }
+    }
+
+    fn optional_index(&self, key: &str) -> Result<Option<usize>, Error> {
+        match self.int(key, -1)? {
+            -1 => Ok(None),
+            n if n >= 0 => Ok(Some(n as usize)),
+            _ => Err(self.wrong_type(self.field(key).unwrap(), "an index or -1")),
+        }
+    }
+
+    fn fixed(&self, key: &str) -> Result<Fixed, Error> {
+        let field = self.field(key).ok_or_else(|| self.missing(key))?;
+        match field.value {
+            Value::Float(f) => Ok(Fixed::from_f64(f)),
+            Value::Int(n) => Ok(Fixed::from_int(n as i32)),
+            _ => Err(self.wrong_type(field, "a number")),
+        }
+    }
+
+    fn flag(&self, key: &str, default: bool) -> Result<bool, Error> {
+        match self.field(key) {
+            None => Ok(default),
+            Some(Field {
+                value: Value::Bool(b),
+                ..
+            }) => Ok(*b),
+            Some(field) => Err(self.wrong_type(field, "true or false")),
+        }
+    }
+
+    fn flags(&self, flags: &[(&str, i16)], default: bool, when: bool) -> Result<i16, Error> {
+        let mut bits = 0;
+        for (key, bit) in flags {
+            if self.flag(key, default)? == when {
+                bits |= bit;
+            }
+        }
+        Ok(bits)
+    }
+
+    fn string(&self, key: &str, default: &str) -> Result<String, Error> {
+        match self.field(key) {
+            None => Ok(default.to_owned()),
+            Some(Field {
+                value: Value::Str(s),
+                ..
+            }) => Ok(s.clone()),
+            Some(field) => Err(self.wrong_type(field, "a string")),
+        }
+    }
+
+    fn required_string(&self, key: &str) -> Result<String, Error> {
+        self.field(key).ok_or_else(|| self.missing(key))?;
+        self.string(key, "")
+    }
+
+    fn thing(&self) -> Result<super::Thing, Error> {
+        Ok(super::Thing {
+            x: self.fixed("x")?,
+            y: self.fixed("y")?,
+            angle: self.int("angle", 0)? as i16,
+            kind: self.required_int("type")? as i16,
+            options: self.flags(&THING_SKILLS, false, true)?
+                | self.flags(&THING_NOT_FLAGS, false, false)?
+                | self.flags(&THING_FLAGS, false, true)?,
+        })
+    }
+
+    // The line id is its tag in the doom namespace; unset, it is -1. In the
+    // zdoom namespace specials are Hexen style, and are kept apart from the
+    // Doom ones with their arguments.
+    fn linedef(&self, hexen_specials: bool) -> Result<super::LineDef, Error> {
+        let special = self.int("special", 0)?;
+        let hexen_special = if hexen_specials && special != 0 {
+            let mut args = [0; 5];
+            for (arg, key) in args.iter_mut().zip(LINE_ARGS.iter()) {
+                *arg = self.int(key, 0)? as i32;
+            }
+            Some(super::HexenSpecial {
+                special: special as i32,
+                args,
+            })
+        } else {
+            None
+        };
+
+        Ok(super::LineDef {
+            v1: self.index("v1")?,
+            v2: self.index("v2")?,
+            flags: self.flags(&LINE_FLAGS, false, true)?,
+            special: if hexen_specials { 0 } else { special as i16 },
+            tag: self.int("id", 0)?.max(0) as i16,
+            sidenum: [
+                Some(self.index("sidefront")?),
+                self.optional_index("sideback")?,
+            ],
+            hexen_special,
+        })
+    }
+
+    fn sidedef(&self) -> Result<super::SideDef, Error> {
+        Ok(super::SideDef {
+            texture_offset: Fixed::from_int(self.int("offsetx", 0)? as i32),
+            row_offset: Fixed::from_int(self.int("offsety", 0)? as i32),
+            top_texture: self.string("texturetop", "-")?,
+            bottom_texture: self.string("texturebottom", "-")?,
+            mid_texture: self.string("texturemiddle", "-")?,
+            sector: self.index("sector")?,
+        })
+    }
+
+    fn vertex(&self) -> Result<super::Vertex, Error> {
+        Ok(super::Vertex {
+            x: self.fixed("x")?,
+            y: self.fixed("y")?,
+        })
+    }
+
+    fn sector(&self) -> Result<super::Sector, Error> {
+        Ok(super::Sector {
+            floor_height: Fixed::from_int(self.int("heightfloor", 0)? as i32),
+            ceiling_height: Fixed::from_int(self.int("heightceiling", 0)? as i32),
+            floor_pic: self.required_string("texturefloor")?,
+            ceiling_pic: self.required_string("textureceiling")?,
+            light_level: self.int("lightlevel", 160)? as i16,
+            special: self.int("special", 0)? as i16,
+            tag: self.int("id", 0)? as i16,
+        })
+    }
+}
+
+impl super::Map {
+    // Fills in the map from a TEXTMAP lump. Unknown blocks and fields are
+    // skipped, as the specification asks.
+    pub fn load_textmap(&mut self, data: &[u8]) -> Result<(), MapError> {
+        let map = self.name.clone();
+        let udmf_error = |(line, why): Error| MapError::Udmf {
+            map: map.clone(),
+            line,
+            why,
+        };
+
+        let text = String::from_utf8_lossy(data);
+        let textmap = parser::parse(&text).map_err(udmf_error)?;
+
+        if !NAMESPACES
+            .iter()
+            .any(|n| n.eq_ignore_ascii_case(&textmap.namespace))
+        {
+            return Err(udmf_error((
+                textmap.namespace_line,
+                format!("namespace \"{}\" is not supported", textmap.namespace),
+            )));
+        }
+
+        let hexen_specials = textmap.namespace.eq_ignore_ascii_case("zdoom");
+        for block in &textmap.blocks {
+            match block.kind.as_str() {
+                "thing" => self.things.push(block.thing().map_err(udmf_error)?),
+                "linedef" => self
+                    .linedefs
+                    .push(block.linedef(hexen_specials).map_err(udmf_error)?),
+                "sidedef" => self.sidedefs.push(block.sidedef().map_err(udmf_error)?),
+                "vertex" => self.vertexes.push(block.vertex().map_err(udmf_error)?),
+                "sector" => self.sectors.push(block.sector().map_err(udmf_error)?),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    // ZNODES holds ZDoom nodes for a UDMF map. When they can't be used the
+    // map is left without nodes, for the node builder to make them.
+    pub fn load_znodes(&mut self, data: &[u8]) {
+        if data.is_empty() {
+            return;
+        }
+
+        let vertexes = self.vertexes.clone();
+        if let Err(why) = self.decode_zdoom(data) {
+            self.vertexes = vertexes;
+            self.segs.clear();
+            self.subsectors.clear();
+            self.nodes.clear();
+            self.node_format = super::extended::NodeFormat::Vanilla;
+            self.warnings.push(format!("ZNODES ignored: {}", why));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{HexenSpecial, Map};
+
+    fn load(namespace: &str) -> Map {
+        let textmap = format!(
+            "namespace = \"{}\";\n\
+             linedef {{ v1 = 0; v2 = 1; sidefront = 0; special = 80; id = 3;\n\
+                        arg0 = 7; arg2 = 1; }}\n",
+            namespace
+        );
+        let mut map = Map::default();
+        map.load_textmap(textmap.as_bytes()).unwrap();
+        map
+    }
+
+    #[test]
+    fn doom_specials_are_line_types() {
+        let line = load("doom").linedefs[0];
+        assert_eq!((line.special, line.tag), (80, 3));
+        assert_eq!(line.hexen_special, None);
+    }
+
+    #[test]
+    fn zdoom_specials_keep_their_arguments() {
+        let line = load("zdoom").linedefs[0];
+        assert_eq!((line.special, line.tag), (0, 3));
+        assert_eq!(
+            line.hexen_special,
+            Some(HexenSpecial {
+                special: 80,
+                args: [7, 0, 1, 0, 0],
+            })
+        );
+    }
+
+    #[test]
+    fn other_namespaces_are_refused() {
+        let mut map = Map::default();
+        let error = map
+            .load_textmap(b"// Hexen\nnamespace = \"hexen\";\n")
+            .unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Map : TEXTMAP line 2: namespace \"hexen\" is not supported"
+        );
+    }
+}
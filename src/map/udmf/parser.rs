@@ -0,0 +1,374 @@
+use nom::branch::alt;
+use nom::bytes::complete::{tag, take_until, take_while, take_while1};
+use nom::character::complete::{char, digit0, digit1, hex_digit1, multispace1, oct_digit1, one_of};
+use nom::combinator::{map_res, opt, recognize};
+use nom::multi::many0;
+use nom::sequence::{pair, preceded, tuple};
+use nom::IResult;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Value {
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Bool(bool),
+    Keyword(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Field {
+    pub key: String,
+    pub value: Value,
+    pub line: usize,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Block {
+    pub kind: String,
+    pub line: usize,
+    pub fields: Vec<Field>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TextMap {
+    pub namespace: String,
+    pub namespace_line: usize,
+    pub blocks: Vec<Block>,
+}
+
+// A parse error, with the line it was found on.
+pub type Error = (usize, String);
+
+fn comment(i: &str) -> IResult<&str, &str> {
+    alt((
+        recognize(pair(tag("//"), take_while(|c| c != '\n'))),
+        recognize(tuple((tag("/*"), take_until("*/"), tag("*/")))),
+    ))(i)
+}
+
+fn skip(i: &str) -> &str {
+    many0(alt((multispace1, comment)))(i)
+        .map(|(rest, _)| rest)
+        .unwrap_or(i)
+}
+
+fn identifier(i: &str) -> IResult<&str, &str> {
+    recognize(pair(
+        take_while1(|c: char| c.is_ascii_alphabetic() || c == '_'),
+        take_while(|c: char| c.is_ascii_alphanumeric() || c == '_'),
+    ))(i)
+}
+
+fn float(i: &str) -> IResult<&str, f64> {
+    map_res(
+        recognize(tuple((
+            opt(one_of("+-")),
+            digit1,
+            char('.'),
+            digit0,
+            opt(tuple((one_of("eE"), opt(one_of("+-")), digit1))),
+        ))),
+        str::parse,
+    )(i)
+}
+
+fn integer(i: &str) -> IResult<&str, i64> {
+    let (rest, sign) = opt(one_of("+-"))(i)?;
+    let (rest, value) = alt((
+        map_res(preceded(alt((tag("0x"), tag("0X"))), hex_digit1), |d| {
+            i64::from_str_radix(d, 16)
+        }),
+        map_res(preceded(char('0'), oct_digit1), |d| {
+            i64::from_str_radix(d, 8)
+        }),
+        map_res(digit1, str::parse),
+    ))(rest)?;
+
+    Ok((rest, if sign == Some('-') { -value } else { value }))
+}
+
+fn quoted(i: &str) -> IResult<&str, String> {
+    let (mut rest, _) = char('"')(i)?;
+    let mut string = String::new();
+
+    loop {
+        let mut chars = rest.chars();
+        match chars.next() {
+            Some('"') => return Ok((chars.as_str(), string)),
+            Some('\\') => match chars.next() {
+                Some(c) => string.push(c),
+                None => break,
+            },
+            Some(c) => string.push(c),
+            None => break,
+        }
+        rest = chars.as_str();
+    }
+
+    Err(nom::Err::Error((i, nom::error::ErrorKind::Char)))
+}
+
+fn keyword(i: &str) -> IResult<&str, &str> {
+    take_while1(|c: char| !"{}();\"'\n\t ".contains(c))(i)
+}
+
+fn value(i: &str) -> IResult<&str, Value> {
+    if let Ok((rest, s)) = quoted(i) {
+        return Ok((rest, Value::Str(s)));
+    }
+    if let Ok((rest, f)) = float(i) {
+        return Ok((rest, Value::Float(f)));
+    }
+    if let Ok((rest, n)) = integer(i) {
+        return Ok((rest, Value::Int(n)));
+    }
+
+    let (rest, word) = keyword(i)?;
+    Ok((
+        rest,
+        match word.to_ascii_lowercase().as_str() {
+            "true" => Value::Bool(true),
+            "false" => Value::Bool(false),
+            _ => Value::Keyword(word.to_owned()),
+        },
+    ))
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    // The last offset a line was asked for and its line, as the parser
+    // mostly moves forwards and big maps have a lot of lines to count.
+    last_line: std::cell::Cell<(usize, usize)>,
+}
+
+impl<'a> Parser<'a> {
+    fn line(&self, rest: &str) -> usize {
+        let offset = self.text.len() - rest.len();
+        let (mut from, mut line) = self.last_line.get();
+        if offset < from {
+            from = 0;
+            line = 1;
+        }
+        line += self.text[from..offset].matches('\n').count();
+        self.last_line.set((offset, line));
+        line
+    }
+
+    fn error<S: Into<String>>(&self, rest: &str, why: S) -> Error {
+        (self.line(rest), why.into())
+    }
+
+    fn expect(&self, rest: &'a str, c: char, after: &str) -> Result<&'a str, Error> {
+        match char::<_, (&str, nom::error::ErrorKind)>(c)(skip(rest)) {
+            Ok((rest, _)) => Ok(rest),
+            Err(_) => Err(self.error(skip(rest), format!("expected '{}' after {}", c, after))),
+        }
+    }
+
+    // The key has been read; this reads "= value ;".
+    fn assignment(&self, rest: &'a str, key: &str) -> Result<(&'a str, Value), Error> {
+        let rest = skip(self.expect(rest, '=', key)?);
+        let (rest, value) = value(rest).map_err(|_| {
+            if rest.starts_with('"') {
+                self.error(rest, "unterminated string")
+            } else {
+                self.error(rest, format!("expected a value for {}", key))
+            }
+        })?;
+        let rest = self.expect(rest, ';', &format!("the value of {}", key))?;
+        Ok((rest, value))
+    }
+
+    fn identifier(&self, rest: &'a str) -> Result<(&'a str, String), Error> {
+        if rest.starts_with("/*") {
+            return Err(self.error(rest, "unterminated comment"));
+        }
+        identifier(rest)
+            .map(|(rest, name)| (rest, name.to_ascii_lowercase()))
+            .map_err(|_| self.error(rest, "expected an identifier"))
+    }
+
+    fn block(
+        &self,
+        mut rest: &'a str,
+        kind: String,
+        line: usize,
+    ) -> Result<(&'a str, Block), Error> {
+        let mut block = Block {
+            kind,
+            line,
+            fields: vec![],
+        };
+
+        loop {
+            rest = skip(rest);
+            if let Some(after) = rest.strip_prefix('}') {
+                return Ok((after, block));
+            }
+            if rest.is_empty() {
+                return Err((block.line, format!("{} block is not closed", block.kind)));
+            }
+
+            let line = self.line(rest);
+            let (after, key) = self.identifier(rest)?;
+            let (after, value) = self.assignment(after, &key)?;
+            block.fields.push(Field { key, value, line });
+            rest = after;
+        }
+    }
+}
+
+// Identifiers are case insensitive, so keys and block kinds come back in
+// lower case. Assignments outside of blocks other than the namespace are
+// dropped, as the specification allows.
+pub fn parse(text: &str) -> Result<TextMap, Error> {
+    let parser = Parser {
+        text,
+        last_line: std::cell::Cell::new((0, 1)),
+    };
+    let mut textmap = TextMap::default();
+    let mut rest = text;
+
+    loop {
+        rest = skip(rest);
+        if rest.is_empty() {
+            break;
+        }
+
+        let line = parser.line(rest);
+        let (after, name) = parser.identifier(rest)?;
+        let after = skip(after);
+        if after.starts_with('=') {
+            let (after, value) = parser.assignment(after, &name)?;
+            if name == "namespace" {
+                match value {
+                    Value::Str(namespace) => {
+                        textmap.namespace = namespace;
+                        textmap.namespace_line = line;
+                    }
+                    _ => return Err(parser.error(rest, "namespace must be a string")),
+                }
+            }
+            rest = after;
+        } else if let Some(after) = after.strip_prefix('{') {
+            let (after, block) = parser.block(after, name, line)?;
+            textmap.blocks.push(block);
+            rest = after;
+        } else {
+            return Err(parser.error(after, format!("expected '=' or '{{' after {}", name)));
+        }
+    }
+
+    if textmap.namespace.is_empty() {
+        return Err((1, String::from("TEXTMAP has no namespace")));
+    }
+
+    Ok(textmap)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field(key: &str, value: Value, line: usize) -> Field {
+        Field {
+            key: key.to_owned(),
+            value,
+            line,
+        }
+    }
+
+    #[test]
+    fn parses_a_minimal_textmap() {
+        let textmap = parse(
+            "namespace = \"doom\";\n\
+             vertex { x = 64.0; y = -32; }\n\
+             Thing\n{\n  TYPE = 0x1;\n  ambush = true;\n  flags = 010;\n}\n",
+        )
+        .unwrap();
+
+        assert_eq!(textmap.namespace, "doom");
+        assert_eq!(textmap.namespace_line, 1);
+        assert_eq!(
+            textmap.blocks,
+            [
+                Block {
+                    kind: String::from("vertex"),
+                    line: 2,
+                    fields: vec![
+                        field("x", Value::Float(64.0), 2),
+                        field("y", Value::Int(-32), 2),
+                    ],
+                },
+                Block {
+                    kind: String::from("thing"),
+                    line: 3,
+                    fields: vec![
+                        field("type", Value::Int(1), 5),
+                        field("ambush", Value::Bool(true), 6),
+                        field("flags", Value::Int(8), 7),
+                    ],
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn skips_comments() {
+        let textmap = parse(
+            "// a map\n\
+             namespace /* the */ = \"zdoom\"; // namespace\n\
+             /* a\n   long\n   comment */\n\
+             sector { texturefloor = \"FLAT1\"; // floor\n}\n",
+        )
+        .unwrap();
+
+        assert_eq!(textmap.namespace, "zdoom");
+        assert_eq!(textmap.blocks.len(), 1);
+        assert_eq!(textmap.blocks[0].line, 6);
+        assert_eq!(
+            textmap.blocks[0].fields,
+            [field("texturefloor", Value::Str(String::from("FLAT1")), 6)]
+        );
+    }
+
+    #[test]
+    fn unescapes_strings() {
+        let textmap =
+            parse("namespace = \"doom\";\nsidedef { comment = \"a \\\"b\\\" \\\\c\"; }").unwrap();
+        assert_eq!(
+            textmap.blocks[0].fields[0].value,
+            Value::Str(String::from("a \"b\" \\c"))
+        );
+    }
+
+    #[test]
+    fn errors_give_their_line() {
+        let error = |text: &str| parse(text).unwrap_err();
+
+        assert_eq!(
+            error("namespace = \"doom\";\n\nthing\n{\n  x = 1;\n"),
+            (3, String::from("thing block is not closed"))
+        );
+        assert_eq!(
+            error("namespace = \"doom\";\nthing {\n  comment = \"abc;\n}\n"),
+            (3, String::from("unterminated string"))
+        );
+        assert_eq!(
+            error("namespace = \"doom\";\nthing {\n  x = 1;\n  /* y = 2;\n}\n"),
+            (4, String::from("unterminated comment"))
+        );
+        assert_eq!(
+            error("namespace = \"doom\";\n/* thing {\n}\n"),
+            (2, String::from("unterminated comment"))
+        );
+        assert_eq!(
+            error("namespace = \"doom\";\nthing {\n  x = 1\n  y = 2;\n}\n"),
+            (4, String::from("expected ';' after the value of x"))
+        );
+        assert_eq!(
+            error("thing { x = 1; }"),
+            (1, String::from("TEXTMAP has no namespace"))
+        );
+    }
+}
@@ -0,0 +1,214 @@
+use super::error::MapError;
+
+impl super::Map {
+    fn check_index(
+        &self,
+        lump: &'static str,
+        index: usize,
+        field: &'static str,
+        value: usize,
+        limit: usize,
+    ) -> Result<(), MapError> {
+        if value < limit {
+            return Ok(());
+        }
+        Err(MapError::BadReference {
+            map: self.name.clone(),
+            lump,
+            index,
+            field,
+            value,
+            limit,
+        })
+    }
+
+    fn bad_record<S: Into<String>>(&self, lump: &'static str, index: usize, why: S) -> MapError {
+        MapError::BadRecord {
+            map: self.name.clone(),
+            lump,
+            index,
+            why: why.into(),
+        }
+    }
+
+    pub fn validate(&mut self) -> Result<(), MapError> {
+        let num_vertexes = self.vertexes.len();
+        let num_sidedefs = self.sidedefs.len();
+        let num_linedefs = self.linedefs.len();
+        let num_segs = self.segs.len();
+
+        for (i, line) in self.linedefs.iter().enumerate() {
+            self.check_index("LINEDEFS", i, "vertex", line.v1, num_vertexes)?;
+            self.check_index("LINEDEFS", i, "vertex", line.v2, num_vertexes)?;
+            match line.sidenum {
+                [Some(front), back] => {
+                    self.check_index("LINEDEFS", i, "sidedef", front, num_sidedefs)?;
+                    if let Some(back) = back {
+                        self.check_index("LINEDEFS", i, "sidedef", back, num_sidedefs)?;
+                    }
+                }
+                [None, _] => return Err(self.bad_record("LINEDEFS", i, "has no front sidedef")),
+            }
+        }
+
+        for (i, side) in self.sidedefs.iter().enumerate() {
+            self.check_index("SIDEDEFS", i, "sector", side.sector, self.sectors.len())?;
+        }
+
+        for (i, seg) in self.segs.iter().enumerate() {
+            self.check_index("SEGS", i, "vertex", seg.v1, num_vertexes)?;
+            self.check_index("SEGS", i, "vertex", seg.v2, num_vertexes)?;
+            self.check_index("SEGS", i, "linedef", seg.linedef, num_linedefs)?;
+            if seg.side > 1 {
+                return Err(self.bad_record("SEGS", i, format!("has bad side {}", seg.side)));
+            }
+            if self.linedefs[seg.linedef].sidenum[seg.side].is_none() {
+                return Err(self.bad_record(
+                    "SEGS",
+                    i,
+                    format!(
+                        "is on side {} of one-sided linedef {}",
+                        seg.side, seg.linedef
+                    ),
+                ));
+            }
+        }
+
+        for (i, subsector) in self.subsectors.iter().enumerate() {
+            if subsector.num_segs == 0 || subsector.first_seg + subsector.num_segs > num_segs {
+                return Err(self.bad_record(
+                    "SSECTORS",
+                    i,
+                    format!(
+                        "has segs {}..{}, but there are only {}",
+                        subsector.first_seg,
+                        subsector.first_seg + subsector.num_segs,
+                        num_segs
+                    ),
+                ));
+            }
+        }
+
+        for (i, node) in self.nodes.iter().enumerate() {
+            for child in node.children.iter() {
+                match *child {
+                    super::NodeChild::Node(n) => {
+                        self.check_index("NODES", i, "node", n, self.nodes.len())?
+                    }
+                    super::NodeChild::SubSector(s) => {
+                        self.check_index("NODES", i, "subsector", s, self.subsectors.len())?
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::testing::MapLumps;
+    use super::*;
+    use crate::fixed::Fixed;
+
+    fn load_error(lumps: &MapLumps) -> MapError {
+        match lumps.load(&[]) {
+            Ok(_) => panic!("loaded a bad map"),
+            Err(e) => e,
+        }
+    }
+
+    #[test]
+    fn linedef_vertexes_must_exist() {
+        let mut lumps = MapLumps::room(256);
+        lumps.sidedef(0).linedef(0, 9, 0, [4, 0xffff]);
+        match load_error(&lumps) {
+            MapError::BadReference {
+                lump: "LINEDEFS",
+                index: 4,
+                field: "vertex",
+                value: 9,
+                limit: 4,
+                ..
+            } => {}
+            e => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn linedefs_need_a_front_side() {
+        let mut lumps = MapLumps::room(256);
+        lumps.linedef(0, 2, 0, [0xffff, 0]);
+        match load_error(&lumps) {
+            MapError::BadRecord {
+                lump: "LINEDEFS",
+                index: 4,
+                ..
+            } => {}
+            e => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn sidedef_sectors_must_exist() {
+        let mut lumps = MapLumps::room(256);
+        lumps.sidedef(1);
+        match load_error(&lumps) {
+            MapError::BadReference {
+                lump: "SIDEDEFS",
+                index: 4,
+                field: "sector",
+                value: 1,
+                limit: 1,
+                ..
+            } => {}
+            e => panic!("{}", e),
+        }
+    }
+
+    #[test]
+    fn bsp_references_are_checked() {
+        let map = MapLumps::room(256).load(&[]).unwrap();
+        assert!(map.clone().validate().is_ok());
+
+        let mut bad = map.clone();
+        bad.segs[0].side = 1;
+        assert!(matches!(
+            bad.validate(),
+            Err(MapError::BadRecord { lump: "SEGS", .. })
+        ));
+
+        let mut bad = map.clone();
+        bad.subsectors[0].num_segs = bad.segs.len() + 1;
+        assert!(matches!(
+            bad.validate(),
+            Err(MapError::BadRecord {
+                lump: "SSECTORS",
+                ..
+            })
+        ));
+
+        let mut bad = map;
+        bad.nodes.push(super::super::Node {
+            x: Fixed::default(),
+            y: Fixed::default(),
+            dx: Fixed::from_int(1),
+            dy: Fixed::default(),
+            bbox: [[Fixed::default(); 4]; 2],
+            children: [
+                super::super::NodeChild::Node(99),
+                super::super::NodeChild::SubSector(0),
+            ],
+        });
+        assert!(matches!(
+            bad.validate(),
+            Err(MapError::BadReference {
+                lump: "NODES",
+                field: "node",
+                value: 99,
+                ..
+            })
+        ));
+    }
+}
@@ -47,6 +47,18 @@ impl crate::state::State {
         })
     }
 
+    pub fn parm_files(&self, parm: &str) -> Vec<String> {
+        self.check_parm_with_args(parm, 1)
+            .map(|p| {
+                self.args[p + 1..]
+                    .iter()
+                    .take_while(|arg| !arg.starts_with('-'))
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
     pub fn add_loose_files(&mut self) {
         if self.args.len() < 2 {
             return;
@@ -118,7 +130,11 @@ impl crate::state::State {
         if !self.iwad_found && lower.is_iwad_name() {
             self.iwad_found = true;
             FileKind::IWAD
-        } else if lower.ends_with(".wad") || lower.ends_with(".lmp") {
+        } else if lower.ends_with(".wad")
+            || lower.ends_with(".lmp")
+            || lower.ends_with(".pk3")
+            || lower.ends_with(".zip")
+        {
             FileKind::PWAD
         } else if lower.ends_with(".deh") || lower.ends_with(".hhe") || lower.ends_with(".seh") {
             FileKind::DEH
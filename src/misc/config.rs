@@ -23,7 +23,7 @@ impl crate::state::State {
     }
 
     pub fn load_defaults(&mut self) {
-        // TODO bind autoload path here
+        self.bind_string_variable("autoload_path", self.autoload_path.clone());
 
         if let Some(i) = self.check_parm_with_args("--config", 1) {
             self.doom_defaults.file_name = self.args[i + 1].clone();
@@ -48,6 +48,25 @@ impl crate::state::State {
         self.load_default_collection(&self.extra_defaults);
     }
 
+    pub fn get_autoload_dir(&mut self, iwad_name: &str) -> String {
+        let mut autoload_path = self.autoload_path.as_string().unwrap_or_default();
+        if autoload_path.is_empty() {
+            autoload_path = self.config_dir.clone() + "autoload";
+            self.autoload_path.set_string(autoload_path.clone());
+        }
+
+        let dir = std::path::Path::new(&autoload_path).join(iwad_name);
+        std::fs::create_dir_all(&dir).unwrap_or_else(|e| {
+            eprintln!(
+                "Error creating autoload directory '{}': {}",
+                dir.display(),
+                e
+            )
+        });
+
+        dir.to_string_lossy().into_owned()
+    }
+
     pub fn load_default_collection(
         &self,
         collection: &crate::options::defaults::DefaultCollection,
@@ -0,0 +1,107 @@
+pub mod pack;
+
+#[derive(Clone, Debug)]
+pub enum Song {
+    Lump(usize),
+    Substitute(pack::Substitute),
+}
+
+#[derive(Clone, Debug)]
+pub struct PlayingSong {
+    pub music: usize,
+    pub song: Song,
+    pub looping: bool,
+}
+
+#[derive(Default)]
+pub struct Music {
+    pub pack: pack::MusicPack,
+    pub playing: Option<PlayingSong>,
+}
+
+impl crate::state::State {
+    pub fn init_music(&mut self) {
+        let mut music_pack_path = self
+            .sound_options
+            .music_pack_path
+            .as_string()
+            .unwrap_or_default();
+        if music_pack_path.is_empty() {
+            music_pack_path = self.config_dir.clone() + "music-packs";
+            self.sound_options
+                .music_pack_path
+                .set_string(music_pack_path.clone());
+        }
+
+        if let Err(e) = std::fs::create_dir_all(&music_pack_path) {
+            eprintln!(
+                "Error creating music pack directory '{}': {}",
+                music_pack_path, e
+            );
+        }
+
+        self.music.pack = pack::MusicPack::load_dir(&music_pack_path);
+        if !self.music.pack.is_empty() {
+            println!(
+                " music pack: {} substitute tracks from {}",
+                self.music.pack.len(),
+                music_pack_path
+            );
+        }
+    }
+
+    pub fn change_music(&mut self, music: usize, looping: bool) {
+        if music == crate::sounds::MUS_NONE || music >= crate::sounds::NUM_MUSIC {
+            self.error(format!("Bad music number {}", music));
+        }
+
+        if self.music.playing.as_ref().map(|p| p.music) == Some(music) {
+            return;
+        }
+
+        let name = format!("d_{}", self.deh_string(crate::sounds::MUSIC_NAMES[music]));
+        let lump = self.get_num_for_name(&name);
+        let data = self.cache_lump_num(lump);
+
+        let song = match self.music.pack.substitute(&data) {
+            Some(substitute) => Song::Substitute(substitute),
+            None => Song::Lump(lump),
+        };
+
+        self.music.playing = Some(PlayingSong {
+            music,
+            song,
+            looping,
+        });
+    }
+
+    pub fn stop_music(&mut self) {
+        self.music.playing = None;
+    }
+
+    pub fn dump_subst_config(&self, file_name: &str) -> ! {
+        let mut config = format!(
+            "# Example {} substitute MIDI file.\n\n\
+             # SHA1 hash                              = filename\n",
+            crate::meta::PACKAGE_NAME
+        );
+
+        for (lump, info) in self.wad.lumps.iter().enumerate() {
+            let data = match self.wad.lump_data(lump) {
+                Ok(data) if pack::is_music_lump(&data) => data,
+                _ => continue,
+            };
+
+            let digest = crate::sha1::digest(&data);
+            config += &format!("{} = {}.ogg\n", crate::sha1::to_hex(&digest), info.name());
+        }
+        config += "\n";
+
+        std::fs::write(file_name, config).unwrap_or_else(|e| {
+            self.error(format!("Failed to write {}: {}", file_name, e));
+        });
+        println!("Substitute MIDI config file written to {}.", file_name);
+
+        std::process::exit(0);
+    }
+}
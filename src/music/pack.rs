@@ -0,0 +1,479 @@
+use std::io::Read;
+use std::io::Seek;
+use std::io::SeekFrom;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct LoopPoints {
+    pub sample_rate: u32,
+    pub start: Option<u64>,
+    pub end: Option<u64>,
+}
+
+impl LoopPoints {
+    pub fn start_seconds(&self) -> Option<f64> {
+        self.seconds(self.start)
+    }
+
+    pub fn end_seconds(&self) -> Option<f64> {
+        self.seconds(self.end)
+    }
+
+    fn seconds(&self, samples: Option<u64>) -> Option<f64> {
+        if self.sample_rate == 0 {
+            return None;
+        }
+        samples.map(|s| s as f64 / self.sample_rate as f64)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Substitute {
+    pub path: String,
+    pub loop_points: LoopPoints,
+}
+
+#[derive(Default)]
+pub struct MusicPack {
+    // Hashes may be given as a prefix of the full SHA-1, as in Chocolate's
+    // configs; the first match wins. Paths only; a track's loop points are
+    // read when it is played.
+    substitutes: Vec<(String, String)>,
+}
+
+impl MusicPack {
+    pub fn load_dir(dir: &str) -> Self {
+        let mut pack = Self::default();
+
+        for path in crate::wad::main::autoload_files(dir, &["cfg"]) {
+            if let Err(e) = pack.load_config(&path) {
+                eprintln!(" couldn't read music pack config {}: {}", path, e);
+            }
+        }
+
+        pack
+    }
+
+    pub fn load_config(&mut self, path: &str) -> std::io::Result<()> {
+        let text = std::fs::read_to_string(path)?;
+        let dir = std::path::Path::new(path)
+            .parent()
+            .unwrap_or_else(|| std::path::Path::new(""));
+
+        for (line_number, line) in text.lines().enumerate() {
+            let line = strip_comment(line).trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            let (hash, file_name) = match line.find('=') {
+                Some(equals) => (line[..equals].trim(), line[equals + 1..].trim()),
+                None => {
+                    eprintln!("{}:{}: failed to parse '{}'", path, line_number + 1, line);
+                    continue;
+                }
+            };
+            if hash.is_empty() || !hash.chars().all(|c| c.is_ascii_hexdigit()) {
+                eprintln!("{}:{}: invalid hash '{}'", path, line_number + 1, hash);
+                continue;
+            }
+
+            let file_path = dir.join(file_name);
+            if !file_path.is_file() {
+                eprintln!(
+                    "{}:{}: {} does not exist",
+                    path,
+                    line_number + 1,
+                    file_path.display()
+                );
+                continue;
+            }
+
+            self.substitutes.push((
+                hash.to_lowercase(),
+                file_path.to_string_lossy().into_owned(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.substitutes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.substitutes.is_empty()
+    }
+
+    pub fn substitute(&self, data: &[u8]) -> Option<Substitute> {
+        let hash = crate::sha1::to_hex(&crate::sha1::digest(data));
+
+        self.substitutes
+            .iter()
+            .find(|(prefix, _)| hash.starts_with(prefix.as_str()))
+            .map(|(_, path)| Substitute {
+                path: path.clone(),
+                loop_points: read_loop_points(std::path::Path::new(path)),
+            })
+    }
+}
+
+// A '#' starts a comment at the start of a line or after whitespace, so file
+// names can still contain one.
+fn strip_comment(line: &str) -> &str {
+    let bytes = line.as_bytes();
+    let comment = (0..bytes.len())
+        .find(|&i| bytes[i] == b'#' && (i == 0 || bytes[i - 1].is_ascii_whitespace()));
+    match comment {
+        Some(i) => &line[..i],
+        None => line,
+    }
+}
+
+pub fn is_music_lump(data: &[u8]) -> bool {
+    data.starts_with(b"MUS\x1a") || data.starts_with(b"MThd")
+}
+
+// Loop points come from LOOP_START and LOOP_END Vorbis comments, counted in
+// samples, which both Ogg Vorbis and FLAC files carry. Only the headers are
+// read, not the whole track.
+fn read_loop_points(path: &std::path::Path) -> LoopPoints {
+    let mut loop_points = LoopPoints::default();
+    let mut reader = match std::fs::File::open(path) {
+        Ok(file) => std::io::BufReader::new(file),
+        Err(_) => return loop_points,
+    };
+
+    let mut magic = [0; 4];
+    if reader.read_exact(&mut magic).is_err() {
+        return loop_points;
+    }
+    match &magic {
+        b"fLaC" => read_flac_metadata(&mut reader, &mut loop_points),
+        b"OggS" => read_ogg_metadata(&mut (&magic[..]).chain(reader), &mut loop_points),
+        _ => {}
+    }
+    loop_points
+}
+
+// The metadata blocks after the "fLaC" marker. Blocks other than the stream
+// info and comments, such as pictures, are skipped over unread.
+fn read_flac_metadata(reader: &mut (impl Read + Seek), loop_points: &mut LoopPoints) {
+    loop {
+        let mut header = [0; 4];
+        if reader.read_exact(&mut header).is_err() {
+            return;
+        }
+        let last = header[0] & 0x80 != 0;
+        let block_type = header[0] & 0x7f;
+        let length = u32::from_be_bytes([0, header[1], header[2], header[3]]);
+
+        if block_type == 0 || block_type == 4 {
+            let mut block = vec![0; length as usize];
+            if reader.read_exact(&mut block).is_err() {
+                return;
+            }
+            if block_type == 4 {
+                read_vorbis_comments(&block, loop_points);
+            } else if block.len() >= 13 {
+                loop_points.sample_rate =
+                    (block[10] as u32) << 12 | (block[11] as u32) << 4 | (block[12] as u32) >> 4;
+            }
+        } else if reader.seek(SeekFrom::Current(length as i64)).is_err() {
+            return;
+        }
+
+        if last {
+            return;
+        }
+    }
+}
+
+// The identification and comment headers are the first two packets.
+fn read_ogg_metadata(reader: &mut impl Read, loop_points: &mut LoopPoints) {
+    for packet in ogg_packets(reader).take(2) {
+        if packet.starts_with(b"\x01vorbis") && packet.len() >= 16 {
+            loop_points.sample_rate =
+                u32::from_le_bytes([packet[12], packet[13], packet[14], packet[15]]);
+        } else if packet.starts_with(b"\x03vorbis") {
+            read_vorbis_comments(&packet[7..], loop_points);
+        }
+    }
+}
+
+fn ogg_packets(reader: &mut impl Read) -> impl Iterator<Item = Vec<u8>> + '_ {
+    let mut packet = vec![];
+    let mut pending = std::collections::VecDeque::new();
+
+    std::iter::from_fn(move || loop {
+        if let Some(p) = pending.pop_front() {
+            return Some(p);
+        }
+
+        let mut header = [0; 27];
+        reader.read_exact(&mut header).ok()?;
+        if !header.starts_with(b"OggS") {
+            return None;
+        }
+        let mut lacing = vec![0; header[26] as usize];
+        reader.read_exact(&mut lacing).ok()?;
+
+        for &lace in &lacing {
+            let start = packet.len();
+            packet.resize(start + lace as usize, 0);
+            reader.read_exact(&mut packet[start..]).ok()?;
+            if lace < 255 {
+                pending.push_back(std::mem::take(&mut packet));
+            }
+        }
+    })
+}
+
+fn read_vorbis_comments(mut data: &[u8], loop_points: &mut LoopPoints) {
+    // The vendor string comes first.
+    if take_string(&mut data).is_none() {
+        return;
+    }
+    let count = match take_u32(&mut data) {
+        Some(count) => count,
+        None => return,
+    };
+
+    for _ in 0..count {
+        let comment = match take_string(&mut data) {
+            Some(comment) => comment,
+            None => return,
+        };
+        let (key, value) = match comment.find('=') {
+            Some(equals) => (&comment[..equals], comment[equals + 1..].trim()),
+            None => continue,
+        };
+
+        if key.eq_ignore_ascii_case("LOOP_START") {
+            loop_points.start = value.parse().ok();
+        } else if key.eq_ignore_ascii_case("LOOP_END") {
+            loop_points.end = value.parse().ok();
+        }
+    }
+}
+
+fn take_u32(data: &mut &[u8]) -> Option<u32> {
+    let value = data.get(..4)?;
+    let value = u32::from_le_bytes([value[0], value[1], value[2], value[3]]);
+    *data = &data[4..];
+    Some(value)
+}
+
+fn take_string(data: &mut &[u8]) -> Option<String> {
+    let length = take_u32(data)? as usize;
+    let value = data.get(..length)?;
+    *data = &data[length..];
+    Some(String::from_utf8_lossy(value).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wad::testing::TempDir;
+
+    fn vorbis_comments(comments: &[&str]) -> Vec<u8> {
+        let mut data = vec![];
+        let vendor = "test vendor";
+        data.extend_from_slice(&(vendor.len() as u32).to_le_bytes());
+        data.extend_from_slice(vendor.as_bytes());
+        data.extend_from_slice(&(comments.len() as u32).to_le_bytes());
+        for comment in comments {
+            data.extend_from_slice(&(comment.len() as u32).to_le_bytes());
+            data.extend_from_slice(comment.as_bytes());
+        }
+        data
+    }
+
+    fn flac(comments: &[&str]) -> Vec<u8> {
+        let mut data = b"fLaC".to_vec();
+
+        // STREAMINFO, with 44100 Hz in bits 80 to 99.
+        let mut stream_info = [0u8; 34];
+        stream_info[10] = (44100 >> 12) as u8;
+        stream_info[11] = (44100 >> 4) as u8;
+        stream_info[12] = ((44100 & 0xf) << 4) as u8;
+        data.extend_from_slice(&[0, 0, 0, 34]);
+        data.extend_from_slice(&stream_info);
+
+        // A picture, which is skipped.
+        data.extend_from_slice(&[6, 0, 1, 0]);
+        data.extend_from_slice(&[0xff; 256]);
+
+        let block = vorbis_comments(comments);
+        let length = (block.len() as u32).to_be_bytes();
+        data.extend_from_slice(&[0x84, length[1], length[2], length[3]]);
+        data.extend_from_slice(&block);
+
+        // Audio frames, which are never read.
+        data.extend_from_slice(&[0xff, 0xf8, 0, 0]);
+        data
+    }
+
+    // Packets laced into pages of at most this many segments, so that long
+    // packets carry on across pages.
+    fn ogg(packets: &[Vec<u8>], max_segments: usize) -> Vec<u8> {
+        let mut laces = vec![];
+        for packet in packets {
+            let mut rest = &packet[..];
+            loop {
+                let lace = rest.len().min(255);
+                laces.push((lace as u8, &rest[..lace]));
+                rest = &rest[lace..];
+                if lace < 255 {
+                    break;
+                }
+            }
+        }
+
+        let mut data = vec![];
+        for page in laces.chunks(max_segments) {
+            data.extend_from_slice(b"OggS\0\0");
+            data.extend_from_slice(&[0; 20]);
+            data.push(page.len() as u8);
+            data.extend(page.iter().map(|&(lace, _)| lace));
+            for (_, bytes) in page {
+                data.extend_from_slice(bytes);
+            }
+        }
+        data
+    }
+
+    fn ogg_vorbis(comments: &[&str], max_segments: usize) -> Vec<u8> {
+        let mut identification = b"\x01vorbis".to_vec();
+        identification.extend_from_slice(&0u32.to_le_bytes());
+        identification.push(2);
+        identification.extend_from_slice(&22050u32.to_le_bytes());
+        identification.extend_from_slice(&[0; 14]);
+
+        let mut comment = b"\x03vorbis".to_vec();
+        comment.extend_from_slice(&vorbis_comments(comments));
+        comment.push(1);
+
+        ogg(&[identification, comment, vec![0; 10]], max_segments)
+    }
+
+    fn loop_points(dir: &TempDir, name: &str, data: &[u8]) -> LoopPoints {
+        let path = dir.join(name);
+        std::fs::write(&path, data).unwrap();
+        read_loop_points(&path)
+    }
+
+    #[test]
+    fn reads_flac_loop_points() {
+        let dir = TempDir::create();
+        let data = flac(&["TITLE=E1M1", "loop_start=1000", "LOOP_END= 88200 "]);
+        let loop_points = loop_points(&dir, "track.flac", &data);
+
+        assert_eq!(
+            loop_points,
+            LoopPoints {
+                sample_rate: 44100,
+                start: Some(1000),
+                end: Some(88200),
+            }
+        );
+        assert_eq!(loop_points.end_seconds(), Some(2.0));
+    }
+
+    #[test]
+    fn reads_ogg_loop_points_across_pages() {
+        let dir = TempDir::create();
+        let padding = format!("COMMENT={}", "x".repeat(600));
+        let comments = [padding.as_str(), "LOOP_START=22050"];
+
+        for &max_segments in &[1, 3, 255] {
+            let data = ogg_vorbis(&comments, max_segments);
+            let loop_points = loop_points(&dir, "track.ogg", &data);
+            assert_eq!(
+                loop_points,
+                LoopPoints {
+                    sample_rate: 22050,
+                    start: Some(22050),
+                    end: None,
+                }
+            );
+            assert_eq!(loop_points.start_seconds(), Some(1.0));
+        }
+    }
+
+    #[test]
+    fn other_files_have_no_loop_points() {
+        let dir = TempDir::create();
+        assert_eq!(
+            loop_points(&dir, "track.wav", b"RIFF"),
+            LoopPoints::default()
+        );
+        assert_eq!(loop_points(&dir, "short.ogg", b"Og"), LoopPoints::default());
+
+        // A truncated comment block keeps what was read before it.
+        let mut data = flac(&["LOOP_START=5"]);
+        data.truncate(data.len() - 10);
+        let loop_points = loop_points(&dir, "cut.flac", &data);
+        assert_eq!((loop_points.sample_rate, loop_points.start), (44100, None));
+    }
+
+    #[test]
+    fn comments_need_whitespace_before_them() {
+        assert_eq!(strip_comment("# a comment"), "");
+        assert_eq!(
+            strip_comment("abc = track.ogg # comment"),
+            "abc = track.ogg "
+        );
+        assert_eq!(strip_comment("abc = track#2.ogg"), "abc = track#2.ogg");
+        assert_eq!(
+            strip_comment("abc = track#2.ogg\t#2"),
+            "abc = track#2.ogg\t"
+        );
+    }
+
+    #[test]
+    fn loads_substitutes_from_a_config() {
+        let dir = TempDir::create();
+        let lump = b"MUS\x1a some music";
+        let hash = crate::sha1::to_hex(&crate::sha1::digest(lump));
+        std::fs::write(dir.join("e1m1#2.ogg"), ogg_vorbis(&["LOOP_START=10"], 255)).unwrap();
+        std::fs::write(dir.join("other.flac"), flac(&[])).unwrap();
+
+        let config = format!(
+            "# Music pack\n\
+             \n\
+             {} = e1m1#2.ogg   # the first level\n\
+             0123456789abcdef = other.flac\n\
+             nothex = other.flac\n\
+             abcdef = missing.ogg\n\
+             no equals sign\n",
+            hash[..8].to_uppercase()
+        );
+        let config_path = dir.join("pack.cfg");
+        std::fs::write(&config_path, config).unwrap();
+
+        let mut pack = MusicPack::default();
+        pack.load_config(config_path.to_str().unwrap()).unwrap();
+        assert_eq!(pack.len(), 2);
+
+        let substitute = pack.substitute(lump).unwrap();
+        assert_eq!(
+            std::path::Path::new(&substitute.path),
+            dir.join("e1m1#2.ogg")
+        );
+        assert_eq!(substitute.loop_points.start, Some(10));
+        assert!(pack.substitute(b"MUS\x1a other music").is_none());
+    }
+
+    #[test]
+    fn loads_every_config_in_a_directory() {
+        let dir = TempDir::create();
+        std::fs::write(dir.join("track.ogg"), b"").unwrap();
+        std::fs::write(dir.join("a.cfg"), "aaaa = track.ogg\n").unwrap();
+        std::fs::write(dir.join("b.CFG"), "bbbb = track.ogg\n").unwrap();
+        std::fs::write(dir.join("c.txt"), "cccc = track.ogg\n").unwrap();
+
+        let pack = MusicPack::load_dir(dir.path.to_str().unwrap());
+        assert_eq!(pack.len(), 2);
+    }
+}
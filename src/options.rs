@@ -42,11 +42,19 @@ impl Default for JoystickOptions {
     }
 }
 
-pub struct SoundOptions {}
+pub struct SoundOptions {
+    pub snd_cachesize: defaults::DefaultLocation,
+    pub music_pack_path: defaults::DefaultLocation,
+}
 
 impl Default for SoundOptions {
     fn default() -> Self {
-        Self {}
+        Self {
+            snd_cachesize: defaults::DefaultLocation::new_int(
+                crate::wad::cache::DEFAULT_SOUND_BUDGET as i32,
+            ),
+            music_pack_path: defaults::DefaultLocation::new_string(""),
+        }
     }
 }
 
@@ -120,6 +128,11 @@ impl crate::state::State {
         assert!(variable.read().is_int());
         variable.write().location = location;
     }
+    pub fn bind_string_variable(&mut self, name: &str, location: defaults::DefaultLocation) {
+        let variable = self.get_default_for_name(name);
+        assert!(variable.read().is_string());
+        variable.write().location = location;
+    }
 
     pub fn bind_input_variables(&mut self) {
         self.bind_float_variable(
@@ -141,7 +154,13 @@ impl crate::state::State {
 
     pub fn bind_joystick_variables(&mut self) {}
 
-    pub fn bind_sound_variables(&mut self) {}
+    pub fn bind_sound_variables(&mut self) {
+        self.bind_int_variable("snd_cachesize", self.sound_options.snd_cachesize.clone());
+        self.bind_string_variable(
+            "music_pack_path",
+            self.sound_options.music_pack_path.clone(),
+        );
+    }
 
     pub fn bind_base_controls(&mut self) {}
 
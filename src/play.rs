@@ -0,0 +1,216 @@
+pub mod mobj;
+pub mod thinker;
+
+use crate::defs::MAX_PLAYERS;
+use crate::map::{Map, Thing, MTF_EASY, MTF_HARD};
+use crate::random::PlayRandom;
+use thinker::{Thinker, ThinkerId, Thinkers};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Skill {
+    Baby,
+    Easy,
+    Medium,
+    Hard,
+    Nightmare,
+}
+
+impl Skill {
+    // Skills are numbered from 1 on the command line.
+    pub fn from_number(n: i32) -> Option<Self> {
+        match n {
+            1 => Some(Skill::Baby),
+            2 => Some(Skill::Easy),
+            3 => Some(Skill::Medium),
+            4 => Some(Skill::Hard),
+            5 => Some(Skill::Nightmare),
+            _ => None,
+        }
+    }
+
+    // The THINGS flag for things that appear on this skill; the easiest and
+    // hardest two skills share theirs.
+    pub fn thing_flag(self) -> i16 {
+        match self {
+            Skill::Baby => MTF_EASY,
+            Skill::Nightmare => MTF_HARD,
+            skill => 1 << (skill as i16 - 1),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct GameOptions {
+    pub skill: Skill,
+    pub commercial: bool,
+    pub netgame: bool,
+    pub deathmatch: bool,
+    pub no_monsters: bool,
+    pub players_in_game: [bool; MAX_PLAYERS],
+}
+
+// Everything the playsim works on. It is handed the playsim random stream
+// and never the menu one.
+pub struct Level {
+    pub map: Map,
+    pub options: GameOptions,
+    pub thinkers: Thinkers,
+    pub players: [Option<ThinkerId>; MAX_PLAYERS],
+    pub player_starts: [Option<Thing>; MAX_PLAYERS],
+    pub deathmatch_starts: Vec<Thing>,
+    pub total_kills: usize,
+    pub total_items: usize,
+    pub level_time: u32,
+}
+
+impl Level {
+    // P_SetupLevel, as far as there is a playsim to set up.
+    pub fn new(
+        map: Map,
+        options: GameOptions,
+        info: &crate::info::Info,
+        random: &mut PlayRandom,
+    ) -> Result<Self, String> {
+        let mut level = Level {
+            map,
+            options,
+            thinkers: Thinkers::default(),
+            players: [None; MAX_PLAYERS],
+            player_starts: [None; MAX_PLAYERS],
+            deathmatch_starts: vec![],
+            total_kills: 0,
+            total_items: 0,
+            level_time: 0,
+        };
+        level.spawn_things(info, random)?;
+
+        if options.deathmatch {
+            for player in 0..MAX_PLAYERS {
+                if options.players_in_game[player] {
+                    level.deathmatch_spawn_player(player, info, random)?;
+                }
+            }
+        }
+
+        Ok(level)
+    }
+
+    // G_DeathMatchSpawnPlayer. Spots aren't checked for other players until
+    // there is collision code, so the first one picked is used.
+    fn deathmatch_spawn_player(
+        &mut self,
+        player: usize,
+        info: &crate::info::Info,
+        random: &mut PlayRandom,
+    ) -> Result<(), String> {
+        let selections = self.deathmatch_starts.len();
+        if selections < 4 {
+            return Err(format!("Only {} deathmatch spots, 4 required", selections));
+        }
+
+        let start = &mut self.deathmatch_starts[random.random() as usize % selections];
+        start.kind = player as i16 + 1;
+        let start = *start;
+        self.spawn_player(&start, info, random);
+        Ok(())
+    }
+
+    // P_RunThinkers. Removed thinkers are unlinked as the run reaches them,
+    // and the next thinker is found after each one runs.
+    pub fn run_thinkers(&mut self, info: &crate::info::Info) {
+        self.run_thinkers_with(|level, id| match level.thinkers.get(id) {
+            Some(Thinker::Mobj(_)) => level.mobj_thinker(id, info),
+            None => {}
+        });
+    }
+
+    fn run_thinkers_with(&mut self, mut think: impl FnMut(&mut Level, ThinkerId)) {
+        let mut current = self.thinkers.first();
+        while let Some(id) = current {
+            if self.thinkers.is_removed(id) {
+                current = self.thinkers.unlink_removed(id);
+                continue;
+            }
+
+            think(self, id);
+            current = self.thinkers.next(id);
+        }
+    }
+
+    // P_Ticker.
+    pub fn ticker(&mut self, info: &crate::info::Info) {
+        self.run_thinkers(info);
+        self.level_time += 1;
+    }
+}
+
+impl crate::state::State {
+    // G_InitNew and G_DoLoadLevel, for a single player game.
+    pub fn init_new(&mut self, skill: Skill, map: &str) {
+        self.random.clear();
+
+        let options = GameOptions {
+            skill,
+            commercial: self.game_mode == crate::doom::iwad::GameMode::Commercial,
+            netgame: false,
+            deathmatch: self.multiplayer_mode != crate::types::MultiplayerMode::CoOp,
+            no_monsters: self.no_monsters,
+            players_in_game: [true, false, false, false],
+        };
+        let map = self.load_map(map);
+        let level = Level::new(map, options, &self.info, &mut self.random.play)
+            .unwrap_or_else(|e| self.error(e));
+
+        if self.dev_parm {
+            println!(
+                "{}: {} things spawned, {} kills, {} items",
+                level.map.name,
+                level.thinkers.iter().count(),
+                level.total_kills,
+                level.total_items
+            );
+        }
+        self.level = Some(level);
+    }
+
+    // The map -warp asks for, by episode and map or by map alone.
+    pub fn warp_map(&self, p: usize) -> String {
+        let number = |i: usize| {
+            self.args
+                .get(i)
+                .and_then(|a| a.parse::<u32>().ok())
+                .unwrap_or(1)
+        };
+
+        if self.game_mode == crate::doom::iwad::GameMode::Commercial {
+            format!("MAP{:02}", number(p + 1))
+        } else {
+            format!("E{}M{}", number(p + 1), number(p + 2))
+        }
+    }
+}
+
+// A single player game on a map built for a test.
+#[cfg(test)]
+pub fn test_level(
+    lumps: &crate::map::testing::MapLumps,
+    options: GameOptions,
+    random: &mut PlayRandom,
+) -> Result<Level, String> {
+    let map = lumps.load(&[]).unwrap();
+    Level::new(map, options, &crate::info::Info::default(), random)
+}
+
+#[cfg(test)]
+impl GameOptions {
+    pub fn single_player(skill: Skill) -> Self {
+        GameOptions {
+            skill,
+            commercial: true,
+            netgame: false,
+            deathmatch: false,
+            no_monsters: false,
+            players_in_game: [true, false, false, false],
+        }
+    }
+}
@@ -0,0 +1,425 @@
+use super::thinker::{Thinker, ThinkerId};
+use crate::angle::Angle;
+use crate::fixed::Fixed;
+use crate::info::{Info, MF_AMBUSH, MF_COUNTITEM, MF_COUNTKILL, MF_NOTDMATCH, MF_SPAWNCEILING};
+use crate::map::{Thing, MTF_AMBUSH, MTF_NOTSINGLE};
+use crate::random::PlayRandom;
+
+pub const ONFLOORZ: Fixed = Fixed::MIN;
+pub const ONCEILINGZ: Fixed = Fixed::MAX;
+
+// Monsters only in Doom II. In the other games vanilla stops loading things
+// at the first of these, and the things after it go missing too.
+const DOOM2_ONLY_THINGS: [i16; 10] = [68, 64, 88, 89, 69, 67, 71, 65, 66, 84];
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Mobj {
+    pub x: Fixed,
+    pub y: Fixed,
+    pub z: Fixed,
+    pub angle: Angle,
+    pub sprite: usize,
+    pub frame: i32,
+
+    pub subsector: usize,
+    pub floor_z: Fixed,
+    pub ceiling_z: Fixed,
+    pub radius: Fixed,
+    pub height: Fixed,
+
+    pub mom_x: Fixed,
+    pub mom_y: Fixed,
+    pub mom_z: Fixed,
+
+    pub kind: usize,
+    pub tics: i32,
+    pub state: usize,
+    pub flags: i32,
+    pub health: i32,
+
+    pub move_dir: i32,
+    pub move_count: i32,
+    pub target: Option<ThinkerId>,
+    pub tracer: Option<ThinkerId>,
+    pub reaction_time: i32,
+    pub threshold: i32,
+
+    pub player: Option<usize>,
+    pub last_look: i32,
+    pub spawn_point: Thing,
+}
+
+impl super::Level {
+    pub fn mobj(&self, id: ThinkerId) -> Option<&Mobj> {
+        match self.thinkers.get(id) {
+            Some(Thinker::Mobj(mobj)) => Some(mobj),
+            None => None,
+        }
+    }
+
+    pub fn mobj_mut(&mut self, id: ThinkerId) -> Option<&mut Mobj> {
+        match self.thinkers.get_mut(id) {
+            Some(Thinker::Mobj(mobj)) => Some(mobj),
+            None => None,
+        }
+    }
+
+    // P_SpawnMobj. Things aren't linked into sectors and blocks yet; only
+    // their subsector is found, for the heights.
+    pub fn spawn_mobj(
+        &mut self,
+        x: Fixed,
+        y: Fixed,
+        z: Fixed,
+        kind: usize,
+        info: &Info,
+        random: &mut PlayRandom,
+    ) -> ThinkerId {
+        let mobj_info = &info.mobj_info[kind];
+        let state = &info.states[mobj_info.spawn_state];
+
+        let mut mobj = Mobj {
+            x,
+            y,
+            kind,
+            radius: mobj_info.radius,
+            height: mobj_info.height,
+            flags: mobj_info.flags,
+            health: mobj_info.spawn_health,
+            last_look: random.random() % crate::defs::MAX_PLAYERS as i32,
+            state: mobj_info.spawn_state,
+            tics: state.tics,
+            sprite: state.sprite,
+            frame: state.frame,
+            ..Mobj::default()
+        };
+        if self.options.skill != super::Skill::Nightmare {
+            mobj.reaction_time = mobj_info.reaction_time;
+        }
+
+        mobj.subsector = self.map.point_in_subsector(x, y);
+        let sector = &self.map.sectors[self.map.subsector_sector(mobj.subsector)];
+        mobj.floor_z = sector.floor_height;
+        mobj.ceiling_z = sector.ceiling_height;
+        mobj.z = match z {
+            ONFLOORZ => mobj.floor_z,
+            ONCEILINGZ => mobj.ceiling_z - mobj.height,
+            z => z,
+        };
+
+        self.thinkers.add(Thinker::Mobj(mobj))
+    }
+
+    // P_RemoveMobj.
+    pub fn remove_mobj(&mut self, id: ThinkerId) {
+        self.thinkers.remove(id);
+    }
+
+    // P_SetMobjState: goes through any zero tic states straight away. State
+    // actions are not called yet. Returns false if the mobj was removed.
+    pub fn set_mobj_state(&mut self, id: ThinkerId, mut state: usize, info: &Info) -> bool {
+        loop {
+            if state == crate::info::S_NULL {
+                if let Some(mobj) = self.mobj_mut(id) {
+                    mobj.state = state;
+                }
+                self.remove_mobj(id);
+                return false;
+            }
+
+            let st = &info.states[state];
+            let mobj = match self.mobj_mut(id) {
+                Some(mobj) => mobj,
+                None => return false,
+            };
+            mobj.state = state;
+            mobj.tics = st.tics;
+            mobj.sprite = st.sprite;
+            mobj.frame = st.frame;
+
+            state = st.next_state;
+            if mobj.tics != 0 {
+                return true;
+            }
+        }
+    }
+
+    // P_MobjThinker. Things don't move until there is collision code to move
+    // them with, so for now they only step through their states.
+    pub fn mobj_thinker(&mut self, id: ThinkerId, info: &Info) {
+        let mobj = match self.mobj_mut(id) {
+            Some(mobj) => mobj,
+            None => return,
+        };
+
+        if mobj.tics != -1 {
+            mobj.tics -= 1;
+            if mobj.tics == 0 {
+                let next = info.states[mobj.state].next_state;
+                self.set_mobj_state(id, next, info);
+            }
+        }
+    }
+
+    // P_SpawnPlayer. Player 1 is the only one in a game until there is
+    // networking.
+    pub fn spawn_player(&mut self, thing: &Thing, info: &Info, random: &mut PlayRandom) {
+        let player = (thing.kind - 1) as usize;
+        if !self.options.players_in_game[player] {
+            return;
+        }
+
+        let id = self.spawn_mobj(
+            thing.x,
+            thing.y,
+            ONFLOORZ,
+            crate::info::MT_PLAYER,
+            info,
+            random,
+        );
+        let mobj = self.mobj_mut(id).unwrap();
+        if player > 0 {
+            mobj.flags |= (player as i32) << crate::info::MF_TRANSSHIFT;
+        }
+        mobj.angle = Angle::ANG45 * (thing.angle / 45) as u32;
+        mobj.player = Some(player);
+        self.players[player] = Some(id);
+    }
+
+    // P_SpawnMapThing. Numbers are taken from the playsim stream in the same
+    // order as vanilla's, once by P_SpawnMobj and once for the first tics.
+    pub fn spawn_map_thing(
+        &mut self,
+        thing: &Thing,
+        info: &Info,
+        random: &mut PlayRandom,
+    ) -> Result<(), String> {
+        if thing.kind == 11 {
+            self.deathmatch_starts.push(*thing);
+            return Ok(());
+        }
+        if thing.kind <= 0 {
+            return Ok(());
+        }
+        if thing.kind <= 4 {
+            self.player_starts[(thing.kind - 1) as usize] = Some(*thing);
+            if !self.options.deathmatch {
+                self.spawn_player(thing, info, random);
+            }
+            return Ok(());
+        }
+
+        if !self.options.netgame && thing.options & MTF_NOTSINGLE != 0 {
+            return Ok(());
+        }
+        if thing.options & self.options.skill.thing_flag() == 0 {
+            return Ok(());
+        }
+
+        let kind = info
+            .mobj_info
+            .iter()
+            .position(|m| m.doomed_num == thing.kind as i32)
+            .ok_or_else(|| {
+                format!(
+                    "Unknown thing type {} at ({}, {})",
+                    thing.kind,
+                    thing.x.to_int(),
+                    thing.y.to_int()
+                )
+            })?;
+        let mobj_info = &info.mobj_info[kind];
+
+        if self.options.deathmatch && mobj_info.flags & MF_NOTDMATCH != 0 {
+            return Ok(());
+        }
+        if self.options.no_monsters
+            && (kind == crate::info::MT_SKULL || mobj_info.flags & MF_COUNTKILL != 0)
+        {
+            return Ok(());
+        }
+
+        let z = if mobj_info.flags & MF_SPAWNCEILING != 0 {
+            ONCEILINGZ
+        } else {
+            ONFLOORZ
+        };
+        let id = self.spawn_mobj(thing.x, thing.y, z, kind, info, random);
+
+        let mobj = self.mobj_mut(id).unwrap();
+        mobj.spawn_point = *thing;
+        if mobj.tics > 0 {
+            mobj.tics = 1 + random.random() % mobj.tics;
+        }
+        mobj.angle = Angle::ANG45 * (thing.angle / 45) as u32;
+        if thing.options & MTF_AMBUSH != 0 {
+            mobj.flags |= MF_AMBUSH;
+        }
+
+        let flags = mobj.flags;
+        if flags & MF_COUNTKILL != 0 {
+            self.total_kills += 1;
+        }
+        if flags & MF_COUNTITEM != 0 {
+            self.total_items += 1;
+        }
+        Ok(())
+    }
+
+    // P_LoadThings.
+    pub fn spawn_things(&mut self, info: &Info, random: &mut PlayRandom) -> Result<(), String> {
+        for thing in self.map.things.clone() {
+            if !self.options.commercial && DOOM2_ONLY_THINGS.contains(&thing.kind) {
+                break;
+            }
+            self.spawn_map_thing(&thing, info, random)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::map::testing::MapLumps;
+    use crate::map::{MTF_EASY, MTF_HARD, MTF_NORMAL, MTF_NOTSINGLE};
+    use crate::play::{test_level, GameOptions, Level, Skill};
+    use crate::random::PlayRandom;
+
+    const ALL_SKILLS: i16 = MTF_EASY | MTF_NORMAL | MTF_HARD;
+
+    const ZOMBIEMAN: i16 = 3004;
+    const LOST_SOUL: i16 = 3006;
+    const ARACHNOTRON: i16 = 68;
+    const HEALTH_BONUS: i16 = 2014;
+    const TALL_COLUMN: i16 = 2028;
+
+    // A room with these things in a row, as kind and options.
+    fn level(things: &[(i16, i16)], options: GameOptions) -> Level {
+        let mut lumps = MapLumps::room(1024);
+        for (i, &(kind, flags)) in things.iter().enumerate() {
+            lumps.thing(64 + 64 * i as i16, 64, 0, kind, flags);
+        }
+        test_level(&lumps, options, &mut PlayRandom::default()).unwrap()
+    }
+
+    fn spawned(level: &Level) -> Vec<(i16, i16)> {
+        level
+            .thinkers
+            .mobjs()
+            .map(|(_, mobj)| (mobj.spawn_point.kind, mobj.spawn_point.options))
+            .collect()
+    }
+
+    #[test]
+    fn no_monsters_leaves_out_monsters_and_lost_souls() {
+        let things = [
+            (ZOMBIEMAN, ALL_SKILLS),
+            (LOST_SOUL, ALL_SKILLS),
+            (HEALTH_BONUS, ALL_SKILLS),
+        ];
+        let options = GameOptions::single_player(Skill::Medium);
+
+        let all = level(&things, options);
+        assert_eq!(spawned(&all), things);
+        // Lost souls aren't counted as kills.
+        assert_eq!((all.total_kills, all.total_items), (1, 1));
+
+        let no_monsters = level(
+            &things,
+            GameOptions {
+                no_monsters: true,
+                ..options
+            },
+        );
+        assert_eq!(spawned(&no_monsters), [(HEALTH_BONUS, ALL_SKILLS)]);
+        assert_eq!((no_monsters.total_kills, no_monsters.total_items), (0, 1));
+    }
+
+    #[test]
+    fn things_only_spawn_on_their_skills() {
+        let things = [
+            (HEALTH_BONUS, MTF_EASY),
+            (HEALTH_BONUS, MTF_NORMAL),
+            (HEALTH_BONUS, MTF_HARD),
+            (HEALTH_BONUS, 0),
+        ];
+        let flags_on = |skill: Skill| -> Vec<i16> {
+            let level = level(&things, GameOptions::single_player(skill));
+            spawned(&level).iter().map(|&(_, flags)| flags).collect()
+        };
+        assert_eq!(flags_on(Skill::Baby), [MTF_EASY]);
+        assert_eq!(flags_on(Skill::Easy), [MTF_EASY]);
+        assert_eq!(flags_on(Skill::Medium), [MTF_NORMAL]);
+        assert_eq!(flags_on(Skill::Hard), [MTF_HARD]);
+        assert_eq!(flags_on(Skill::Nightmare), [MTF_HARD]);
+    }
+
+    #[test]
+    fn multiplayer_things_only_spawn_in_netgames() {
+        let things = [
+            (HEALTH_BONUS, ALL_SKILLS | MTF_NOTSINGLE),
+            (HEALTH_BONUS, ALL_SKILLS),
+        ];
+        let options = GameOptions::single_player(Skill::Medium);
+
+        let single = level(&things, options);
+        assert_eq!(spawned(&single), [(HEALTH_BONUS, ALL_SKILLS)]);
+
+        let netgame = level(
+            &things,
+            GameOptions {
+                netgame: true,
+                ..options
+            },
+        );
+        assert_eq!(spawned(&netgame), things);
+    }
+
+    #[test]
+    fn doom2_monsters_stop_the_things_outside_doom2() {
+        let things = [
+            (HEALTH_BONUS, ALL_SKILLS),
+            (ARACHNOTRON, ALL_SKILLS),
+            (HEALTH_BONUS, ALL_SKILLS),
+        ];
+        let options = GameOptions::single_player(Skill::Medium);
+
+        let doom2 = level(&things, options);
+        assert_eq!(spawned(&doom2), things);
+
+        let doom = level(
+            &things,
+            GameOptions {
+                commercial: false,
+                ..options
+            },
+        );
+        assert_eq!(spawned(&doom), [(HEALTH_BONUS, ALL_SKILLS)]);
+    }
+
+    #[test]
+    fn spawning_takes_the_last_look_then_the_first_tics() {
+        let mut expected = PlayRandom::default();
+        let last_look = expected.random() % crate::defs::MAX_PLAYERS as i32;
+        let tics = 1 + expected.random() % 6;
+
+        let level = level(
+            &[(HEALTH_BONUS, ALL_SKILLS)],
+            GameOptions::single_player(Skill::Medium),
+        );
+        let (_, mobj) = level.thinkers.mobjs().next().unwrap();
+        assert_eq!((mobj.last_look, mobj.tics), (last_look, tics));
+    }
+
+    #[test]
+    fn things_that_never_change_state_take_one_number() {
+        let mut random = PlayRandom::default();
+        let mut lumps = MapLumps::room(1024);
+        lumps
+            .thing(64, 64, 0, TALL_COLUMN, ALL_SKILLS)
+            .thing(128, 64, 0, HEALTH_BONUS, ALL_SKILLS);
+        let options = GameOptions::single_player(Skill::Medium);
+        test_level(&lumps, options, &mut random).unwrap();
+        assert_eq!(random.index(), 3);
+    }
+}
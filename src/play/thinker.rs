@@ -0,0 +1,268 @@
+// Slots are reused once a thinker is unlinked, so an id also carries the
+// generation of its slot, and an old id finds nothing rather than whatever
+// took its place.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ThinkerId {
+    index: usize,
+    generation: u32,
+}
+
+#[derive(Debug)]
+pub enum Thinker {
+    Mobj(super::mobj::Mobj),
+}
+
+#[derive(Debug)]
+struct Node {
+    prev: usize,
+    next: usize,
+    removed: bool,
+    generation: u32,
+    thinker: Option<Thinker>,
+}
+
+// Vanilla's circular thinker list, kept in a Vec with the links as indices.
+// Slot 0 is the list head, thinkercap. Thinkers are added at the end and
+// only marked when removed; they are unlinked when a run gets to them, so
+// they keep their place in the order until then.
+#[derive(Debug)]
+pub struct Thinkers {
+    nodes: Vec<Node>,
+    free: Vec<usize>,
+}
+
+const CAP: usize = 0;
+
+impl Default for Thinkers {
+    fn default() -> Self {
+        Self {
+            nodes: vec![Node {
+                prev: CAP,
+                next: CAP,
+                removed: false,
+                generation: 0,
+                thinker: None,
+            }],
+            free: vec![],
+        }
+    }
+}
+
+impl Thinkers {
+    // P_InitThinkers.
+    pub fn clear(&mut self) {
+        *self = Thinkers::default();
+    }
+
+    // P_AddThinker: onto the end, so it runs after everything already there,
+    // in the same pass if one is under way.
+    pub fn add(&mut self, thinker: Thinker) -> ThinkerId {
+        let last = self.nodes[CAP].prev;
+        let index = match self.free.pop() {
+            Some(index) => index,
+            None => {
+                self.nodes.push(Node {
+                    prev: CAP,
+                    next: CAP,
+                    removed: false,
+                    generation: 0,
+                    thinker: None,
+                });
+                self.nodes.len() - 1
+            }
+        };
+        let node = &mut self.nodes[index];
+        node.prev = last;
+        node.next = CAP;
+        node.removed = false;
+        node.thinker = Some(thinker);
+        let generation = node.generation;
+
+        self.nodes[last].next = index;
+        self.nodes[CAP].prev = index;
+        ThinkerId { index, generation }
+    }
+
+    fn node(&self, id: ThinkerId) -> Option<&Node> {
+        self.nodes
+            .get(id.index)
+            .filter(|node| node.generation == id.generation)
+    }
+
+    fn node_mut(&mut self, id: ThinkerId) -> Option<&mut Node> {
+        self.nodes
+            .get_mut(id.index)
+            .filter(|node| node.generation == id.generation)
+    }
+
+    // P_RemoveThinker. Removing a thinker that is already gone does nothing.
+    pub fn remove(&mut self, id: ThinkerId) {
+        if let Some(node) = self.node_mut(id) {
+            node.removed = true;
+        }
+    }
+
+    pub fn is_removed(&self, id: ThinkerId) -> bool {
+        self.node(id).map(|node| node.removed).unwrap_or(true)
+    }
+
+    fn unlink(&mut self, index: usize) {
+        let Node { prev, next, .. } = self.nodes[index];
+        self.nodes[next].prev = prev;
+        self.nodes[prev].next = next;
+        let node = &mut self.nodes[index];
+        node.thinker = None;
+        node.generation = node.generation.wrapping_add(1);
+        self.free.push(index);
+    }
+
+    fn after(&self, index: usize) -> Option<ThinkerId> {
+        Some(self.nodes[index].next)
+            .filter(|&next| next != CAP)
+            .map(|next| ThinkerId {
+                index: next,
+                generation: self.nodes[next].generation,
+            })
+    }
+
+    pub fn first(&self) -> Option<ThinkerId> {
+        self.after(CAP)
+    }
+
+    // The thinker after this one, read as P_RunThinkers does once the current
+    // thinker has run, so it sees anything the thinker added.
+    pub fn next(&self, id: ThinkerId) -> Option<ThinkerId> {
+        self.after(id.index)
+    }
+
+    // Unlinks a thinker that has been removed, giving the one after it.
+    pub fn unlink_removed(&mut self, id: ThinkerId) -> Option<ThinkerId> {
+        let next = self.next(id);
+        self.unlink(id.index);
+        next
+    }
+
+    pub fn get(&self, id: ThinkerId) -> Option<&Thinker> {
+        self.node(id).and_then(|node| node.thinker.as_ref())
+    }
+
+    pub fn get_mut(&mut self, id: ThinkerId) -> Option<&mut Thinker> {
+        self.node_mut(id).and_then(|node| node.thinker.as_mut())
+    }
+
+    // Thinkers in list order, skipping the ones waiting to be unlinked.
+    pub fn iter(&self) -> impl Iterator<Item = (ThinkerId, &Thinker)> + '_ {
+        std::iter::successors(self.first(), move |&id| self.next(id))
+            .filter(move |&id| !self.is_removed(id))
+            .filter_map(move |id| self.get(id).map(|thinker| (id, thinker)))
+    }
+
+    pub fn mobjs(&self) -> impl Iterator<Item = (ThinkerId, &super::mobj::Mobj)> + '_ {
+        self.iter().map(|(id, thinker)| match thinker {
+            Thinker::Mobj(mobj) => (id, mobj),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::map::testing::MapLumps;
+    use crate::play::mobj::Mobj;
+    use crate::play::{test_level, GameOptions, Level, Skill};
+    use crate::random::PlayRandom;
+
+    fn level() -> Level {
+        let options = GameOptions::single_player(Skill::Medium);
+        test_level(&MapLumps::room(256), options, &mut PlayRandom::default()).unwrap()
+    }
+
+    fn mobj(kind: usize) -> Thinker {
+        Thinker::Mobj(Mobj {
+            kind,
+            ..Mobj::default()
+        })
+    }
+
+    fn kinds(thinkers: &Thinkers) -> Vec<usize> {
+        thinkers.mobjs().map(|(_, mobj)| mobj.kind).collect()
+    }
+
+    #[test]
+    fn keeps_the_order_added() {
+        let mut thinkers = Thinkers::default();
+        for kind in 1..=4 {
+            thinkers.add(mobj(kind));
+        }
+        assert_eq!(kinds(&thinkers), [1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn removed_thinkers_keep_their_place_until_run() {
+        let mut level = level();
+        let ids: Vec<_> = (1..=4).map(|kind| level.thinkers.add(mobj(kind))).collect();
+        level.thinkers.remove(ids[1]);
+        assert_eq!(kinds(&level.thinkers), [1, 3, 4]);
+        assert!(level.thinkers.get(ids[1]).is_some());
+
+        level.run_thinkers(&crate::info::Info::default());
+        assert!(level.thinkers.get(ids[1]).is_none());
+
+        // Its slot is reused, but the new thinker still goes on the end.
+        let id = level.thinkers.add(mobj(5));
+        assert_eq!(id.index, ids[1].index);
+        assert_eq!(kinds(&level.thinkers), [1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn old_ids_do_not_find_a_reused_slot() {
+        let mut level = level();
+        let old = level.thinkers.add(mobj(1));
+        level.thinkers.remove(old);
+        level.run_thinkers(&crate::info::Info::default());
+        let new = level.thinkers.add(mobj(2));
+        assert_eq!(new.index, old.index);
+
+        assert!(level.thinkers.get(old).is_none());
+        assert!(level.thinkers.get_mut(old).is_none());
+        assert!(level.thinkers.is_removed(old));
+        level.thinkers.remove(old);
+        assert!(!level.thinkers.is_removed(new));
+        assert_eq!(kinds(&level.thinkers), [2]);
+    }
+
+    #[test]
+    fn thinkers_added_in_a_run_run_in_it() {
+        let mut level = level();
+        level.thinkers.add(mobj(1));
+        level.thinkers.add(mobj(2));
+
+        let mut ran = vec![];
+        level.run_thinkers_with(|level, id| {
+            let Thinker::Mobj(mobj) = level.thinkers.get(id).unwrap();
+            let kind = mobj.kind;
+            ran.push(kind);
+            if kind < 4 {
+                level.thinkers.add(self::mobj(kind + 2));
+            }
+        });
+        assert_eq!(ran, [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn a_thinker_can_remove_itself_and_later_ones() {
+        let mut level = level();
+        let ids: Vec<_> = (1..=4).map(|kind| level.thinkers.add(mobj(kind))).collect();
+
+        let mut ran = vec![];
+        level.run_thinkers_with(|level, id| {
+            ran.push(id);
+            if id == ids[1] {
+                level.thinkers.remove(id);
+                level.thinkers.remove(ids[2]);
+            }
+        });
+        assert_eq!(ran, [ids[0], ids[1], ids[3]]);
+        assert_eq!(kinds(&level.thinkers), [1, 4]);
+    }
+}
@@ -0,0 +1,162 @@
+// Not so random: every number comes from this table, in order, so that
+// demos and netgames play out the same everywhere.
+pub static RNDTABLE: [u8; 256] = [
+    0, 8, 109, 220, 222, 241, 149, 107, 75, 248, 254, 140, 16, 66, 74, 21, 211, 47, 80, 242, 154,
+    27, 205, 128, 161, 89, 77, 36, 95, 110, 85, 48, 212, 140, 211, 249, 22, 79, 200, 50, 28, 188,
+    52, 140, 202, 120, 68, 145, 62, 70, 184, 190, 91, 197, 152, 224, 149, 104, 25, 178, 252, 182,
+    202, 182, 141, 197, 4, 81, 181, 242, 145, 42, 39, 227, 156, 198, 225, 193, 219, 93, 122, 175,
+    249, 0, 175, 143, 70, 239, 46, 246, 163, 53, 163, 109, 168, 135, 2, 235, 25, 92, 20, 145, 138,
+    77, 69, 166, 78, 176, 173, 212, 166, 113, 94, 161, 41, 50, 239, 49, 111, 164, 70, 60, 2, 37,
+    171, 75, 136, 156, 11, 56, 42, 146, 138, 229, 73, 146, 77, 61, 98, 196, 135, 106, 63, 197, 195,
+    86, 96, 203, 113, 101, 170, 247, 181, 113, 80, 250, 108, 7, 255, 237, 129, 226, 79, 107, 112,
+    166, 103, 241, 24, 223, 239, 120, 198, 58, 60, 82, 128, 3, 184, 66, 143, 224, 145, 224, 81,
+    206, 163, 45, 63, 90, 168, 114, 59, 33, 159, 95, 28, 139, 123, 98, 125, 196, 15, 70, 194, 253,
+    54, 14, 109, 226, 71, 17, 161, 93, 186, 87, 244, 138, 20, 52, 123, 251, 26, 36, 17, 46, 52,
+    231, 232, 76, 31, 221, 84, 37, 216, 165, 212, 106, 197, 242, 98, 43, 39, 175, 254, 145, 190,
+    84, 118, 222, 187, 136, 120, 163, 236, 249,
+];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+struct Stream {
+    index: u8,
+}
+
+impl Stream {
+    fn next(&mut self) -> i32 {
+        self.index = self.index.wrapping_add(1);
+        RNDTABLE[self.index as usize] as i32
+    }
+}
+
+// P_Random. Only the playsim takes numbers from it, and it is the only
+// stream the playsim is handed, so nothing else can throw a demo out of
+// sync.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct PlayRandom(Stream);
+
+impl PlayRandom {
+    // Starts the stream at a given point in the table, for tests that want
+    // something other than a new game's sequence.
+    pub fn seeded(seed: u8) -> Self {
+        PlayRandom(Stream { index: seed })
+    }
+
+    pub fn random(&mut self) -> i32 {
+        self.0.next()
+    }
+
+    // P_SubRandom: the difference of two numbers, the first taken first.
+    pub fn sub_random(&mut self) -> i32 {
+        let r = self.random();
+        r - self.random()
+    }
+
+    pub fn index(&self) -> u8 {
+        self.0.index
+    }
+}
+
+// M_Random, for the menus, screen wipes and anything else that doesn't
+// change the game.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct MenuRandom(Stream);
+
+impl MenuRandom {
+    pub fn seeded(seed: u8) -> Self {
+        MenuRandom(Stream { index: seed })
+    }
+
+    pub fn random(&mut self) -> i32 {
+        self.0.next()
+    }
+
+    pub fn index(&self) -> u8 {
+        self.0.index
+    }
+}
+
+// The indices of both streams, as kept in savegames.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct RandomState {
+    pub play: u8,
+    pub menu: u8,
+}
+
+#[derive(Debug, Default)]
+pub struct Random {
+    pub play: PlayRandom,
+    pub menu: MenuRandom,
+}
+
+impl Random {
+    // M_ClearRandom, done when a new game starts.
+    pub fn clear(&mut self) {
+        *self = Random::default();
+    }
+
+    pub fn save(&self) -> RandomState {
+        RandomState {
+            play: self.play.index(),
+            menu: self.menu.index(),
+        }
+    }
+
+    pub fn restore(&mut self, state: RandomState) {
+        self.play = PlayRandom::seeded(state.play);
+        self.menu = MenuRandom::seeded(state.menu);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn starts_after_the_first_entry() {
+        let mut random = Random::default();
+        let numbers: Vec<_> = (0..4).map(|_| random.play.random()).collect();
+        assert_eq!(numbers, [8, 109, 220, 222]);
+        assert_eq!(random.menu.random(), 8);
+    }
+
+    #[test]
+    fn wraps_around_the_table() {
+        let mut random = PlayRandom::seeded(254);
+        assert_eq!(random.random(), 249);
+        assert_eq!(random.random(), 0);
+        assert_eq!(random.random(), 8);
+        assert_eq!(random.index(), 1);
+    }
+
+    #[test]
+    fn streams_are_independent() {
+        let mut random = Random::default();
+        for _ in 0..10 {
+            random.menu.random();
+        }
+        assert_eq!(random.play.random(), 8);
+        assert_eq!(random.save(), RandomState { play: 1, menu: 10 });
+    }
+
+    #[test]
+    fn save_and_restore() {
+        let mut random = Random::default();
+        random.play.random();
+        random.menu.random();
+        random.menu.random();
+        let saved = random.save();
+        let expected: Vec<_> = (0..300).map(|_| random.play.random()).collect();
+
+        random.clear();
+        assert_eq!(random.save(), RandomState::default());
+        random.restore(saved);
+        let numbers: Vec<_> = (0..300).map(|_| random.play.random()).collect();
+        assert_eq!(numbers, expected);
+        assert_eq!(random.menu.random(), 220);
+    }
+
+    #[test]
+    fn sub_random_takes_the_first_number_first() {
+        assert_eq!(PlayRandom::default().sub_random(), 8 - 109);
+    }
+}
@@ -0,0 +1,332 @@
+#[derive(Clone, Debug)]
+pub struct SfxInfo {
+    pub name: &'static str,
+    pub singularity: bool,
+    pub priority: i32,
+    pub link: Option<usize>,
+    pub pitch: i32,
+    pub volume: i32,
+}
+
+const fn sound(name: &'static str, singularity: bool, priority: i32) -> SfxInfo {
+    SfxInfo {
+        name,
+        singularity,
+        priority,
+        link: None,
+        pitch: -1,
+        volume: -1,
+    }
+}
+
+pub const NUM_SFX: usize = 109;
+
+pub const SFX_NONE: usize = 0;
+pub const SFX_PISTOL: usize = 1;
+pub const SFX_SHOTGN: usize = 2;
+pub const SFX_SGCOCK: usize = 3;
+pub const SFX_DSHTGN: usize = 4;
+pub const SFX_DBOPN: usize = 5;
+pub const SFX_DBCLS: usize = 6;
+pub const SFX_DBLOAD: usize = 7;
+pub const SFX_PLASMA: usize = 8;
+pub const SFX_BFG: usize = 9;
+pub const SFX_SAWUP: usize = 10;
+pub const SFX_SAWIDL: usize = 11;
+pub const SFX_SAWFUL: usize = 12;
+pub const SFX_SAWHIT: usize = 13;
+pub const SFX_RLAUNC: usize = 14;
+pub const SFX_RXPLOD: usize = 15;
+pub const SFX_FIRSHT: usize = 16;
+pub const SFX_FIRXPL: usize = 17;
+pub const SFX_PSTART: usize = 18;
+pub const SFX_PSTOP: usize = 19;
+pub const SFX_DOROPN: usize = 20;
+pub const SFX_DORCLS: usize = 21;
+pub const SFX_STNMOV: usize = 22;
+pub const SFX_SWTCHN: usize = 23;
+pub const SFX_SWTCHX: usize = 24;
+pub const SFX_PLPAIN: usize = 25;
+pub const SFX_DMPAIN: usize = 26;
+pub const SFX_POPAIN: usize = 27;
+pub const SFX_VIPAIN: usize = 28;
+pub const SFX_MNPAIN: usize = 29;
+pub const SFX_PEPAIN: usize = 30;
+pub const SFX_SLOP: usize = 31;
+pub const SFX_ITEMUP: usize = 32;
+pub const SFX_WPNUP: usize = 33;
+pub const SFX_OOF: usize = 34;
+pub const SFX_TELEPT: usize = 35;
+pub const SFX_POSIT1: usize = 36;
+pub const SFX_POSIT2: usize = 37;
+pub const SFX_POSIT3: usize = 38;
+pub const SFX_BGSIT1: usize = 39;
+pub const SFX_BGSIT2: usize = 40;
+pub const SFX_SGTSIT: usize = 41;
+pub const SFX_CACSIT: usize = 42;
+pub const SFX_BRSSIT: usize = 43;
+pub const SFX_CYBSIT: usize = 44;
+pub const SFX_SPISIT: usize = 45;
+pub const SFX_BSPSIT: usize = 46;
+pub const SFX_KNTSIT: usize = 47;
+pub const SFX_VILSIT: usize = 48;
+pub const SFX_MANSIT: usize = 49;
+pub const SFX_PESIT: usize = 50;
+pub const SFX_SKLATK: usize = 51;
+pub const SFX_SGTATK: usize = 52;
+pub const SFX_SKEPCH: usize = 53;
+pub const SFX_VILATK: usize = 54;
+pub const SFX_CLAW: usize = 55;
+pub const SFX_SKESWG: usize = 56;
+pub const SFX_PLDETH: usize = 57;
+pub const SFX_PDIEHI: usize = 58;
+pub const SFX_PODTH1: usize = 59;
+pub const SFX_PODTH2: usize = 60;
+pub const SFX_PODTH3: usize = 61;
+pub const SFX_BGDTH1: usize = 62;
+pub const SFX_BGDTH2: usize = 63;
+pub const SFX_SGTDTH: usize = 64;
+pub const SFX_CACDTH: usize = 65;
+pub const SFX_SKLDTH: usize = 66;
+pub const SFX_BRSDTH: usize = 67;
+pub const SFX_CYBDTH: usize = 68;
+pub const SFX_SPIDTH: usize = 69;
+pub const SFX_BSPDTH: usize = 70;
+pub const SFX_VILDTH: usize = 71;
+pub const SFX_KNTDTH: usize = 72;
+pub const SFX_PEDTH: usize = 73;
+pub const SFX_SKEDTH: usize = 74;
+pub const SFX_POSACT: usize = 75;
+pub const SFX_BGACT: usize = 76;
+pub const SFX_DMACT: usize = 77;
+pub const SFX_BSPACT: usize = 78;
+pub const SFX_BSPWLK: usize = 79;
+pub const SFX_VILACT: usize = 80;
+pub const SFX_NOWAY: usize = 81;
+pub const SFX_BAREXP: usize = 82;
+pub const SFX_PUNCH: usize = 83;
+pub const SFX_HOOF: usize = 84;
+pub const SFX_METAL: usize = 85;
+pub const SFX_CHGUN: usize = 86;
+pub const SFX_TINK: usize = 87;
+pub const SFX_BDOPN: usize = 88;
+pub const SFX_BDCLS: usize = 89;
+pub const SFX_ITMBK: usize = 90;
+pub const SFX_FLAME: usize = 91;
+pub const SFX_FLAMST: usize = 92;
+pub const SFX_GETPOW: usize = 93;
+pub const SFX_BOSPIT: usize = 94;
+pub const SFX_BOSCUB: usize = 95;
+pub const SFX_BOSSIT: usize = 96;
+pub const SFX_BOSPN: usize = 97;
+pub const SFX_BOSDTH: usize = 98;
+pub const SFX_MANATK: usize = 99;
+pub const SFX_MANDTH: usize = 100;
+pub const SFX_SSSIT: usize = 101;
+pub const SFX_SSDTH: usize = 102;
+pub const SFX_KEENPN: usize = 103;
+pub const SFX_KEENDT: usize = 104;
+pub const SFX_SKEACT: usize = 105;
+pub const SFX_SKESIT: usize = 106;
+pub const SFX_SKEATK: usize = 107;
+pub const SFX_RADIO: usize = 108;
+
+pub const SFX: [SfxInfo; NUM_SFX] = [
+    sound("none", false, 0),
+    sound("pistol", false, 64),
+    sound("shotgn", false, 64),
+    sound("sgcock", false, 64),
+    sound("dshtgn", false, 64),
+    sound("dbopn", false, 64),
+    sound("dbcls", false, 64),
+    sound("dbload", false, 64),
+    sound("plasma", false, 64),
+    sound("bfg", false, 64),
+    sound("sawup", false, 64),
+    sound("sawidl", false, 118),
+    sound("sawful", false, 64),
+    sound("sawhit", false, 64),
+    sound("rlaunc", false, 64),
+    sound("rxplod", false, 70),
+    sound("firsht", false, 70),
+    sound("firxpl", false, 70),
+    sound("pstart", false, 100),
+    sound("pstop", false, 100),
+    sound("doropn", false, 100),
+    sound("dorcls", false, 100),
+    sound("stnmov", false, 119),
+    sound("swtchn", false, 78),
+    sound("swtchx", false, 78),
+    sound("plpain", false, 96),
+    sound("dmpain", false, 96),
+    sound("popain", false, 96),
+    sound("vipain", false, 96),
+    sound("mnpain", false, 96),
+    sound("pepain", false, 96),
+    sound("slop", false, 78),
+    sound("itemup", true, 78),
+    sound("wpnup", true, 78),
+    sound("oof", false, 96),
+    sound("telept", false, 32),
+    sound("posit1", true, 98),
+    sound("posit2", true, 98),
+    sound("posit3", true, 98),
+    sound("bgsit1", true, 98),
+    sound("bgsit2", true, 98),
+    sound("sgtsit", true, 98),
+    sound("cacsit", true, 98),
+    sound("brssit", true, 94),
+    sound("cybsit", true, 92),
+    sound("spisit", true, 90),
+    sound("bspsit", true, 90),
+    sound("kntsit", true, 90),
+    sound("vilsit", true, 90),
+    sound("mansit", true, 90),
+    sound("pesit", true, 90),
+    sound("sklatk", false, 70),
+    sound("sgtatk", false, 70),
+    sound("skepch", false, 70),
+    sound("vilatk", false, 70),
+    sound("claw", false, 70),
+    sound("skeswg", false, 70),
+    sound("pldeth", false, 32),
+    sound("pdiehi", false, 32),
+    sound("podth1", false, 70),
+    sound("podth2", false, 70),
+    sound("podth3", false, 70),
+    sound("bgdth1", false, 70),
+    sound("bgdth2", false, 70),
+    sound("sgtdth", false, 70),
+    sound("cacdth", false, 70),
+    sound("skldth", false, 70),
+    sound("brsdth", false, 32),
+    sound("cybdth", false, 32),
+    sound("spidth", false, 32),
+    sound("bspdth", false, 32),
+    sound("vildth", false, 32),
+    sound("kntdth", false, 32),
+    sound("pedth", false, 32),
+    sound("skedth", false, 32),
+    sound("posact", true, 120),
+    sound("bgact", true, 120),
+    sound("dmact", true, 120),
+    sound("bspact", true, 100),
+    sound("bspwlk", true, 100),
+    sound("vilact", true, 100),
+    sound("noway", false, 78),
+    sound("barexp", false, 60),
+    sound("punch", false, 64),
+    sound("hoof", false, 70),
+    sound("metal", false, 70),
+    SfxInfo {
+        name: "chgun",
+        singularity: false,
+        priority: 64,
+        link: Some(SFX_PISTOL),
+        pitch: 150,
+        volume: 0,
+    },
+    sound("tink", false, 60),
+    sound("bdopn", false, 100),
+    sound("bdcls", false, 100),
+    sound("itmbk", false, 100),
+    sound("flame", false, 32),
+    sound("flamst", false, 32),
+    sound("getpow", false, 60),
+    sound("bospit", false, 70),
+    sound("boscub", false, 70),
+    sound("bossit", false, 70),
+    sound("bospn", false, 70),
+    sound("bosdth", false, 70),
+    sound("manatk", false, 70),
+    sound("mandth", false, 70),
+    sound("sssit", false, 70),
+    sound("ssdth", false, 70),
+    sound("keenpn", false, 70),
+    sound("keendt", false, 70),
+    sound("skeact", false, 70),
+    sound("skesit", false, 70),
+    sound("skeatk", false, 70),
+    sound("radio", false, 60),
+];
+
+pub const NUM_MUSIC: usize = 68;
+
+pub const MUS_NONE: usize = 0;
+pub const MUS_E1M1: usize = 1;
+pub const MUS_E1M2: usize = 2;
+pub const MUS_E1M3: usize = 3;
+pub const MUS_E1M4: usize = 4;
+pub const MUS_E1M5: usize = 5;
+pub const MUS_E1M6: usize = 6;
+pub const MUS_E1M7: usize = 7;
+pub const MUS_E1M8: usize = 8;
+pub const MUS_E1M9: usize = 9;
+pub const MUS_E2M1: usize = 10;
+pub const MUS_E2M2: usize = 11;
+pub const MUS_E2M3: usize = 12;
+pub const MUS_E2M4: usize = 13;
+pub const MUS_E2M5: usize = 14;
+pub const MUS_E2M6: usize = 15;
+pub const MUS_E2M7: usize = 16;
+pub const MUS_E2M8: usize = 17;
+pub const MUS_E2M9: usize = 18;
+pub const MUS_E3M1: usize = 19;
+pub const MUS_E3M2: usize = 20;
+pub const MUS_E3M3: usize = 21;
+pub const MUS_E3M4: usize = 22;
+pub const MUS_E3M5: usize = 23;
+pub const MUS_E3M6: usize = 24;
+pub const MUS_E3M7: usize = 25;
+pub const MUS_E3M8: usize = 26;
+pub const MUS_E3M9: usize = 27;
+pub const MUS_INTER: usize = 28;
+pub const MUS_INTRO: usize = 29;
+pub const MUS_BUNNY: usize = 30;
+pub const MUS_VICTOR: usize = 31;
+pub const MUS_INTROA: usize = 32;
+pub const MUS_RUNNIN: usize = 33;
+pub const MUS_STALKS: usize = 34;
+pub const MUS_COUNTD: usize = 35;
+pub const MUS_BETWEE: usize = 36;
+pub const MUS_DOOM: usize = 37;
+pub const MUS_THE_DA: usize = 38;
+pub const MUS_SHAWN: usize = 39;
+pub const MUS_DDTBLU: usize = 40;
+pub const MUS_IN_CIT: usize = 41;
+pub const MUS_DEAD: usize = 42;
+pub const MUS_STLKS2: usize = 43;
+pub const MUS_THEDA2: usize = 44;
+pub const MUS_DOOM2: usize = 45;
+pub const MUS_DDTBL2: usize = 46;
+pub const MUS_RUNNI2: usize = 47;
+pub const MUS_DEAD2: usize = 48;
+pub const MUS_STLKS3: usize = 49;
+pub const MUS_ROMERO: usize = 50;
+pub const MUS_SHAWN2: usize = 51;
+pub const MUS_MESSAG: usize = 52;
+pub const MUS_COUNT2: usize = 53;
+pub const MUS_DDTBL3: usize = 54;
+pub const MUS_AMPIE: usize = 55;
+pub const MUS_THEDA3: usize = 56;
+pub const MUS_ADRIAN: usize = 57;
+pub const MUS_MESSG2: usize = 58;
+pub const MUS_ROMER2: usize = 59;
+pub const MUS_TENSE: usize = 60;
+pub const MUS_SHAWN3: usize = 61;
+pub const MUS_OPENIN: usize = 62;
+pub const MUS_EVIL: usize = 63;
+pub const MUS_ULTIMA: usize = 64;
+pub const MUS_READ_M: usize = 65;
+pub const MUS_DM2TTL: usize = 66;
+pub const MUS_DM2INT: usize = 67;
+
+pub const MUSIC_NAMES: [&str; NUM_MUSIC] = [
+    "", "e1m1", "e1m2", "e1m3", "e1m4", "e1m5", "e1m6", "e1m7", "e1m8", "e1m9", "e2m1", "e2m2",
+    "e2m3", "e2m4", "e2m5", "e2m6", "e2m7", "e2m8", "e2m9", "e3m1", "e3m2", "e3m3", "e3m4", "e3m5",
+    "e3m6", "e3m7", "e3m8", "e3m9", "inter", "intro", "bunny", "victor", "introa", "runnin",
+    "stalks", "countd", "betwee", "doom", "the_da", "shawn", "ddtblu", "in_cit", "dead", "stlks2",
+    "theda2", "doom2", "ddtbl2", "runni2", "dead2", "stlks3", "romero", "shawn2", "messag",
+    "count2", "ddtbl3", "ampie", "theda3", "adrian", "messg2", "romer2", "tense", "shawn3",
+    "openin", "evil", "ultima", "read_m", "dm2ttl", "dm2int",
+];
@@ -4,10 +4,19 @@ pub struct State {
     pub iwad_found: bool,
     pub iwad_file: String,
 
+    pub game_mission: crate::doom::iwad::GameMission,
+    pub game_mode: crate::doom::iwad::GameMode,
+    pub game_description: String,
+    pub game_version: crate::doom::version::GameVersion,
+    pub modified_game: bool,
+
     pub no_monsters: bool,
     pub respawn_parm: bool,
     pub fast_parm: bool,
     pub dev_parm: bool,
+    pub rebuild_blockmap_parm: bool,
+    pub headless: bool,
+    pub max_tics: Option<u32>,
 
     pub display_fps_dots: bool,
 
@@ -16,6 +25,8 @@ pub struct State {
     pub config_dir: String,
     pub default_main_config: String,
     pub default_extra_config: String,
+    pub autoload_path: crate::options::defaults::DefaultLocation,
+    pub autoload_dirs: Vec<String>,
 
     pub forward_move: [crate::fixed::Fixed; 2],
     pub side_move: [crate::fixed::Fixed; 2],
@@ -28,6 +39,17 @@ pub struct State {
 
     pub doom_defaults: crate::options::defaults::DefaultCollection,
     pub extra_defaults: crate::options::defaults::DefaultCollection,
+
+    pub wad: crate::wad::Wad,
+    pub lump_cache: crate::wad::cache::LumpCache,
+
+    pub info: crate::info::Info,
+    pub random: crate::random::Random,
+
+    pub game_tic: u32,
+    pub level: Option<crate::play::Level>,
+    pub dehacked: crate::deh::Dehacked,
+    pub music: crate::music::Music,
 }
 
 impl Default for State {
@@ -38,10 +60,19 @@ impl Default for State {
             iwad_found: false,
             iwad_file: String::new(),
 
+            game_mission: crate::doom::iwad::GameMission::None,
+            game_mode: crate::doom::iwad::GameMode::Indetermined,
+            game_description: String::new(),
+            game_version: crate::doom::version::GameVersion::Doom1_9,
+            modified_game: false,
+
             no_monsters: false,
             respawn_parm: false,
             fast_parm: false,
             dev_parm: false,
+            rebuild_blockmap_parm: false,
+            headless: false,
+            max_tics: None,
 
             display_fps_dots: false,
 
@@ -50,9 +81,11 @@ impl Default for State {
             config_dir: String::new(),
             default_main_config: String::new(),
             default_extra_config: String::new(),
+            autoload_path: crate::options::defaults::DefaultLocation::new_string(""),
+            autoload_dirs: vec![],
 
-            forward_move: [0x19, 0x32],
-            side_move: [0x18, 0x28],
+            forward_move: [crate::fixed::Fixed(0x19), crate::fixed::Fixed(0x32)],
+            side_move: [crate::fixed::Fixed(0x18), crate::fixed::Fixed(0x28)],
 
             input_options: crate::options::InputOptions::default(),
             video_options: crate::options::VideoOptions::default(),
@@ -62,6 +95,17 @@ impl Default for State {
 
             doom_defaults: crate::options::defaults::doom_defaults_init(),
             extra_defaults: crate::options::defaults::extra_defaults_init(),
+
+            wad: crate::wad::Wad::default(),
+            lump_cache: crate::wad::cache::LumpCache::default(),
+
+            info: crate::info::Info::default(),
+            random: crate::random::Random::default(),
+
+            game_tic: 0,
+            level: None,
+            dehacked: crate::deh::Dehacked::default(),
+            music: crate::music::Music::default(),
         }
     }
 }
@@ -0,0 +1,158 @@
+pub mod archive;
+pub mod cache;
+pub mod check;
+pub mod error;
+pub mod file;
+pub mod format;
+pub mod hash;
+pub mod main;
+pub mod merge;
+#[cfg(test)]
+pub mod testing;
+pub mod tool;
+pub mod writer;
+
+use error::WadError;
+
+pub const LUMP_NAME_LENGTH: usize = 8;
+
+#[derive(Clone, Debug)]
+pub struct LumpInfo {
+    pub name: [u8; LUMP_NAME_LENGTH],
+    pub wad_file: usize,
+    pub position: u64,
+    pub size: usize,
+}
+
+impl LumpInfo {
+    pub fn name(&self) -> String {
+        name_to_string(&self.name)
+    }
+}
+
+#[derive(Default)]
+pub struct Wad {
+    pub files: Vec<file::WadFile>,
+    pub lumps: Vec<LumpInfo>,
+    lookup: std::collections::HashMap<[u8; LUMP_NAME_LENGTH], usize>,
+}
+
+impl Wad {
+    pub fn add_file(&mut self, path: &str) -> Result<usize, WadError> {
+        let wad_file = file::WadFile::open(path)?;
+        let directory = wad_file.read_directory()?;
+
+        let file_index = self.files.len();
+        self.files.push(wad_file);
+
+        for entry in directory {
+            self.lookup
+                .insert(normalize_name(&entry.name), self.lumps.len());
+            self.lumps.push(LumpInfo {
+                name: entry.name,
+                wad_file: file_index,
+                position: entry.position,
+                size: entry.size,
+            });
+        }
+
+        Ok(file_index)
+    }
+
+    pub fn rebuild_lookup(&mut self) {
+        self.lookup.clear();
+        for (index, lump) in self.lumps.iter().enumerate() {
+            if lump.name[0] != 0 {
+                self.lookup.insert(normalize_name(&lump.name), index);
+            }
+        }
+    }
+
+    pub fn num_lumps(&self) -> usize {
+        self.lumps.len()
+    }
+
+    pub fn check_num_for_name(&self, name: &str) -> Option<usize> {
+        self.lookup.get(&normalize_name(name.as_bytes())).copied()
+    }
+
+    pub fn lump(&self, lump: usize) -> Option<&LumpInfo> {
+        self.lumps.get(lump)
+    }
+
+    pub fn lump_length(&self, lump: usize) -> Option<usize> {
+        self.lump(lump).map(|l| l.size)
+    }
+
+    pub fn read_lump(&self, lump: usize) -> Result<Vec<u8>, WadError> {
+        self.lump_data(lump).map(|data| data.into_owned())
+    }
+
+    pub fn lump_data(&self, lump: usize) -> Result<std::borrow::Cow<'_, [u8]>, WadError> {
+        let info = self.lump(lump).ok_or(WadError::NoSuchLump(lump))?;
+        let wad_file = &self.files[info.wad_file];
+
+        if info.position + info.size as u64 > wad_file.length {
+            return Err(WadError::LumpOutOfBounds {
+                lump_name: info.name(),
+                position: info.position,
+                size: info.size,
+                file_size: wad_file.length,
+            });
+        }
+
+        if let Some(data) = wad_file.mapped(info.position, info.size) {
+            return Ok(std::borrow::Cow::Borrowed(data));
+        }
+
+        let mut buffer = vec![0u8; info.size];
+        wad_file.read_at(info.position, &mut buffer)?;
+        Ok(std::borrow::Cow::Owned(buffer))
+    }
+}
+
+pub fn normalize_name(name: &[u8]) -> [u8; LUMP_NAME_LENGTH] {
+    let mut result = [0u8; LUMP_NAME_LENGTH];
+    for (dest, src) in result.iter_mut().zip(name.iter().take_while(|&&c| c != 0)) {
+        *dest = src.to_ascii_uppercase();
+    }
+    result
+}
+
+pub fn name_to_string(name: &[u8]) -> String {
+    name.iter()
+        .take(LUMP_NAME_LENGTH)
+        .take_while(|&&c| c != 0)
+        .map(|&c| c as char)
+        .collect()
+}
+
+impl crate::state::State {
+    pub fn add_wad_file(&mut self, path: &str) -> bool {
+        println!(" adding {}", path);
+
+        match self.wad.add_file(path) {
+            Ok(_) => true,
+            Err(e) => {
+                eprintln!(" couldn't open {}: {}", path, e);
+                false
+            }
+        }
+    }
+
+    pub fn get_num_for_name(&self, name: &str) -> usize {
+        self.wad
+            .check_num_for_name(name)
+            .unwrap_or_else(|| self.error(format!("W_GetNumForName: {} not found!", name)))
+    }
+
+    pub fn cache_lump_num(&self, lump: usize) -> Vec<u8> {
+        self.wad
+            .read_lump(lump)
+            .unwrap_or_else(|e| self.error(format!("W_CacheLumpNum: {}", e)))
+    }
+
+    pub fn cache_lump_name(&self, name: &str) -> Vec<u8> {
+        self.cache_lump_num(self.get_num_for_name(name))
+    }
+}
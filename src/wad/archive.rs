@@ -0,0 +1,208 @@
+use std::io::Read;
+
+use super::error::WadError;
+use super::file::DirectoryEntry;
+
+const NAMESPACES: [(&str, &str, &str); 3] = [
+    ("patches", "P_START", "P_END"),
+    ("flats", "F_START", "F_END"),
+    ("sprites", "S_START", "S_END"),
+];
+
+const PLAIN_DIRECTORIES: [&str; 4] = ["sounds", "music", "graphics", "colormaps"];
+
+struct ArchiveLump {
+    path: String,
+    name: [u8; super::LUMP_NAME_LENGTH],
+    data: Vec<u8>,
+}
+
+#[derive(Default)]
+struct ArchiveBuilder {
+    data: Vec<u8>,
+    directory: Vec<DirectoryEntry>,
+}
+
+impl ArchiveBuilder {
+    fn add_lump(&mut self, name: [u8; super::LUMP_NAME_LENGTH], data: &[u8]) {
+        self.directory.push(DirectoryEntry {
+            position: self.data.len() as u64,
+            size: data.len(),
+            name,
+        });
+        self.data.extend_from_slice(data);
+    }
+
+    fn add_marker(&mut self, name: &str) {
+        self.add_lump(super::normalize_name(name.as_bytes()), &[]);
+    }
+
+    // Maps in an archive are stored as complete WADs under maps/, with the
+    // map taking the name of the file rather than its own header lump.
+    fn add_map_wad(&mut self, path: &str, lump: &ArchiveLump) -> Result<(), WadError> {
+        let data = &lump.data;
+        if data.len() < super::file::HEADER_SIZE as usize {
+            return Err(WadError::TruncatedHeader {
+                file_name: path.to_owned(),
+                file_size: data.len() as u64,
+            });
+        }
+        if &data[0..4] != b"IWAD" && &data[0..4] != b"PWAD" {
+            let mut identification = [0u8; 4];
+            identification.copy_from_slice(&data[0..4]);
+            return Err(WadError::BadIdentification {
+                file_name: path.to_owned(),
+                identification,
+            });
+        }
+
+        let entries =
+            super::file::read_wad_directory(path, data.len() as u64, |position, buffer| {
+                let start = position as usize;
+                let source = data
+                    .get(start..start + buffer.len())
+                    .ok_or_else(|| WadError::Io {
+                        file_name: path.to_owned(),
+                        source: std::io::ErrorKind::UnexpectedEof.into(),
+                    })?;
+                buffer.copy_from_slice(source);
+                Ok(())
+            })?;
+
+        let base = self.data.len() as u64;
+        self.data.extend_from_slice(data);
+        for (i, entry) in entries.into_iter().enumerate() {
+            self.directory.push(DirectoryEntry {
+                position: base + entry.position,
+                size: entry.size,
+                name: if i == 0 { lump.name } else { entry.name },
+            });
+        }
+
+        Ok(())
+    }
+}
+
+pub fn read_archive(path: &str) -> Result<(Vec<u8>, Vec<DirectoryEntry>), WadError> {
+    let io_error = |source| WadError::Io {
+        file_name: path.to_owned(),
+        source,
+    };
+    let archive_error = |e: zip::result::ZipError| WadError::BadArchive {
+        file_name: path.to_owned(),
+        why: e.to_string(),
+    };
+
+    let handle = std::fs::File::open(path).map_err(io_error)?;
+    let mut zip = zip::ZipArchive::new(handle).map_err(archive_error)?;
+
+    let mut root = vec![];
+    let mut plain = vec![];
+    let mut maps = vec![];
+    let mut namespaced: [Vec<ArchiveLump>; 3] = Default::default();
+
+    for i in 0..zip.len() {
+        let mut entry = zip.by_index(i).map_err(archive_error)?;
+        if entry.is_dir() {
+            continue;
+        }
+
+        let entry_path = entry.name().to_owned();
+        let components = entry_path
+            .split('/')
+            .filter(|c| !c.is_empty())
+            .collect::<Vec<_>>();
+        // Subdirectories belong to the directory they are in, so a PK3 can
+        // keep sprites/monsters/ and the like apart.
+        let (dir, file_name) = match components.as_slice() {
+            [file_name] => (String::new(), *file_name),
+            [dir, .., file_name] => (dir.to_lowercase(), *file_name),
+            [] => continue,
+        };
+
+        let destination = if dir.is_empty() {
+            &mut root
+        } else if dir == "maps" {
+            if components.len() > 2 {
+                eprintln!(
+                    " skipping {} in {}: maps must be directly under maps/",
+                    entry_path, path
+                );
+                continue;
+            }
+            &mut maps
+        } else if PLAIN_DIRECTORIES.contains(&dir.as_str()) {
+            &mut plain
+        } else if let Some(ns) = NAMESPACES.iter().position(|(ns, _, _)| *ns == dir) {
+            &mut namespaced[ns]
+        } else {
+            continue;
+        };
+
+        let name = match archive_lump_name(file_name) {
+            Some(name) => name,
+            None => {
+                eprintln!(
+                    " skipping {} in {}: name is too long for a lump",
+                    entry_path, path
+                );
+                continue;
+            }
+        };
+
+        let mut data = Vec::with_capacity(entry.size() as usize);
+        entry.read_to_end(&mut data).map_err(io_error)?;
+        destination.push(ArchiveLump {
+            path: entry_path,
+            name,
+            data,
+        });
+    }
+
+    let mut builder = ArchiveBuilder::default();
+
+    let by_path = |a: &ArchiveLump, b: &ArchiveLump| a.path.cmp(&b.path);
+    root.sort_by(by_path);
+    plain.sort_by(by_path);
+    maps.sort_by(by_path);
+    for lumps in namespaced.iter_mut() {
+        lumps.sort_by(by_path);
+    }
+
+    for lump in root.iter().chain(plain.iter()) {
+        builder.add_lump(lump.name, &lump.data);
+    }
+
+    for lump in &maps {
+        if lump.path.to_lowercase().ends_with(".wad") {
+            builder.add_map_wad(&format!("{}:{}", path, lump.path), lump)?;
+        } else {
+            eprintln!(
+                " skipping {} in {}: maps must be WAD files",
+                lump.path, path
+            );
+        }
+    }
+
+    for ((_, start, end), lumps) in NAMESPACES.iter().zip(namespaced.iter()) {
+        if lumps.is_empty() {
+            continue;
+        }
+        builder.add_marker(start);
+        for lump in lumps {
+            builder.add_lump(lump.name, &lump.data);
+        }
+        builder.add_marker(end);
+    }
+
+    Ok((builder.data, builder.directory))
+}
+
+fn archive_lump_name(file_name: &str) -> Option<[u8; super::LUMP_NAME_LENGTH]> {
+    let stem = file_name.split('.').next().unwrap_or(file_name);
+    if stem.is_empty() || stem.len() > super::LUMP_NAME_LENGTH {
+        return None;
+    }
+
+    Some(super::normalize_name(stem.to_uppercase().as_bytes()))
+}
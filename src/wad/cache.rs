@@ -0,0 +1,256 @@
+use super::format::Cacheable;
+use super::format::Flat;
+use super::format::Patch;
+use super::format::Sound;
+
+pub const DEFAULT_PATCH_BUDGET: usize = 16 * 1024 * 1024;
+pub const DEFAULT_FLAT_BUDGET: usize = 4 * 1024 * 1024;
+pub const DEFAULT_SOUND_BUDGET: usize = 64 * 1024 * 1024;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct CacheStatistics {
+    pub entries: usize,
+    pub bytes: usize,
+    pub budget: usize,
+    pub hits: usize,
+    pub misses: usize,
+    pub evictions: usize,
+}
+
+struct CacheEntry<T> {
+    value: std::sync::Arc<T>,
+    size: usize,
+    last_used: u64,
+}
+
+pub struct CachePool<T> {
+    entries: std::collections::HashMap<usize, CacheEntry<T>>,
+    recency: std::collections::BTreeMap<u64, usize>,
+    clock: u64,
+    bytes: usize,
+    budget: usize,
+    hits: usize,
+    misses: usize,
+    evictions: usize,
+}
+
+impl<T: Cacheable> CachePool<T> {
+    pub fn new(budget: usize) -> Self {
+        Self {
+            entries: std::collections::HashMap::new(),
+            recency: std::collections::BTreeMap::new(),
+            clock: 0,
+            bytes: 0,
+            budget,
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+        }
+    }
+
+    pub fn get_or_insert_with<E>(
+        &mut self,
+        lump: usize,
+        load: impl FnOnce() -> Result<T, E>,
+    ) -> Result<std::sync::Arc<T>, E> {
+        self.clock += 1;
+
+        if let Some(entry) = self.entries.get_mut(&lump) {
+            self.recency.remove(&entry.last_used);
+            self.recency.insert(self.clock, lump);
+            entry.last_used = self.clock;
+            self.hits += 1;
+            return Ok(entry.value.clone());
+        }
+
+        self.misses += 1;
+        let value = std::sync::Arc::new(load()?);
+        let size = value.cache_size();
+
+        self.bytes += size;
+        self.recency.insert(self.clock, lump);
+        self.entries.insert(
+            lump,
+            CacheEntry {
+                value: value.clone(),
+                size,
+                last_used: self.clock,
+            },
+        );
+        self.evict();
+
+        Ok(value)
+    }
+
+    pub fn set_budget(&mut self, budget: usize) {
+        self.budget = budget;
+        self.evict();
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.recency.clear();
+        self.bytes = 0;
+    }
+
+    pub fn statistics(&self) -> CacheStatistics {
+        CacheStatistics {
+            entries: self.entries.len(),
+            bytes: self.bytes,
+            budget: self.budget,
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+        }
+    }
+
+    // The most recently used entry is never evicted, even when it alone is
+    // over budget, so the caller always gets back what it asked for.
+    fn evict(&mut self) {
+        while self.bytes > self.budget && self.entries.len() > 1 {
+            let (&last_used, &lump) = match self.recency.iter().next() {
+                Some(oldest) => oldest,
+                None => break,
+            };
+            self.recency.remove(&last_used);
+            if let Some(entry) = self.entries.remove(&lump) {
+                self.bytes -= entry.size;
+                self.evictions += 1;
+            }
+        }
+    }
+}
+
+pub struct LumpCache {
+    pub patches: CachePool<Patch>,
+    pub flats: CachePool<Flat>,
+    pub sounds: CachePool<Sound>,
+}
+
+impl Default for LumpCache {
+    fn default() -> Self {
+        Self {
+            patches: CachePool::new(DEFAULT_PATCH_BUDGET),
+            flats: CachePool::new(DEFAULT_FLAT_BUDGET),
+            sounds: CachePool::new(DEFAULT_SOUND_BUDGET),
+        }
+    }
+}
+
+impl crate::state::State {
+    pub fn print_cache_statistics(&self) {
+        let mapped = self.wad.files.iter().filter(|f| f.is_mapped()).count();
+        let mapped_bytes: u64 = self
+            .wad
+            .files
+            .iter()
+            .filter(|f| f.is_mapped())
+            .map(|f| f.length)
+            .sum();
+        println!(
+            "Lump cache: {} lumps in {} files, {} mapped ({} bytes)",
+            self.wad.num_lumps(),
+            self.wad.files.len(),
+            mapped,
+            mapped_bytes
+        );
+
+        let pools = [
+            ("patches", self.lump_cache.patches.statistics()),
+            ("flats", self.lump_cache.flats.statistics()),
+            ("sounds", self.lump_cache.sounds.statistics()),
+        ];
+        for (name, stats) in pools.iter() {
+            println!(
+                "  {:<8} {:>6} entries {:>10}/{:>10} bytes {:>8} hits {:>8} misses {:>6} evictions",
+                name,
+                stats.entries,
+                stats.bytes,
+                stats.budget,
+                stats.hits,
+                stats.misses,
+                stats.evictions
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Blob(usize);
+
+    impl Cacheable for Blob {
+        fn cache_size(&self) -> usize {
+            self.0
+        }
+    }
+
+    fn load(pool: &mut CachePool<Blob>, lump: usize, size: usize) {
+        pool.get_or_insert_with(lump, || Ok::<_, ()>(Blob(size)))
+            .unwrap();
+    }
+
+    fn cached(pool: &CachePool<Blob>) -> Vec<usize> {
+        let mut lumps: Vec<_> = pool.entries.keys().copied().collect();
+        lumps.sort_unstable();
+        lumps
+    }
+
+    #[test]
+    fn evicts_the_least_recently_used_first() {
+        let mut pool = CachePool::new(30);
+        load(&mut pool, 1, 10);
+        load(&mut pool, 2, 10);
+        load(&mut pool, 3, 10);
+        // Using 1 again leaves 2 as the oldest.
+        load(&mut pool, 1, 10);
+        load(&mut pool, 4, 10);
+        assert_eq!(cached(&pool), [1, 3, 4]);
+
+        load(&mut pool, 5, 20);
+        assert_eq!(cached(&pool), [4, 5]);
+
+        let stats = pool.statistics();
+        assert_eq!((stats.hits, stats.misses, stats.evictions), (1, 5, 3));
+        assert_eq!((stats.entries, stats.bytes), (2, 30));
+    }
+
+    #[test]
+    fn keeps_within_the_budget() {
+        let mut pool = CachePool::new(100);
+        for lump in 0..50 {
+            load(&mut pool, lump, 7);
+            assert!(pool.statistics().bytes <= 100);
+        }
+        assert_eq!(pool.statistics().entries, 14);
+
+        pool.set_budget(20);
+        assert_eq!(cached(&pool), [48, 49]);
+        assert_eq!(pool.statistics().bytes, 14);
+    }
+
+    #[test]
+    fn keeps_the_newest_entry_even_over_budget() {
+        let mut pool = CachePool::new(10);
+        load(&mut pool, 1, 5);
+        load(&mut pool, 2, 50);
+        assert_eq!(cached(&pool), [2]);
+        assert_eq!(pool.statistics().bytes, 50);
+
+        // A hit hands back the cached value without loading it again.
+        let blob = pool
+            .get_or_insert_with(2, || -> Result<Blob, ()> { panic!("loaded twice") })
+            .unwrap();
+        assert_eq!(blob.0, 50);
+    }
+
+    #[test]
+    fn failed_loads_are_not_cached() {
+        let mut pool = CachePool::<Blob>::new(10);
+        assert!(pool.get_or_insert_with(1, || Err("bad lump")).is_err());
+        assert!(cached(&pool).is_empty());
+        assert_eq!(pool.statistics().misses, 1);
+    }
+}
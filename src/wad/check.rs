@@ -0,0 +1,546 @@
+use super::file::DirectoryEntry;
+use super::file::WadKind;
+
+type Section = (
+    &'static str,
+    &'static [&'static str],
+    &'static [&'static str],
+);
+
+const SECTIONS: [Section; 3] = [
+    ("sprites", &["S_START", "SS_START"], &["S_END", "SS_END"]),
+    ("flats", &["F_START", "FF_START"], &["F_END", "FF_END"]),
+    ("patches", &["P_START", "PP_START"], &["P_END", "PP_END"]),
+];
+
+const REQUIRED_MAP_LUMPS: [&str; 5] = ["THINGS", "LINEDEFS", "SIDEDEFS", "VERTEXES", "SECTORS"];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.pad(match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        })
+    }
+}
+
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub lump: Option<usize>,
+    pub message: String,
+}
+
+struct Checker<'a> {
+    entries: &'a [DirectoryEntry],
+    names: Vec<String>,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl<'a> Checker<'a> {
+    fn report<S: Into<String>>(&mut self, severity: Severity, lump: Option<usize>, message: S) {
+        self.diagnostics.push(Diagnostic {
+            severity,
+            lump,
+            message: message.into(),
+        });
+    }
+
+    fn check_bounds(&mut self, file_length: u64, reserved: &[(u64, u64, &str)]) {
+        for (i, entry) in self.entries.iter().enumerate() {
+            let end = entry.position + entry.size as u64;
+            if end > file_length {
+                self.report(
+                    Severity::Error,
+                    Some(i),
+                    format!(
+                        "{} bytes at offset {} extend past end of file ({} bytes)",
+                        entry.size, entry.position, file_length
+                    ),
+                );
+                continue;
+            }
+
+            for &(start, reserved_end, what) in reserved {
+                if entry.size > 0 && entry.position < reserved_end && start < end {
+                    self.report(Severity::Error, Some(i), format!("overlaps the {}", what));
+                }
+            }
+        }
+    }
+
+    fn check_overlaps(&mut self) {
+        let mut by_position = (0..self.entries.len())
+            .filter(|&i| self.entries[i].size > 0)
+            .collect::<Vec<_>>();
+        by_position.sort_by_key(|&i| (self.entries[i].position, self.entries[i].size));
+
+        // Each lump is compared with the one reaching furthest so far, so a
+        // big lump is caught overlapping every lump it covers, not just the
+        // one after it.
+        let end = |i: usize| self.entries[i].position + self.entries[i].size as u64;
+        let mut previous: Option<usize> = None;
+        let mut furthest: Option<usize> = None;
+        let mut reports = vec![];
+
+        for &i in &by_position {
+            let entry = &self.entries[i];
+            match (previous, furthest) {
+                (Some(p), _)
+                    if self.entries[p].position == entry.position
+                        && self.entries[p].size == entry.size =>
+                {
+                    let message = format!("shares its data with {}", self.names[p]);
+                    reports.push((Severity::Info, i, message));
+                }
+                (_, Some(f)) if entry.position < end(f) => {
+                    let message = format!("overlaps {}", self.names[f]);
+                    reports.push((Severity::Error, i, message));
+                }
+                _ => {}
+            }
+
+            if furthest.map(|f| end(i) > end(f)).unwrap_or(true) {
+                furthest = Some(i);
+            }
+            previous = Some(i);
+        }
+
+        for (severity, i, message) in reports {
+            self.report(severity, Some(i), message);
+        }
+    }
+
+    fn check_sections(&mut self) {
+        let mut open: Option<(usize, &Section)> = None;
+
+        for i in 0..self.entries.len() {
+            let name = self.names[i].clone();
+            let start = SECTIONS
+                .iter()
+                .find(|(_, starts, _)| starts.contains(&name.as_str()));
+            let end = SECTIONS
+                .iter()
+                .find(|(_, _, ends)| ends.contains(&name.as_str()));
+
+            if let Some(section) = start {
+                if let Some((opened, _)) = open {
+                    let message =
+                        format!("{} starts before {} was closed", name, self.names[opened]);
+                    self.report(Severity::Error, Some(i), message);
+                }
+                open = Some((i, section));
+            } else if let Some(section) = end {
+                match open {
+                    Some((_, open_section)) if open_section.0 == section.0 => open = None,
+                    Some((opened, _)) => {
+                        let message = format!("{} closes {}", name, self.names[opened]);
+                        self.report(Severity::Error, Some(i), message);
+                        open = None;
+                    }
+                    None => self.report(Severity::Error, Some(i), "end marker without start"),
+                }
+            } else if let Some((_, section)) = open {
+                if self.entries[i].size == 0 && !is_marker(&name) {
+                    let message = format!("empty lump in {} section", section.0);
+                    self.report(Severity::Error, Some(i), message);
+                }
+            } else if self.entries[i].size == 0
+                && !is_marker(&name)
+                && !is_map_name(&name)
+                && !self.is_map_header(i)
+                && !crate::data::is_map_lump_name(&name)
+                && name != "ENDMAP"
+            {
+                self.report(Severity::Warning, Some(i), "empty lump");
+            }
+        }
+
+        if let Some((opened, _)) = open {
+            self.report(Severity::Error, Some(opened), "section is never closed");
+        }
+    }
+
+    fn check_maps(&mut self) {
+        let mut seen = std::collections::HashMap::new();
+
+        for i in 0..self.entries.len() {
+            let name = self.names[i].clone();
+            let is_header = self.is_map_header(i);
+
+            if !is_header {
+                if is_map_name(&name) {
+                    self.report(
+                        Severity::Error,
+                        Some(i),
+                        "map header is not followed by map data",
+                    );
+                }
+                continue;
+            }
+
+            if let Some(previous) = seen.insert(name.clone(), i) {
+                let message = format!("duplicate map header, also at lump {}", previous);
+                self.report(Severity::Warning, Some(i), message);
+            }
+
+            if self.names[i + 1] == "TEXTMAP" {
+                // The map's own ENDMAP, not one belonging to a later map.
+                let has_end = self.names[i + 2..]
+                    .iter()
+                    .find(|n| !crate::data::UDMF_MAP_LUMP_NAMES.contains(&n.as_str()))
+                    .map(|n| n == "ENDMAP")
+                    .unwrap_or(false);
+                if !has_end {
+                    self.report(Severity::Error, Some(i), "UDMF map has no ENDMAP");
+                }
+                continue;
+            }
+
+            self.check_map_lump_order(i);
+        }
+    }
+
+    // Vanilla finds map lumps by their offset from the header, so they must
+    // appear in exactly the standard order with none missing.
+    fn check_map_lump_order(&mut self, header: usize) {
+        let map_name = self.names[header].clone();
+        let num_map_lumps = crate::data::MAP_LUMP_NAMES.len();
+        let mut highest = 0;
+        let mut found = vec![];
+        let mut lump = header + 1;
+
+        while lump < self.entries.len() {
+            let name = self.names[lump].clone();
+            let order = match crate::data::map_lump_order(&name) {
+                Some(order) => order,
+                None => break,
+            };
+
+            if order < highest {
+                let message = format!("{} is out of order in map {}", name, map_name);
+                self.report(Severity::Error, Some(lump), message);
+            }
+
+            if self.entries[lump].size == 0 && REQUIRED_MAP_LUMPS.contains(&name.as_str()) {
+                self.report(Severity::Error, Some(lump), "required map lump is empty");
+            } else if self.entries[lump].size == 0 && name != "REJECT" && order < num_map_lumps {
+                self.report(Severity::Warning, Some(lump), "map lump is empty");
+            }
+
+            found.push(order);
+            highest = highest.max(order);
+            lump += 1;
+        }
+
+        for (order, name) in crate::data::MAP_LUMP_NAMES.iter().enumerate() {
+            if found.contains(&order) {
+                continue;
+            }
+
+            let message = format!("map {} has no {} lump", map_name, name);
+            if REQUIRED_MAP_LUMPS.contains(name) || order < highest {
+                self.report(Severity::Error, Some(header), message);
+            } else {
+                self.report(Severity::Warning, Some(header), message);
+            }
+        }
+    }
+
+    fn is_map_header(&self, lump: usize) -> bool {
+        self.names
+            .get(lump + 1)
+            .map(|next| next == "THINGS" || next == "TEXTMAP")
+            .unwrap_or(false)
+    }
+}
+
+pub fn check_wad_file(path: &str) -> Vec<Diagnostic> {
+    let error = |message: String| {
+        vec![Diagnostic {
+            severity: Severity::Error,
+            lump: None,
+            message,
+        }]
+    };
+
+    let file = match super::file::WadFile::open(path) {
+        Ok(file) => file,
+        Err(e) => return error(e.to_string()),
+    };
+    let entries = match file.read_directory() {
+        Ok(entries) => entries,
+        Err(e) => return error(e.to_string()),
+    };
+
+    let mut reserved = vec![];
+    if file.kind == WadKind::Iwad || file.kind == WadKind::Pwad {
+        let mut header = [0u8; super::file::HEADER_SIZE as usize];
+        if file.read_at(0, &mut header).is_ok() {
+            let info_table_offset =
+                i32::from_le_bytes([header[8], header[9], header[10], header[11]]) as u64;
+            reserved.push((0, super::file::HEADER_SIZE, "WAD header"));
+            reserved.push((
+                info_table_offset,
+                info_table_offset + entries.len() as u64 * super::file::DIRECTORY_ENTRY_SIZE,
+                "WAD directory",
+            ));
+        }
+    }
+
+    let mut checker = Checker {
+        entries: &entries,
+        names: entries
+            .iter()
+            .map(|e| super::name_to_string(&e.name).to_uppercase())
+            .collect(),
+        diagnostics: vec![],
+    };
+
+    checker.check_bounds(file.length, &reserved);
+    checker.check_overlaps();
+    checker.check_sections();
+    checker.check_maps();
+
+    let mut diagnostics = checker.diagnostics;
+    diagnostics.sort_by_key(|d| d.lump);
+    diagnostics
+}
+
+impl crate::state::State {
+    pub fn check_wad_mode(&self) -> ! {
+        let mut failed = false;
+
+        for path in self.parm_files("--check-wad") {
+            println!("Checking {}", path);
+
+            let diagnostics = check_wad_file(&path);
+            let entries = super::file::WadFile::open(&path)
+                .and_then(|f| f.read_directory())
+                .unwrap_or_default();
+
+            for diagnostic in &diagnostics {
+                match diagnostic.lump {
+                    Some(lump) => println!(
+                        "  {:<7} [{:>5}] {:<8} {}",
+                        diagnostic.severity,
+                        lump,
+                        entries
+                            .get(lump)
+                            .map(|e| super::name_to_string(&e.name))
+                            .unwrap_or_default(),
+                        diagnostic.message
+                    ),
+                    None => println!("  {:<7} {}", diagnostic.severity, diagnostic.message),
+                }
+            }
+
+            let count = |severity| {
+                diagnostics
+                    .iter()
+                    .filter(|d| d.severity == severity)
+                    .count()
+            };
+            let errors = count(Severity::Error);
+            println!(
+                "{}: {} errors, {} warnings",
+                path,
+                errors,
+                count(Severity::Warning)
+            );
+
+            failed |= errors > 0;
+        }
+
+        std::process::exit(if failed { 1 } else { 0 });
+    }
+}
+
+fn is_marker(name: &str) -> bool {
+    name.ends_with("_START") || name.ends_with("_END")
+}
+
+fn is_map_name(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    match bytes {
+        [b'E', e, b'M', m] => e.is_ascii_digit() && m.is_ascii_digit(),
+        [b'M', b'A', b'P', a, b] => a.is_ascii_digit() && b.is_ascii_digit(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wad::testing::TempFile;
+
+    const MAP: [(&str, &[u8]); 11] = [
+        ("MAP01", b""),
+        ("THINGS", b"t"),
+        ("LINEDEFS", b"l"),
+        ("SIDEDEFS", b"s"),
+        ("VERTEXES", b"v"),
+        ("SEGS", b"s"),
+        ("SSECTORS", b"s"),
+        ("NODES", b"n"),
+        ("SECTORS", b"s"),
+        ("REJECT", b""),
+        ("BLOCKMAP", b"b"),
+    ];
+
+    fn check(lumps: &[(&str, &[u8])]) -> Vec<(Severity, Option<usize>, String)> {
+        let file = TempFile::wad(WadKind::Pwad, lumps);
+        check_wad_file(&file.path)
+            .into_iter()
+            .map(|d| (d.severity, d.lump, d.message))
+            .collect()
+    }
+
+    fn error(lump: usize, message: &str) -> (Severity, Option<usize>, String) {
+        (Severity::Error, Some(lump), message.to_owned())
+    }
+
+    #[test]
+    fn a_well_formed_wad_is_clean() {
+        let mut lumps = MAP.to_vec();
+        lumps.extend_from_slice(&[
+            ("F_START", b""),
+            ("FLAT1", b"flat"),
+            ("F_END", b""),
+            ("MAP02", b""),
+            ("TEXTMAP", b"namespace = \"doom\";"),
+            ("ZNODES", b"z"),
+            ("ENDMAP", b""),
+        ]);
+        assert_eq!(check(&lumps), []);
+    }
+
+    #[test]
+    fn udmf_maps_need_their_own_endmap() {
+        let lumps: [(&str, &[u8]); 6] = [
+            ("MAP01", b""),
+            ("TEXTMAP", b"t"),
+            ("ZNODES", b"z"),
+            ("MAP02", b""),
+            ("TEXTMAP", b"t"),
+            ("ENDMAP", b""),
+        ];
+        assert_eq!(check(&lumps), [error(0, "UDMF map has no ENDMAP")]);
+
+        let lumps: [(&str, &[u8]); 4] = [
+            ("MAP01", b""),
+            ("TEXTMAP", b"t"),
+            ("DEHACKED", b"d"),
+            ("ENDMAP", b""),
+        ];
+        assert_eq!(check(&lumps), [error(0, "UDMF map has no ENDMAP")]);
+    }
+
+    #[test]
+    fn reports_map_lumps_out_of_order_or_missing() {
+        let lumps: [(&str, &[u8]); 5] = [
+            ("MAP01", b""),
+            ("THINGS", b"t"),
+            ("SIDEDEFS", b"s"),
+            ("LINEDEFS", b"l"),
+            ("SECTORS", b""),
+        ];
+        let diagnostics = check(&lumps);
+        assert!(diagnostics.contains(&error(3, "LINEDEFS is out of order in map MAP01")));
+        assert!(diagnostics.contains(&error(4, "required map lump is empty")));
+        assert!(diagnostics.contains(&error(0, "map MAP01 has no VERTEXES lump")));
+        assert!(diagnostics.contains(&(
+            Severity::Warning,
+            Some(0),
+            "map MAP01 has no BLOCKMAP lump".to_owned()
+        )));
+    }
+
+    #[test]
+    fn reports_duplicate_maps_and_headers_without_data() {
+        let mut lumps = MAP.to_vec();
+        lumps.extend_from_slice(&MAP);
+        lumps.push(("MAP02", b""));
+        let diagnostics = check(&lumps);
+        assert_eq!(
+            diagnostics,
+            [
+                (
+                    Severity::Warning,
+                    Some(11),
+                    "duplicate map header, also at lump 0".to_owned()
+                ),
+                error(22, "map header is not followed by map data"),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_broken_sections() {
+        let lumps: [(&str, &[u8]); 6] = [
+            ("S_END", b""),
+            ("F_START", b""),
+            ("FLAT1", b""),
+            ("S_START", b""),
+            ("S_END", b""),
+            ("P_START", b""),
+        ];
+        assert_eq!(
+            check(&lumps),
+            [
+                error(0, "end marker without start"),
+                error(2, "empty lump in flats section"),
+                error(3, "S_START starts before F_START was closed"),
+                error(5, "section is never closed"),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_lumps_past_the_end_and_overlaps() {
+        let mut data = b"PWAD".to_vec();
+        data.extend_from_slice(&3i32.to_le_bytes());
+        data.extend_from_slice(&20i32.to_le_bytes());
+        data.extend_from_slice(b"abcdefgh");
+        for &(position, size, name) in &[
+            (12i32, 8i32, b"BIG\0\0\0\0\0"),
+            (16, 2, b"SMALL\0\0\0"),
+            (60, 100, b"PAST\0\0\0\0"),
+        ] {
+            data.extend_from_slice(&position.to_le_bytes());
+            data.extend_from_slice(&size.to_le_bytes());
+            data.extend_from_slice(name);
+        }
+        let file = TempFile::new("wad", &data);
+        let diagnostics: Vec<_> = check_wad_file(&file.path)
+            .into_iter()
+            .map(|d| (d.severity, d.lump, d.message))
+            .collect();
+        assert_eq!(
+            diagnostics,
+            [
+                error(1, "overlaps BIG"),
+                error(
+                    2,
+                    "100 bytes at offset 60 extend past end of file (68 bytes)"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_files_that_cannot_be_read() {
+        let file = TempFile::new("wad", b"PWAD");
+        let diagnostics = check_wad_file(&file.path);
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert_eq!(diagnostics[0].lump, None);
+    }
+}
@@ -0,0 +1,142 @@
+#[derive(Debug)]
+pub enum WadError {
+    Io {
+        file_name: String,
+        source: std::io::Error,
+    },
+    BadIdentification {
+        file_name: String,
+        identification: [u8; 4],
+    },
+    TruncatedHeader {
+        file_name: String,
+        file_size: u64,
+    },
+    BadDirectory {
+        file_name: String,
+        num_lumps: i32,
+        info_table_offset: i32,
+    },
+    TruncatedDirectory {
+        file_name: String,
+        directory_end: u64,
+        file_size: u64,
+    },
+    BadLumpSize {
+        file_name: String,
+        lump_name: String,
+        size: i32,
+    },
+    LumpOutOfBounds {
+        lump_name: String,
+        position: u64,
+        size: usize,
+        file_size: u64,
+    },
+    MissingSection {
+        file_name: String,
+        start: &'static str,
+        end: &'static str,
+    },
+    BadArchive {
+        file_name: String,
+        why: String,
+    },
+    BadLump {
+        lump_name: String,
+        why: String,
+    },
+    NoSuchLump(usize),
+}
+
+impl std::error::Error for WadError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for WadError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io { file_name, source } => write!(f, "{}: {}", file_name, source),
+            Self::BadIdentification {
+                file_name,
+                identification,
+            } => write!(
+                f,
+                "Wad file {} doesn't have IWAD or PWAD id (found '{}')",
+                file_name,
+                String::from_utf8_lossy(identification)
+            ),
+            Self::TruncatedHeader {
+                file_name,
+                file_size,
+            } => write!(
+                f,
+                "Wad file {} is too small to hold a header ({} bytes)",
+                file_name, file_size
+            ),
+            Self::BadDirectory {
+                file_name,
+                num_lumps,
+                info_table_offset,
+            } => write!(
+                f,
+                "Wad file {} has a corrupt directory ({} lumps at offset {})",
+                file_name, num_lumps, info_table_offset
+            ),
+            Self::TruncatedDirectory {
+                file_name,
+                directory_end,
+                file_size,
+            } => write!(
+                f,
+                "Wad file {} is truncated: directory ends at {} but file is {} bytes",
+                file_name, directory_end, file_size
+            ),
+            Self::BadLumpSize {
+                file_name,
+                lump_name,
+                size,
+            } => write!(
+                f,
+                "Wad file {} has a corrupt directory (lump {} is {} bytes)",
+                file_name, lump_name, size
+            ),
+            Self::LumpOutOfBounds {
+                lump_name,
+                position,
+                size,
+                file_size,
+            } => write!(
+                f,
+                "Lump {} ({} bytes at offset {}) extends past end of file ({} bytes)",
+                lump_name, size, position, file_size
+            ),
+            Self::MissingSection {
+                file_name,
+                start,
+                end,
+            } => write!(
+                f,
+                "{} section ({} - {}) not found in {}",
+                if *start == "S_START" {
+                    "Sprites"
+                } else {
+                    "Flats"
+                },
+                start,
+                end,
+                file_name
+            ),
+            Self::BadArchive { file_name, why } => {
+                write!(f, "Archive {} could not be read: {}", file_name, why)
+            }
+            Self::BadLump { lump_name, why } => write!(f, "Lump {} is invalid: {}", lump_name, why),
+            Self::NoSuchLump(lump) => write!(f, "Lump {} does not exist", lump),
+        }
+    }
+}
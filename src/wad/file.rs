@@ -0,0 +1,341 @@
+use std::io::Read;
+use std::io::Seek;
+
+use super::error::WadError;
+
+pub const HEADER_SIZE: u64 = 12;
+pub const DIRECTORY_ENTRY_SIZE: u64 = 16;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WadKind {
+    Iwad,
+    Pwad,
+    SingleLump,
+    Archive,
+}
+
+#[derive(Clone, Debug)]
+pub struct DirectoryEntry {
+    pub position: u64,
+    pub size: usize,
+    pub name: [u8; 8],
+}
+
+enum Storage {
+    File {
+        handle: std::fs::File,
+        mapping: Option<memmap2::Mmap>,
+    },
+    Memory(Vec<u8>),
+}
+
+pub struct WadFile {
+    pub path: String,
+    pub kind: WadKind,
+    pub length: u64,
+    storage: Storage,
+    archive_directory: Vec<DirectoryEntry>,
+}
+
+impl WadFile {
+    pub fn open(path: &str) -> Result<Self, WadError> {
+        let io_error = |source| WadError::Io {
+            file_name: path.to_owned(),
+            source,
+        };
+
+        let lower = path.to_lowercase();
+        if lower.ends_with(".pk3") || lower.ends_with(".zip") {
+            let (data, archive_directory) = super::archive::read_archive(path)?;
+            return Ok(Self {
+                path: path.to_owned(),
+                kind: WadKind::Archive,
+                length: data.len() as u64,
+                storage: Storage::Memory(data),
+                archive_directory,
+            });
+        }
+
+        let handle = std::fs::File::open(path).map_err(io_error)?;
+        let length = handle.metadata().map_err(io_error)?.len();
+
+        // Safety: WAD files are treated as read-only for the lifetime of the
+        // mapping. Empty files cannot be mapped, so fall back to reads.
+        let mapping = if length > 0 {
+            unsafe { memmap2::Mmap::map(&handle) }.ok()
+        } else {
+            None
+        };
+
+        let mut file = Self {
+            path: path.to_owned(),
+            kind: WadKind::SingleLump,
+            length,
+            storage: Storage::File { handle, mapping },
+            archive_directory: vec![],
+        };
+
+        if !lower.ends_with(".wad") {
+            return Ok(file);
+        }
+
+        if length < HEADER_SIZE {
+            return Err(WadError::TruncatedHeader {
+                file_name: path.to_owned(),
+                file_size: length,
+            });
+        }
+
+        let mut identification = [0u8; 4];
+        file.read_at(0, &mut identification)?;
+        file.kind = match &identification {
+            b"IWAD" => WadKind::Iwad,
+            b"PWAD" => WadKind::Pwad,
+            _ => {
+                return Err(WadError::BadIdentification {
+                    file_name: path.to_owned(),
+                    identification,
+                })
+            }
+        };
+
+        Ok(file)
+    }
+
+    pub fn read_directory(&self) -> Result<Vec<DirectoryEntry>, WadError> {
+        match self.kind {
+            WadKind::SingleLump => Ok(vec![DirectoryEntry {
+                position: 0,
+                size: self.length as usize,
+                name: single_lump_name(&self.path),
+            }]),
+            WadKind::Archive => Ok(self.archive_directory.clone()),
+            WadKind::Iwad | WadKind::Pwad => {
+                read_wad_directory(&self.path, self.length, |position, buffer| {
+                    self.read_at(position, buffer)
+                })
+            }
+        }
+    }
+
+    pub fn is_mapped(&self) -> bool {
+        match &self.storage {
+            Storage::File { mapping, .. } => mapping.is_some(),
+            Storage::Memory(_) => false,
+        }
+    }
+
+    pub fn mapped(&self, position: u64, size: usize) -> Option<&[u8]> {
+        let start = position as usize;
+        let data: &[u8] = match &self.storage {
+            Storage::File {
+                mapping: Some(mapping),
+                ..
+            } => mapping,
+            Storage::File { mapping: None, .. } => return None,
+            Storage::Memory(data) => data,
+        };
+        data.get(start..start.checked_add(size)?)
+    }
+
+    pub fn read_at(&self, position: u64, buffer: &mut [u8]) -> Result<(), WadError> {
+        if let Some(data) = self.mapped(position, buffer.len()) {
+            buffer.copy_from_slice(data);
+            return Ok(());
+        }
+
+        let io_error = |source| WadError::Io {
+            file_name: self.path.clone(),
+            source,
+        };
+
+        let mut handle = match &self.storage {
+            Storage::File { handle, .. } => handle,
+            Storage::Memory(_) => {
+                return Err(io_error(std::io::ErrorKind::UnexpectedEof.into()));
+            }
+        };
+        handle
+            .seek(std::io::SeekFrom::Start(position))
+            .map_err(io_error)?;
+        handle.read_exact(buffer).map_err(io_error)
+    }
+}
+
+pub fn read_wad_directory(
+    file_name: &str,
+    length: u64,
+    read_at: impl Fn(u64, &mut [u8]) -> Result<(), WadError>,
+) -> Result<Vec<DirectoryEntry>, WadError> {
+    let mut header = [0u8; HEADER_SIZE as usize];
+    read_at(0, &mut header)?;
+    let num_lumps = read_i32(&header[4..8]);
+    let info_table_offset = read_i32(&header[8..12]);
+
+    if num_lumps < 0 || info_table_offset < 0 {
+        return Err(WadError::BadDirectory {
+            file_name: file_name.to_owned(),
+            num_lumps,
+            info_table_offset,
+        });
+    }
+
+    let directory_end = info_table_offset as u64 + num_lumps as u64 * DIRECTORY_ENTRY_SIZE;
+    if directory_end > length {
+        return Err(WadError::TruncatedDirectory {
+            file_name: file_name.to_owned(),
+            directory_end,
+            file_size: length,
+        });
+    }
+
+    let mut directory = vec![0u8; (num_lumps as u64 * DIRECTORY_ENTRY_SIZE) as usize];
+    read_at(info_table_offset as u64, &mut directory)?;
+
+    directory
+        .chunks_exact(DIRECTORY_ENTRY_SIZE as usize)
+        .map(|entry| {
+            let mut name = [0u8; 8];
+            name.copy_from_slice(&entry[8..16]);
+            let size = read_i32(&entry[4..8]);
+            if size < 0 {
+                return Err(WadError::BadLumpSize {
+                    file_name: file_name.to_owned(),
+                    lump_name: super::name_to_string(&name),
+                    size,
+                });
+            }
+            Ok(DirectoryEntry {
+                position: read_i32(&entry[0..4]) as u32 as u64,
+                size: size as usize,
+                name,
+            })
+        })
+        .collect()
+}
+
+fn read_i32(bytes: &[u8]) -> i32 {
+    i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn single_lump_name(path: &str) -> [u8; 8] {
+    let base = std::path::Path::new(path)
+        .file_stem()
+        .map(|b| b.to_string_lossy().to_uppercase())
+        .unwrap_or_default();
+
+    super::normalize_name(base.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wad::testing::TempFile;
+
+    fn header(num_lumps: i32, info_table_offset: i32) -> Vec<u8> {
+        let mut data = b"PWAD".to_vec();
+        data.extend_from_slice(&num_lumps.to_le_bytes());
+        data.extend_from_slice(&info_table_offset.to_le_bytes());
+        data
+    }
+
+    fn entry(data: &mut Vec<u8>, position: i32, size: i32, name: &str) {
+        data.extend_from_slice(&position.to_le_bytes());
+        data.extend_from_slice(&size.to_le_bytes());
+        data.extend_from_slice(&crate::wad::normalize_name(name.as_bytes()));
+    }
+
+    fn directory(data: &[u8]) -> Result<Vec<DirectoryEntry>, WadError> {
+        read_wad_directory("TEST.WAD", data.len() as u64, |position, buffer| {
+            let start = position as usize;
+            buffer.copy_from_slice(&data[start..start + buffer.len()]);
+            Ok(())
+        })
+    }
+
+    #[test]
+    fn reads_the_directory() {
+        let mut data = header(2, 16);
+        data.extend_from_slice(b"DATA");
+        entry(&mut data, 12, 4, "THING");
+        entry(&mut data, 0, 0, "MARKER");
+
+        let directory = directory(&data).unwrap();
+        assert_eq!(directory.len(), 2);
+        assert_eq!((directory[0].position, directory[0].size), (12, 4));
+        assert_eq!(&directory[1].name, b"MARKER\0\0");
+    }
+
+    #[test]
+    fn rejects_a_negative_directory() {
+        match directory(&header(-1, 12)) {
+            Err(WadError::BadDirectory {
+                num_lumps: -1,
+                info_table_offset: 12,
+                ..
+            }) => {}
+            other => panic!("{:?}", other.map(|d| d.len())),
+        }
+    }
+
+    #[test]
+    fn rejects_a_directory_past_the_end() {
+        let mut data = header(2, 12);
+        entry(&mut data, 0, 0, "MARKER");
+        match directory(&data) {
+            Err(WadError::TruncatedDirectory {
+                directory_end: 44,
+                file_size: 28,
+                ..
+            }) => {}
+            other => panic!("{:?}", other.map(|d| d.len())),
+        }
+    }
+
+    #[test]
+    fn rejects_a_negative_lump_size() {
+        let mut data = header(1, 12);
+        entry(&mut data, 12, -4, "THING");
+        match directory(&data) {
+            Err(WadError::BadLumpSize {
+                lump_name, size, ..
+            }) => {
+                assert_eq!((lump_name.as_str(), size), ("THING", -4));
+            }
+            other => panic!("{:?}", other.map(|d| d.len())),
+        }
+    }
+
+    #[test]
+    fn rejects_a_truncated_header() {
+        let file = TempFile::new("wad", b"PWAD\x01\0");
+        match WadFile::open(&file.path) {
+            Err(WadError::TruncatedHeader { file_size: 6, .. }) => {}
+            Err(e) => panic!("{}", e),
+            Ok(_) => panic!("opened a truncated WAD"),
+        }
+    }
+
+    #[test]
+    fn rejects_an_unknown_identification() {
+        let mut data = header(0, 12);
+        data[..4].copy_from_slice(b"ZWAD");
+        let file = TempFile::new("wad", &data);
+        match WadFile::open(&file.path) {
+            Err(WadError::BadIdentification { identification, .. }) => {
+                assert_eq!(&identification, b"ZWAD");
+            }
+            Err(e) => panic!("{}", e),
+            Ok(_) => panic!("opened a WAD with a bad id"),
+        }
+    }
+
+    #[test]
+    fn other_files_are_single_lumps() {
+        let file = TempFile::new("lmp", b"some data");
+        let wad = WadFile::open(&file.path).unwrap();
+        assert_eq!(wad.kind, WadKind::SingleLump);
+        let directory = wad.read_directory().unwrap();
+        assert_eq!(directory[0].size, 9);
+    }
+}
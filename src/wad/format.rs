@@ -0,0 +1,166 @@
+use super::error::WadError;
+
+pub const FLAT_SIZE: usize = 64 * 64;
+
+pub trait Cacheable {
+    fn cache_size(&self) -> usize;
+}
+
+pub struct Post {
+    pub top_delta: u8,
+    pub pixels: Vec<u8>,
+}
+
+pub struct Patch {
+    pub width: i16,
+    pub height: i16,
+    pub left_offset: i16,
+    pub top_offset: i16,
+    pub columns: Vec<Vec<Post>>,
+}
+
+pub struct Flat {
+    pub pixels: Vec<u8>,
+}
+
+pub struct Sound {
+    pub sample_rate: u32,
+    pub samples: Vec<u8>,
+}
+
+impl Patch {
+    pub fn decode(lump_name: &str, data: &[u8]) -> Result<Self, WadError> {
+        if data.len() < 8 {
+            return Err(bad_lump(lump_name, "too short for a patch header"));
+        }
+
+        let width = read_i16(&data[0..2]);
+        let height = read_i16(&data[2..4]);
+        if width <= 0 || height < 0 || data.len() < 8 + width as usize * 4 {
+            return Err(bad_lump(
+                lump_name,
+                format!("bad patch dimensions {}x{}", width, height),
+            ));
+        }
+
+        let columns = (0..width as usize)
+            .map(|x| {
+                let offset = 8 + x * 4;
+                let column_offset = read_i32(&data[offset..offset + 4]) as u32 as usize;
+                decode_column(lump_name, data, column_offset)
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            width,
+            height,
+            left_offset: read_i16(&data[4..6]),
+            top_offset: read_i16(&data[6..8]),
+            columns,
+        })
+    }
+}
+
+impl Cacheable for Patch {
+    fn cache_size(&self) -> usize {
+        self.columns
+            .iter()
+            .flatten()
+            .map(|post| post.pixels.len() + std::mem::size_of::<Post>())
+            .sum::<usize>()
+            + self.columns.len() * std::mem::size_of::<Vec<Post>>()
+    }
+}
+
+impl Flat {
+    pub fn decode(lump_name: &str, data: &[u8]) -> Result<Self, WadError> {
+        if data.len() < FLAT_SIZE {
+            return Err(bad_lump(
+                lump_name,
+                format!("flat is {} bytes, expected {}", data.len(), FLAT_SIZE),
+            ));
+        }
+
+        Ok(Self {
+            pixels: data[..FLAT_SIZE].to_vec(),
+        })
+    }
+}
+
+impl Cacheable for Flat {
+    fn cache_size(&self) -> usize {
+        self.pixels.len()
+    }
+}
+
+impl Sound {
+    pub fn decode(lump_name: &str, data: &[u8]) -> Result<Self, WadError> {
+        if data.len() < 8 || data[0] != 0x03 || data[1] != 0x00 {
+            return Err(bad_lump(lump_name, "not a DMX sound"));
+        }
+
+        let sample_rate = u16::from_le_bytes([data[2], data[3]]) as u32;
+        let length = read_i32(&data[4..8]) as u32 as usize;
+
+        // The sample count includes 16 bytes of padding at either end.
+        if length > data.len() - 8 || length <= 48 {
+            return Err(bad_lump(
+                lump_name,
+                format!("bad sample count {} in {} byte lump", length, data.len()),
+            ));
+        }
+
+        Ok(Self {
+            sample_rate,
+            samples: data[24..24 + length - 32].to_vec(),
+        })
+    }
+}
+
+impl Cacheable for Sound {
+    fn cache_size(&self) -> usize {
+        self.samples.len()
+    }
+}
+
+fn decode_column(lump_name: &str, data: &[u8], mut offset: usize) -> Result<Vec<Post>, WadError> {
+    let mut posts = vec![];
+
+    loop {
+        let top_delta = *data
+            .get(offset)
+            .ok_or_else(|| bad_lump(lump_name, "column runs past end of lump"))?;
+        if top_delta == 0xff {
+            return Ok(posts);
+        }
+
+        let length = *data
+            .get(offset + 1)
+            .ok_or_else(|| bad_lump(lump_name, "column runs past end of lump"))?
+            as usize;
+        let pixels = data
+            .get(offset + 3..offset + 3 + length)
+            .ok_or_else(|| bad_lump(lump_name, "post runs past end of lump"))?;
+
+        posts.push(Post {
+            top_delta,
+            pixels: pixels.to_vec(),
+        });
+        offset += length + 4;
+    }
+}
+
+fn bad_lump<S: Into<String>>(lump_name: &str, why: S) -> WadError {
+    WadError::BadLump {
+        lump_name: lump_name.to_owned(),
+        why: why.into(),
+    }
+}
+
+pub fn read_i16(bytes: &[u8]) -> i16 {
+    i16::from_le_bytes([bytes[0], bytes[1]])
+}
+
+pub fn read_i32(bytes: &[u8]) -> i32 {
+    i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
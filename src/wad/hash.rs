@@ -0,0 +1,78 @@
+use std::io::Read;
+
+use super::error::WadError;
+use crate::sha1::Digest;
+
+impl super::Wad {
+    pub fn lump_sha1(&self, lump: usize) -> Result<Digest, WadError> {
+        self.lump_data(lump).map(|data| crate::sha1::digest(&data))
+    }
+
+    // Hashes the file as it is on disk, so archives get the digest of the
+    // zip itself rather than of what was unpacked from it.
+    pub fn file_sha1(&self, file: usize) -> Result<Digest, WadError> {
+        let path = &self.files[file].path;
+        let io_error = |source| WadError::Io {
+            file_name: path.clone(),
+            source,
+        };
+
+        let mut handle = std::fs::File::open(path).map_err(io_error)?;
+        let mut sha1 = crate::sha1::Sha1::new();
+        let mut buffer = vec![0u8; 64 * 1024];
+        loop {
+            let read = handle.read(&mut buffer).map_err(io_error)?;
+            if read == 0 {
+                break;
+            }
+            sha1.update(&buffer[..read]);
+        }
+
+        Ok(sha1.finish())
+    }
+
+    // One digest for the whole lump directory as the game sees it, merges
+    // included: every lump's name, followed by the digest of its data.
+    pub fn resource_sha1(&self) -> Result<Digest, WadError> {
+        let mut sha1 = crate::sha1::Sha1::new();
+
+        for (lump, info) in self.lumps.iter().enumerate() {
+            sha1.update(&info.name);
+            sha1.update(&self.lump_sha1(lump)?);
+        }
+
+        Ok(sha1.finish())
+    }
+}
+
+impl crate::state::State {
+    pub fn wad_hashes_mode(&self, list_lumps: bool) -> ! {
+        let hex = |digest: Result<Digest, WadError>| {
+            digest
+                .map(|d| crate::sha1::to_hex(&d))
+                .unwrap_or_else(|e| self.error(e.to_string()))
+        };
+
+        println!("WAD files:");
+        for (file, wad_file) in self.wad.files.iter().enumerate() {
+            println!("  {}  {}", hex(self.wad.file_sha1(file)), wad_file.path);
+        }
+
+        if list_lumps {
+            println!("Lumps:");
+            for (lump, info) in self.wad.lumps.iter().enumerate() {
+                println!(
+                    "  [{:>5}] {:<8}  {}  {}",
+                    lump,
+                    info.name(),
+                    hex(self.wad.lump_sha1(lump)),
+                    self.wad.files[info.wad_file].path
+                );
+            }
+        }
+
+        println!("Resource set: {}", hex(self.wad.resource_sha1()));
+
+        std::process::exit(0);
+    }
+}
@@ -0,0 +1,87 @@
+use super::merge::NwtMergeFlags;
+
+impl crate::state::State {
+    pub fn parse_command_line_wads(&mut self) -> bool {
+        let mut modified_game = false;
+
+        for file_name in self.parm_files("-merge") {
+            modified_game = true;
+            println!(" merging {}", file_name);
+            self.merge_wad_file(&file_name, |wad, path| wad.merge_file(path));
+        }
+
+        for file_name in self.parm_files("-nwtmerge") {
+            modified_game = true;
+            println!(" performing NWT-style merge of {}", file_name);
+            self.merge_wad_file(&file_name, |wad, path| wad.nwt_dash_merge(path));
+            self.add_wad_file(&file_name);
+        }
+
+        let nwt_merges = [
+            ("-af", "flats", true, false),
+            ("-as", "sprites", false, true),
+            ("-aa", "sprites and flats", true, true),
+        ];
+        for &(parm, what, flats, sprites) in nwt_merges.iter() {
+            for file_name in self.parm_files(parm) {
+                modified_game = true;
+                println!(" merging {} from {}", what, file_name);
+                self.merge_wad_file(&file_name, |wad, path| {
+                    wad.nwt_merge_file(path, NwtMergeFlags { flats, sprites })
+                });
+            }
+        }
+
+        for file_name in self.parm_files("-file") {
+            modified_game = true;
+            self.add_wad_file(&file_name);
+        }
+
+        modified_game
+    }
+
+    pub fn autoload_wads(&mut self) {
+        for dir in self.autoload_dirs.clone() {
+            for file_name in autoload_files(&dir, &["wad", "lmp"]) {
+                self.add_wad_file(&file_name);
+            }
+        }
+    }
+
+    fn merge_wad_file(
+        &mut self,
+        file_name: &str,
+        merge: impl Fn(&mut super::Wad, &str) -> Result<(), super::error::WadError>,
+    ) {
+        match merge(&mut self.wad, file_name) {
+            Ok(()) => {}
+            Err(e @ super::error::WadError::MissingSection { .. }) => {
+                self.error(format!("Failed to merge {}: {}", file_name, e))
+            }
+            Err(e) => eprintln!(" couldn't merge {}: {}", file_name, e),
+        }
+    }
+}
+
+pub fn autoload_files(dir: &str, extensions: &[&str]) -> Vec<String> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return vec![],
+    };
+
+    let mut files = entries
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .map(|e| extensions.iter().any(|x| e.eq_ignore_ascii_case(x)))
+                    .unwrap_or(false)
+        })
+        .map(|path| path.to_string_lossy().into_owned())
+        .collect::<Vec<_>>();
+    files.sort();
+
+    files
+}
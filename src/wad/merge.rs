@@ -0,0 +1,534 @@
+use super::error::WadError;
+use super::LumpInfo;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Section {
+    Normal,
+    Flats,
+    Sprites,
+}
+
+#[derive(Clone, Copy)]
+pub struct NwtMergeFlags {
+    pub flats: bool,
+    pub sprites: bool,
+}
+
+struct MergeLists {
+    iwad_flats: std::ops::Range<usize>,
+    iwad_sprites: std::ops::Range<usize>,
+    pwad_flats: std::ops::Range<usize>,
+    pwad_sprites: std::ops::Range<usize>,
+}
+
+type SpriteFrames = std::collections::HashMap<([u8; 4], u8), [Option<usize>; 8]>;
+
+impl super::Wad {
+    pub fn merge_file(&mut self, path: &str) -> Result<(), WadError> {
+        let old_num_lumps = self.lumps.len();
+        self.add_file(path)?;
+
+        let lists = self.setup_lists(old_num_lumps)?;
+        let sprite_frames = self.generate_sprite_list(&lists);
+
+        let sprite_lump_needed =
+            |lump: usize| sprite_lump_needed(&sprite_frames, &self.lumps[lump].name, lump);
+
+        let mut new_lumps = Vec::with_capacity(self.lumps.len());
+        let mut current_section = Section::Normal;
+
+        for lump in 0..old_num_lumps {
+            let name = &self.lumps[lump].name;
+            match current_section {
+                Section::Normal => {
+                    if name_is(name, "F_START") {
+                        current_section = Section::Flats;
+                    } else if name_is(name, "S_START") {
+                        current_section = Section::Sprites;
+                    }
+                    new_lumps.push(lump);
+                }
+                Section::Flats => {
+                    if name_is(name, "F_END") {
+                        new_lumps.extend(lists.pwad_flats.clone());
+                        new_lumps.push(lump);
+                        current_section = Section::Normal;
+                    } else if self.find_in_list(&lists.pwad_flats, name).is_none() {
+                        new_lumps.push(lump);
+                    }
+                }
+                Section::Sprites => {
+                    if name_is(name, "S_END") {
+                        new_lumps.extend(
+                            lists
+                                .pwad_sprites
+                                .clone()
+                                .filter(|&l| sprite_lump_needed(l)),
+                        );
+                        new_lumps.push(lump);
+                        current_section = Section::Normal;
+                    } else if sprite_lump_needed(lump) {
+                        new_lumps.push(lump);
+                    }
+                }
+            }
+        }
+
+        current_section = Section::Normal;
+        for lump in old_num_lumps..self.lumps.len() {
+            let name = &self.lumps[lump].name;
+            match current_section {
+                Section::Normal => {
+                    if name_is(name, "F_START") || name_is(name, "FF_START") {
+                        current_section = Section::Flats;
+                    } else if name_is(name, "S_START") || name_is(name, "SS_START") {
+                        current_section = Section::Sprites;
+                    } else {
+                        new_lumps.push(lump);
+                    }
+                }
+                Section::Flats => {
+                    if name_is(name, "F_END") || name_is(name, "FF_END") {
+                        current_section = Section::Normal;
+                    }
+                }
+                Section::Sprites => {
+                    if name_is(name, "S_END") || name_is(name, "SS_END") {
+                        current_section = Section::Normal;
+                    }
+                }
+            }
+        }
+
+        self.lumps = new_lumps
+            .into_iter()
+            .map(|lump| self.lumps[lump].clone())
+            .collect();
+        self.rebuild_lookup();
+
+        Ok(())
+    }
+
+    pub fn nwt_merge_file(&mut self, path: &str, flags: NwtMergeFlags) -> Result<(), WadError> {
+        let old_num_lumps = self.lumps.len();
+        self.add_file(path)?;
+
+        let lists = self.setup_lists(old_num_lumps)?;
+
+        if flags.flats {
+            self.nwt_add_lumps(&lists.iwad_flats, old_num_lumps);
+        }
+        if flags.sprites {
+            self.nwt_add_lumps(&lists.iwad_sprites, old_num_lumps);
+        }
+
+        self.lumps.truncate(old_num_lumps);
+        self.rebuild_lookup();
+
+        Ok(())
+    }
+
+    pub fn nwt_dash_merge(&mut self, path: &str) -> Result<(), WadError> {
+        let old_num_lumps = self.lumps.len();
+        self.add_file(path)?;
+
+        let lists = self.setup_lists(old_num_lumps)?;
+        let pwad = old_num_lumps..self.lumps.len();
+
+        for lump in lists.iwad_sprites {
+            if self.find_in_list(&pwad, &self.lumps[lump].name).is_some() {
+                // This is what nwt -merge does; the PWAD must then be added
+                // again with -file.
+                self.lumps[lump].name = [0; 8];
+            }
+        }
+
+        self.lumps.truncate(old_num_lumps);
+        self.files.pop();
+        self.rebuild_lookup();
+
+        Ok(())
+    }
+
+    // Replaces lumps in an IWAD section with lumps of the same name from
+    // anywhere in the PWAD. As in Chocolate Doom, a match on the PWAD's very
+    // first lump is never used.
+    fn nwt_add_lumps(&mut self, list: &std::ops::Range<usize>, num_iwad_lumps: usize) {
+        let pwad = num_iwad_lumps..self.lumps.len();
+        for lump in list.clone() {
+            let name = self.lumps[lump].name;
+            if let Some(index) = self.find_in_list(&pwad, &name) {
+                if index > pwad.start {
+                    self.lumps[lump] = self.lumps[index].clone();
+                }
+            }
+        }
+    }
+
+    fn setup_lists(&self, num_iwad_lumps: usize) -> Result<MergeLists, WadError> {
+        let iwad = 0..num_iwad_lumps;
+        let pwad = num_iwad_lumps..self.lumps.len();
+        let missing_section = |start: &'static str, end: &'static str| WadError::MissingSection {
+            file_name: self.files[0].path.clone(),
+            start,
+            end,
+        };
+
+        let iwad_flats = self
+            .find_section_markers(&iwad, "F_START", "F_END")
+            .ok_or_else(|| missing_section("F_START", "F_END"))?;
+        let iwad_sprites = self
+            .find_section_markers(&iwad, "S_START", "S_END")
+            .ok_or_else(|| missing_section("S_START", "S_END"))?;
+
+        let pwad_flats = self
+            .find_section_markers(&pwad, "F_START", "F_END")
+            .or_else(|| self.find_section_markers(&pwad, "FF_START", "FF_END"))
+            .unwrap_or(0..0);
+        let pwad_sprites = self
+            .find_section_markers(&pwad, "S_START", "S_END")
+            .or_else(|| self.find_section_markers(&pwad, "SS_START", "SS_END"))
+            .unwrap_or(0..0);
+
+        Ok(MergeLists {
+            iwad_flats,
+            iwad_sprites,
+            pwad_flats,
+            pwad_sprites,
+        })
+    }
+
+    fn find_section_markers(
+        &self,
+        list: &std::ops::Range<usize>,
+        start: &str,
+        end: &str,
+    ) -> Option<std::ops::Range<usize>> {
+        let first = list
+            .clone()
+            .find(|&lump| name_is(&self.lumps[lump].name, start))?;
+        let last = (first + 1..list.end).find(|&lump| name_is(&self.lumps[lump].name, end))?;
+
+        Some(first + 1..last)
+    }
+
+    // The first lump in the list with this name.
+    fn find_in_list(&self, list: &std::ops::Range<usize>, name: &[u8; 8]) -> Option<usize> {
+        let name = super::normalize_name(name);
+        list.clone()
+            .find(|&lump| super::normalize_name(&self.lumps[lump].name) == name)
+    }
+
+    fn generate_sprite_list(&self, lists: &MergeLists) -> SpriteFrames {
+        let mut sprite_frames = SpriteFrames::new();
+
+        for lump in lists.iwad_sprites.clone().chain(lists.pwad_sprites.clone()) {
+            add_sprite_lump(&mut sprite_frames, &self.lumps[lump], lump);
+        }
+
+        sprite_frames
+    }
+}
+
+fn name_is(name: &[u8; 8], marker: &str) -> bool {
+    super::normalize_name(name) == super::normalize_name(marker.as_bytes())
+}
+
+fn valid_sprite_lump_name(name: &[u8; 8]) -> bool {
+    let valid_angle = |c: u8| (b'0'..=b'8').contains(&c);
+
+    name[..4].iter().all(|&c| c != 0)
+        && name[4] != 0
+        && valid_angle(name[5])
+        && (name[6] == 0 || valid_angle(name[7]))
+}
+
+fn sprite_frame_key(name: &[u8; 8], frame: u8) -> ([u8; 4], u8) {
+    let mut sprite_name = [0u8; 4];
+    for (dest, src) in sprite_name.iter_mut().zip(name.iter()) {
+        *dest = src.to_ascii_uppercase();
+    }
+    (sprite_name, frame.to_ascii_uppercase())
+}
+
+fn add_sprite_lump(sprite_frames: &mut SpriteFrames, info: &LumpInfo, lump: usize) {
+    let name = &info.name;
+    if !valid_sprite_lump_name(name) {
+        return;
+    }
+
+    let mut add_frame = |frame: u8, angle: u8| {
+        let angle_lumps = sprite_frames
+            .entry(sprite_frame_key(name, frame))
+            .or_insert([None; 8]);
+        match angle - b'0' {
+            0 => *angle_lumps = [Some(lump); 8],
+            angle_num => angle_lumps[angle_num as usize - 1] = Some(lump),
+        }
+    };
+
+    add_frame(name[4], name[5]);
+    if name[6] != 0 {
+        add_frame(name[6], name[7]);
+    }
+}
+
+fn sprite_lump_needed(sprite_frames: &SpriteFrames, name: &[u8; 8], lump: usize) -> bool {
+    if !valid_sprite_lump_name(name) {
+        return true;
+    }
+
+    let frame_uses_lump = |frame: u8, angle: u8| {
+        let angle_lumps = match sprite_frames.get(&sprite_frame_key(name, frame)) {
+            Some(angle_lumps) => angle_lumps,
+            None => return false,
+        };
+        match angle - b'0' {
+            0 => angle_lumps.contains(&Some(lump)),
+            angle_num => angle_lumps[angle_num as usize - 1] == Some(lump),
+        }
+    };
+
+    frame_uses_lump(name[4], name[5]) || (name[6] != 0 && frame_uses_lump(name[6], name[7]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wad::file::WadKind;
+    use crate::wad::testing::TempFile;
+    use crate::wad::Wad;
+
+    fn iwad() -> TempFile {
+        TempFile::wad(
+            WadKind::Iwad,
+            &[
+                ("PLAYPAL", b"iwad"),
+                ("COLORMAP", b"iwad"),
+                ("F_START", b""),
+                ("FLOOR1", b"iwad"),
+                ("FLOOR2", b"iwad"),
+                ("F_END", b""),
+                ("S_START", b""),
+                ("TROOA1", b"iwad"),
+                ("TROOB1", b"iwad"),
+                ("S_END", b""),
+            ],
+        )
+    }
+
+    fn load(iwad: &TempFile) -> Wad {
+        let mut wad = Wad::default();
+        wad.add_file(&iwad.path).unwrap();
+        wad
+    }
+
+    // Each lump's name and which file its data came from.
+    fn lumps(wad: &Wad) -> Vec<(String, String)> {
+        (0..wad.num_lumps())
+            .map(|lump| {
+                let data = wad.read_lump(lump).unwrap();
+                (wad.lumps[lump].name(), String::from_utf8(data).unwrap())
+            })
+            .collect()
+    }
+
+    fn lumps_named(wad: &Wad, names: &[&str]) -> Vec<(String, String)> {
+        lumps(wad)
+            .into_iter()
+            .filter(|(name, _)| names.contains(&name.as_str()))
+            .collect()
+    }
+
+    fn pairs(expected: &[(&str, &str)]) -> Vec<(String, String)> {
+        expected
+            .iter()
+            .map(|&(name, data)| (name.to_owned(), data.to_owned()))
+            .collect()
+    }
+
+    fn nwt_pwad() -> TempFile {
+        TempFile::wad(
+            WadKind::Pwad,
+            &[
+                // The first lump is never merged.
+                ("FLOOR2", b"first"),
+                ("PLAYPAL", b"pwad"),
+                ("FF_START", b""),
+                ("FLOOR1", b"pwad"),
+                ("COLORMAP", b"pwad"),
+                ("FF_END", b""),
+                ("SS_START", b""),
+                ("TROOA1", b"pwad"),
+                ("SS_END", b""),
+            ],
+        )
+    }
+
+    #[test]
+    fn merge_replaces_and_adds_flats_and_sprites() {
+        let iwad = iwad();
+        let pwad = TempFile::wad(
+            WadKind::Pwad,
+            &[
+                ("MAP01", b"pwad"),
+                ("F_START", b""),
+                ("FLOOR1", b"pwad"),
+                ("NEWFLAT", b"pwad"),
+                ("F_END", b""),
+                ("S_START", b""),
+                ("TROOA1", b"pwad"),
+                ("S_END", b""),
+            ],
+        );
+        let mut wad = load(&iwad);
+        wad.merge_file(&pwad.path).unwrap();
+
+        let names: Vec<_> = lumps(&wad).into_iter().map(|(name, _)| name).collect();
+        assert_eq!(
+            names,
+            [
+                "PLAYPAL", "COLORMAP", "F_START", "FLOOR2", "FLOOR1", "NEWFLAT", "F_END",
+                "S_START", "TROOB1", "TROOA1", "S_END", "MAP01",
+            ]
+        );
+        assert_eq!(
+            lumps_named(&wad, &["FLOOR1", "FLOOR2", "TROOA1", "TROOB1"]),
+            pairs(&[
+                ("FLOOR2", "iwad"),
+                ("FLOOR1", "pwad"),
+                ("TROOB1", "iwad"),
+                ("TROOA1", "pwad"),
+            ])
+        );
+        assert_eq!(
+            wad.read_lump(wad.check_num_for_name("FLOOR1").unwrap())
+                .unwrap(),
+            b"pwad"
+        );
+    }
+
+    #[test]
+    fn merge_needs_iwad_sections() {
+        let iwad = TempFile::wad(WadKind::Iwad, &[("PLAYPAL", b"iwad")]);
+        let pwad = nwt_pwad();
+        let mut wad = load(&iwad);
+        match wad.merge_file(&pwad.path) {
+            Err(WadError::MissingSection {
+                start: "F_START", ..
+            }) => {}
+            other => panic!("{:?}", other),
+        }
+    }
+
+    #[test]
+    fn nwt_merge_flats_only_replaces_iwad_flats() {
+        let iwad = iwad();
+        let pwad = nwt_pwad();
+        let mut wad = load(&iwad);
+        wad.nwt_merge_file(
+            &pwad.path,
+            NwtMergeFlags {
+                flats: true,
+                sprites: false,
+            },
+        )
+        .unwrap();
+
+        // PLAYPAL and COLORMAP are outside the IWAD's flats, so the PWAD's
+        // lumps of those names are left alone, and nothing is added.
+        assert_eq!(
+            lumps(&wad),
+            pairs(&[
+                ("PLAYPAL", "iwad"),
+                ("COLORMAP", "iwad"),
+                ("F_START", ""),
+                ("FLOOR1", "pwad"),
+                ("FLOOR2", "iwad"),
+                ("F_END", ""),
+                ("S_START", ""),
+                ("TROOA1", "iwad"),
+                ("TROOB1", "iwad"),
+                ("S_END", ""),
+            ])
+        );
+    }
+
+    #[test]
+    fn nwt_merge_sprites_only_replaces_iwad_sprites() {
+        let iwad = iwad();
+        let pwad = nwt_pwad();
+        let mut wad = load(&iwad);
+        wad.nwt_merge_file(
+            &pwad.path,
+            NwtMergeFlags {
+                flats: false,
+                sprites: true,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            lumps_named(&wad, &["FLOOR1", "FLOOR2", "TROOA1", "TROOB1"]),
+            pairs(&[
+                ("FLOOR1", "iwad"),
+                ("FLOOR2", "iwad"),
+                ("TROOA1", "pwad"),
+                ("TROOB1", "iwad"),
+            ])
+        );
+    }
+
+    #[test]
+    fn nwt_merge_all_replaces_both() {
+        let iwad = iwad();
+        let pwad = nwt_pwad();
+        let mut wad = load(&iwad);
+        wad.nwt_merge_file(
+            &pwad.path,
+            NwtMergeFlags {
+                flats: true,
+                sprites: true,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(wad.num_lumps(), 10);
+        assert_eq!(
+            lumps_named(&wad, &["PLAYPAL", "FLOOR1", "FLOOR2", "TROOA1", "TROOB1"]),
+            pairs(&[
+                ("PLAYPAL", "iwad"),
+                ("FLOOR1", "pwad"),
+                ("FLOOR2", "iwad"),
+                ("TROOA1", "pwad"),
+                ("TROOB1", "iwad"),
+            ])
+        );
+    }
+
+    #[test]
+    fn nwt_dash_merge_hides_replaced_iwad_sprites() {
+        let iwad = iwad();
+        let pwad = nwt_pwad();
+        let mut wad = load(&iwad);
+        wad.nwt_dash_merge(&pwad.path).unwrap();
+
+        assert_eq!(wad.num_lumps(), 10);
+        assert_eq!(wad.files.len(), 1);
+        assert!(wad.check_num_for_name("TROOA1").is_none());
+        assert!(wad.check_num_for_name("TROOB1").is_some());
+        assert_eq!(
+            wad.read_lump(wad.check_num_for_name("FLOOR1").unwrap())
+                .unwrap(),
+            b"iwad"
+        );
+
+        // The PWAD is then added as with -file.
+        wad.add_file(&pwad.path).unwrap();
+        assert_eq!(
+            wad.read_lump(wad.check_num_for_name("TROOA1").unwrap())
+                .unwrap(),
+            b"pwad"
+        );
+    }
+}
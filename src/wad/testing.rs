@@ -0,0 +1,66 @@
+// A file in the temporary directory that is deleted when it goes out of
+// scope, for tests that need to open files by path.
+pub struct TempFile {
+    pub path: String,
+}
+
+fn temp_path(extension: &str) -> String {
+    static NEXT: std::sync::atomic::AtomicUsize = std::sync::atomic::AtomicUsize::new(0);
+
+    let path = std::env::temp_dir().join(format!(
+        "ez_doom_wad_test_{}_{}.{}",
+        std::process::id(),
+        NEXT.fetch_add(1, std::sync::atomic::Ordering::Relaxed),
+        extension
+    ));
+    path.to_str().unwrap().to_owned()
+}
+
+impl TempFile {
+    pub fn new(extension: &str, data: &[u8]) -> Self {
+        let path = temp_path(extension);
+        std::fs::write(&path, data).unwrap();
+        Self { path }
+    }
+
+    // A WAD with these lumps, written by the same writer the wad tool uses.
+    pub fn wad(kind: super::file::WadKind, lumps: &[(&str, &[u8])]) -> Self {
+        let file = Self::new("wad", &[]);
+        let mut writer = super::writer::WadWriter::new(kind);
+        for (name, data) in lumps {
+            writer.add_lump(name, data.to_vec());
+        }
+        writer.write(&file.path).unwrap();
+        file
+    }
+}
+
+impl Drop for TempFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+// A directory that is deleted, with everything in it, when it goes out of
+// scope.
+pub struct TempDir {
+    pub path: std::path::PathBuf,
+}
+
+impl TempDir {
+    pub fn create() -> Self {
+        let path = std::path::PathBuf::from(temp_path("d"));
+        std::fs::create_dir(&path).unwrap();
+        Self { path }
+    }
+
+    pub fn join(&self, path: &str) -> std::path::PathBuf {
+        self.path.join(path)
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.path);
+    }
+}
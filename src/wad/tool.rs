@@ -0,0 +1,539 @@
+const USAGE: &str = "usage: ez_doom wad list <file.wad>
+       ez_doom wad extract <file.wad> <directory> [lump...]
+       ez_doom wad pack <directory> <file.wad>
+       ez_doom wad nodes <in.wad> <out.wad> [--force]";
+
+const NAMESPACES: [(&str, &[&str], &[&str]); 3] = [
+    ("patches", &["P_START", "PP_START"], &["P_END", "PP_END"]),
+    ("flats", &["F_START", "FF_START"], &["F_END", "FF_END"]),
+    ("sprites", &["S_START", "SS_START"], &["S_END", "SS_END"]),
+];
+
+impl crate::state::State {
+    pub fn wad_tool(&mut self) -> ! {
+        let args = self.args[2..].to_vec();
+
+        match args.first().map(|s| s.as_str()) {
+            Some("list") if args.len() == 2 => self.wad_tool_list(&args[1]),
+            Some("extract") if args.len() >= 3 => {
+                self.wad_tool_extract(&args[1], &args[2], &args[3..])
+            }
+            Some("pack") if args.len() == 3 => self.wad_tool_pack(&args[1], &args[2]),
+            Some("nodes") if args.len() == 3 => self.wad_tool_nodes(&args[1], &args[2], false),
+            Some("nodes") if args.len() == 4 && args[3] == "--force" => {
+                self.wad_tool_nodes(&args[1], &args[2], true)
+            }
+            _ => self.error(USAGE),
+        }
+
+        std::process::exit(0);
+    }
+
+    fn open_tool_wad(&self, path: &str) -> super::Wad {
+        let mut wad = super::Wad::default();
+        wad.add_file(path)
+            .unwrap_or_else(|e| self.error(e.to_string()));
+        wad
+    }
+
+    fn wad_tool_list(&self, path: &str) {
+        print!("{}", lump_listing(&self.open_tool_wad(path)));
+    }
+
+    fn wad_tool_extract(&self, path: &str, out_dir: &str, names: &[String]) {
+        let wad = self.open_tool_wad(path);
+        let out_dir = std::path::Path::new(out_dir);
+
+        let mut written = std::collections::HashSet::new();
+
+        if !names.is_empty() {
+            for name in names {
+                let lump = wad
+                    .check_num_for_name(name)
+                    .unwrap_or_else(|| self.error(format!("Lump {} not found in {}", name, path)));
+                self.extract_lump(&wad, lump, out_dir, &mut written);
+            }
+            return;
+        }
+
+        let mut namespace: Option<&(&str, &[&str], &[&str])> = None;
+        let mut lump = 0;
+
+        while lump < wad.num_lumps() {
+            let name = wad.lumps[lump].name();
+
+            if let Some((ns, _, ends)) = namespace {
+                if ends.contains(&name.as_str()) {
+                    namespace = None;
+                } else if !is_marker(&name) {
+                    self.extract_lump(&wad, lump, &out_dir.join(ns), &mut written);
+                }
+                lump += 1;
+                continue;
+            }
+
+            if let Some(ns) = NAMESPACES
+                .iter()
+                .find(|(_, starts, _)| starts.contains(&name.as_str()))
+            {
+                namespace = Some(ns);
+                lump += 1;
+                continue;
+            }
+
+            if crate::data::is_map_header(&wad, lump) {
+                let map_dir = out_dir.join(lump_file_name(&name));
+                self.extract_lump(&wad, lump, &map_dir, &mut written);
+
+                let range = crate::data::map_lump_range(&wad, lump);
+                lump = range.end;
+                for map_lump in range {
+                    self.extract_lump(&wad, map_lump, &map_dir, &mut written);
+                }
+                continue;
+            }
+
+            self.extract_lump(&wad, lump, out_dir, &mut written);
+            lump += 1;
+        }
+    }
+
+    // Lumps later in the WAD win, as they do in the game, so a duplicate name
+    // overwrites the file written before it.
+    fn extract_lump(
+        &self,
+        wad: &super::Wad,
+        lump: usize,
+        dir: &std::path::Path,
+        written: &mut std::collections::HashSet<std::path::PathBuf>,
+    ) {
+        let data = wad
+            .lump_data(lump)
+            .unwrap_or_else(|e| self.error(e.to_string()));
+        let name = wad.lumps[lump].name();
+        let path = dir.join(lump_file_name(&name) + ".lmp");
+        if !written.insert(path.clone()) {
+            eprintln!(
+                "Warning: duplicate lump {} in {}, keeping the last one",
+                name,
+                dir.display()
+            );
+        }
+
+        std::fs::create_dir_all(dir)
+            .and_then(|_| std::fs::write(&path, &data))
+            .unwrap_or_else(|e| self.error(format!("Failed to write {}: {}", path.display(), e)));
+    }
+
+    fn wad_tool_pack(&self, in_dir: &str, out_path: &str) {
+        let mut writer = super::writer::WadWriter::new(super::file::WadKind::Pwad);
+        let (dirs, files) = self.sorted_dir_entries(std::path::Path::new(in_dir));
+
+        self.pack_lumps(&mut writer, &files);
+
+        for dir in &dirs {
+            let dir_name = file_name(dir).to_lowercase();
+            if NAMESPACES.iter().any(|(ns, _, _)| *ns == dir_name) {
+                continue;
+            }
+
+            let (_, lumps) = self.sorted_dir_entries(dir);
+            let is_map = lumps.iter().any(|l| {
+                let name = self.lump_name_for_file(l);
+                name == "THINGS" || name == "TEXTMAP"
+            });
+
+            if is_map {
+                self.pack_map(&mut writer, dir, lumps);
+            } else {
+                self.pack_lumps(&mut writer, &lumps);
+            }
+        }
+
+        for (ns, starts, ends) in NAMESPACES.iter() {
+            if let Some(dir) = dirs.iter().find(|d| file_name(d).eq_ignore_ascii_case(ns)) {
+                let (_, lumps) = self.sorted_dir_entries(dir);
+                writer.add_marker(starts[0]);
+                self.pack_lumps(&mut writer, &lumps);
+                writer.add_marker(ends[0]);
+            }
+        }
+
+        writer
+            .write(out_path)
+            .unwrap_or_else(|e| self.error(e.to_string()));
+        println!("Wrote {} lumps to {}", writer.num_lumps(), out_path);
+    }
+
+    fn pack_map(
+        &self,
+        writer: &mut super::writer::WadWriter,
+        dir: &std::path::Path,
+        lumps: Vec<std::path::PathBuf>,
+    ) {
+        let map_name = self.lump_name_for_file(dir);
+        let mut header = None;
+        let mut map_lumps = vec![];
+
+        for path in lumps {
+            let name = self.lump_name_for_file(&path);
+            if name == map_name {
+                header = Some(path);
+            } else {
+                map_lumps.push((name, path));
+            }
+        }
+
+        let is_udmf = map_lumps.iter().any(|(name, _)| name == "TEXTMAP");
+        let order = |name: &str| {
+            if is_udmf {
+                match name {
+                    "TEXTMAP" => 0,
+                    "ENDMAP" => 2,
+                    _ => 1,
+                }
+            } else {
+                crate::data::map_lump_order(name).unwrap_or_else(|| {
+                    eprintln!("Warning: {} is not a map lump, adding it last", name);
+                    usize::MAX
+                })
+            }
+        };
+        map_lumps.sort_by_cached_key(|(name, _)| order(name));
+
+        writer.add_lump(
+            &map_name,
+            header.map(|h| self.read_lump_file(&h)).unwrap_or_default(),
+        );
+        for (name, path) in &map_lumps {
+            writer.add_lump(name, self.read_lump_file(path));
+        }
+        if is_udmf
+            && map_lumps
+                .last()
+                .map(|(name, _)| name != "ENDMAP")
+                .unwrap_or(true)
+        {
+            writer.add_marker("ENDMAP");
+        }
+    }
+
+    // Copies the WAD, with new nodes for the maps that have none, or for
+    // every map with --force.
+    fn wad_tool_nodes(&self, in_path: &str, out_path: &str, force: bool) {
+        let wad = self.open_tool_wad(in_path);
+        let mut writer = super::writer::WadWriter::new(super::file::WadKind::Pwad);
+        let read = |lump: usize| {
+            wad.lump_data(lump)
+                .map(|data| data.into_owned())
+                .unwrap_or_else(|e| self.error(e.to_string()))
+        };
+
+        let mut lump = 0;
+        while lump < wad.num_lumps() {
+            let name = wad.lumps[lump].name();
+            let is_binary_map = crate::data::is_map_header(&wad, lump)
+                && wad.lumps[lump + 1].name().eq_ignore_ascii_case("THINGS");
+            if !is_binary_map {
+                writer.add_lump(&name, read(lump));
+                lump += 1;
+                continue;
+            }
+
+            let range = crate::data::map_lump_range(&wad, lump);
+            let has_nodes = range.clone().any(|l| {
+                wad.lumps[l].name().eq_ignore_ascii_case("NODES") && wad.lumps[l].size > 0
+            });
+
+            writer.add_lump(&name, read(lump));
+            let map = if has_nodes && !force {
+                None
+            } else {
+                crate::map::Map::load_header(&wad, lump, false)
+                    .map_err(|e| eprintln!("Warning: {}, copying it unchanged", e))
+                    .ok()
+            };
+            let mut map = match map {
+                Some(map) => map,
+                None => {
+                    for l in range.clone() {
+                        writer.add_lump(&wad.lumps[l].name(), read(l));
+                    }
+                    lump = range.end;
+                    continue;
+                }
+            };
+            // The loader builds nodes for maps that have none.
+            if map.node_format != crate::map::extended::NodeFormat::Built {
+                map.build_nodes();
+            }
+            let encode_error = |why: String| self.error(format!("{}: {}", name, why));
+            let mut rebuilt = vec![
+                (
+                    "VERTEXES",
+                    crate::map::lumps::encode_vertexes(&map.vertexes),
+                ),
+                (
+                    "SEGS",
+                    crate::map::lumps::encode_segs(&map.segs, map.vertexes.len())
+                        .unwrap_or_else(encode_error),
+                ),
+                (
+                    "SSECTORS",
+                    crate::map::lumps::encode_subsectors(&map.subsectors)
+                        .unwrap_or_else(encode_error),
+                ),
+                (
+                    "NODES",
+                    crate::map::lumps::encode_nodes(&map.nodes).unwrap_or_else(encode_error),
+                ),
+            ];
+
+            for map_lump in crate::data::MAP_LUMP_NAMES.iter() {
+                if let Some(i) = rebuilt.iter().position(|(n, _)| n == map_lump) {
+                    let (n, data) = rebuilt.remove(i);
+                    writer.add_lump(n, data);
+                } else if let Some(l) = range
+                    .clone()
+                    .find(|&l| wad.lumps[l].name().eq_ignore_ascii_case(map_lump))
+                {
+                    writer.add_lump(map_lump, read(l));
+                }
+            }
+            for l in range.clone() {
+                let lump_name = wad.lumps[l].name();
+                if !crate::data::MAP_LUMP_NAMES
+                    .iter()
+                    .any(|n| n.eq_ignore_ascii_case(&lump_name))
+                {
+                    writer.add_lump(&lump_name, read(l));
+                }
+            }
+
+            println!(
+                "{}: {} nodes, {} subsectors, {} segs",
+                name,
+                map.nodes.len(),
+                map.subsectors.len(),
+                map.segs.len()
+            );
+            lump = range.end;
+        }
+
+        writer
+            .write(out_path)
+            .unwrap_or_else(|e| self.error(e.to_string()));
+        println!("Wrote {} lumps to {}", writer.num_lumps(), out_path);
+    }
+
+    fn pack_lumps(&self, writer: &mut super::writer::WadWriter, files: &[std::path::PathBuf]) {
+        for path in files {
+            writer.add_lump(&self.lump_name_for_file(path), self.read_lump_file(path));
+        }
+    }
+
+    fn read_lump_file(&self, path: &std::path::Path) -> Vec<u8> {
+        std::fs::read(path)
+            .unwrap_or_else(|e| self.error(format!("Failed to read {}: {}", path.display(), e)))
+    }
+
+    fn lump_name_for_file(&self, path: &std::path::Path) -> String {
+        let name = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_uppercase().replace('^', "\\"))
+            .unwrap_or_default();
+
+        if name.is_empty() || name.len() > super::LUMP_NAME_LENGTH {
+            self.error(format!(
+                "{} cannot be used as a lump name (1 to {} characters)",
+                path.display(),
+                super::LUMP_NAME_LENGTH
+            ));
+        }
+
+        name
+    }
+
+    fn sorted_dir_entries(
+        &self,
+        dir: &std::path::Path,
+    ) -> (Vec<std::path::PathBuf>, Vec<std::path::PathBuf>) {
+        let mut entries: Vec<_> = std::fs::read_dir(dir)
+            .and_then(|entries| entries.map(|e| e.map(|e| e.path())).collect())
+            .unwrap_or_else(|e: std::io::Error| {
+                self.error(format!("Failed to read {}: {}", dir.display(), e))
+            });
+        entries.sort();
+
+        entries
+            .into_iter()
+            .partition(|path: &std::path::PathBuf| path.is_dir())
+    }
+}
+
+fn lump_listing(wad: &super::Wad) -> String {
+    let mut listing = format!(
+        "{:>5}  {:<8}  {:>10}  {:>10}\n",
+        "index", "name", "size", "offset"
+    );
+    for (index, lump) in wad.lumps.iter().enumerate() {
+        listing += &format!(
+            "{:>5}  {:<8}  {:>10}  {:>10}\n",
+            index,
+            lump.name(),
+            lump.size,
+            lump.position
+        );
+    }
+    listing + &format!("{} lumps\n", wad.num_lumps())
+}
+
+fn is_marker(name: &str) -> bool {
+    name.ends_with("_START") || name.ends_with("_END")
+}
+
+fn lump_file_name(name: &str) -> String {
+    name.replace('\\', "^")
+}
+
+fn file_name(path: &std::path::Path) -> String {
+    path.file_name()
+        .map(|f| f.to_string_lossy().to_string())
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wad::file::WadKind;
+    use crate::wad::testing::{TempDir, TempFile};
+
+    fn test_wad() -> TempFile {
+        TempFile::wad(
+            WadKind::Pwad,
+            &[
+                ("DEHACKED", b"patch"),
+                ("DUP", b"first"),
+                ("MAP01", b""),
+                ("THINGS", b"things"),
+                ("LINEDEFS", b"lines"),
+                ("SIDEDEFS", b"sides"),
+                ("VERTEXES", b"vertexes"),
+                ("SECTORS", b"sectors"),
+                ("F_START", b""),
+                ("FLAT1", b"old flat"),
+                ("FLAT1", b"new flat"),
+                ("F_END", b""),
+                ("S_START", b""),
+                ("TROOA1", b"sprite"),
+                ("S_END", b""),
+                ("DUP", b"last"),
+                ("VILE\\1", b"backslash"),
+            ],
+        )
+    }
+
+    fn names(wad: &crate::wad::Wad) -> Vec<String> {
+        wad.lumps.iter().map(|lump| lump.name()).collect()
+    }
+
+    fn read(path: std::path::PathBuf) -> String {
+        String::from_utf8(std::fs::read(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn lists_every_lump() {
+        let file = test_wad();
+        let state = crate::state::State::default();
+        let listing = lump_listing(&state.open_tool_wad(&file.path));
+        let lines: Vec<_> = listing.lines().collect();
+
+        assert_eq!(lines.len(), 19);
+        assert_eq!(
+            lines[0].split_whitespace().collect::<Vec<_>>(),
+            ["index", "name", "size", "offset"]
+        );
+        assert_eq!(
+            lines[1].split_whitespace().collect::<Vec<_>>(),
+            ["0", "DEHACKED", "5", "12"]
+        );
+        assert_eq!(lines[18], "17 lumps");
+    }
+
+    #[test]
+    fn extracts_into_namespace_and_map_directories() {
+        let file = test_wad();
+        let out = TempDir::create();
+        let state = crate::state::State::default();
+        state.wad_tool_extract(&file.path, out.path.to_str().unwrap(), &[]);
+
+        assert_eq!(read(out.join("DEHACKED.lmp")), "patch");
+        assert_eq!(read(out.join("MAP01/THINGS.lmp")), "things");
+        assert_eq!(read(out.join("MAP01/MAP01.lmp")), "");
+        assert_eq!(read(out.join("sprites/TROOA1.lmp")), "sprite");
+        assert_eq!(read(out.join("VILE^1.lmp")), "backslash");
+        // Duplicates keep the last lump of the name, as the game would.
+        assert_eq!(read(out.join("DUP.lmp")), "last");
+        assert_eq!(read(out.join("flats/FLAT1.lmp")), "new flat");
+        assert!(!out.join("F_START.lmp").exists());
+    }
+
+    #[test]
+    fn extracts_named_lumps() {
+        let file = test_wad();
+        let out = TempDir::create();
+        let state = crate::state::State::default();
+        let names = ["TROOA1".to_owned(), "dup".to_owned()];
+        state.wad_tool_extract(&file.path, out.path.to_str().unwrap(), &names);
+
+        let mut files: Vec<_> = std::fs::read_dir(&out.path)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().into_string().unwrap())
+            .collect();
+        files.sort();
+        assert_eq!(files, ["DUP.lmp", "TROOA1.lmp"]);
+        assert_eq!(read(out.join("DUP.lmp")), "last");
+    }
+
+    #[test]
+    fn packs_what_it_extracts() {
+        let file = test_wad();
+        let out = TempDir::create();
+        let state = crate::state::State::default();
+        state.wad_tool_extract(&file.path, out.path.to_str().unwrap(), &[]);
+
+        let packed = TempFile::new("wad", &[]);
+        state.wad_tool_pack(out.path.to_str().unwrap(), &packed.path);
+        let wad = state.open_tool_wad(&packed.path);
+
+        // Loose lumps come first, then maps, then the namespaces.
+        assert_eq!(
+            names(&wad),
+            [
+                "DEHACKED", "DUP", "VILE\\1", "MAP01", "THINGS", "LINEDEFS", "SIDEDEFS",
+                "VERTEXES", "SECTORS", "F_START", "FLAT1", "F_END", "S_START", "TROOA1", "S_END",
+            ]
+        );
+        let lump = |name| {
+            wad.read_lump(wad.check_num_for_name(name).unwrap())
+                .unwrap()
+        };
+        assert_eq!(lump("DUP"), b"last");
+        assert_eq!(lump("FLAT1"), b"new flat");
+        assert_eq!(lump("VILE\\1"), b"backslash");
+    }
+
+    #[test]
+    fn packs_udmf_maps_with_an_endmap() {
+        let dir = TempDir::create();
+        std::fs::create_dir(dir.join("MAP01")).unwrap();
+        std::fs::write(dir.join("MAP01/ZNODES.lmp"), b"nodes").unwrap();
+        std::fs::write(dir.join("MAP01/TEXTMAP.lmp"), b"namespace = \"zdoom\";").unwrap();
+
+        let packed = TempFile::new("wad", &[]);
+        let state = crate::state::State::default();
+        state.wad_tool_pack(dir.path.to_str().unwrap(), &packed.path);
+        let wad = state.open_tool_wad(&packed.path);
+        assert_eq!(names(&wad), ["MAP01", "TEXTMAP", "ZNODES", "ENDMAP"]);
+    }
+}
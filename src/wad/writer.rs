@@ -0,0 +1,70 @@
+use std::io::Write;
+
+use super::error::WadError;
+
+pub struct WadWriter {
+    kind: super::file::WadKind,
+    lumps: Vec<([u8; super::LUMP_NAME_LENGTH], Vec<u8>)>,
+}
+
+impl WadWriter {
+    pub fn new(kind: super::file::WadKind) -> Self {
+        Self {
+            kind,
+            lumps: vec![],
+        }
+    }
+
+    pub fn add_lump(&mut self, name: &str, data: Vec<u8>) {
+        self.lumps
+            .push((super::normalize_name(name.as_bytes()), data));
+    }
+
+    pub fn add_marker(&mut self, name: &str) {
+        self.add_lump(name, vec![]);
+    }
+
+    pub fn num_lumps(&self) -> usize {
+        self.lumps.len()
+    }
+
+    pub fn write(&self, path: &str) -> Result<(), WadError> {
+        let io_error = |source| WadError::Io {
+            file_name: path.to_owned(),
+            source,
+        };
+
+        let identification: &[u8; 4] = match self.kind {
+            super::file::WadKind::Iwad => b"IWAD",
+            _ => b"PWAD",
+        };
+
+        let data_size: usize = self.lumps.iter().map(|(_, data)| data.len()).sum();
+        let info_table_offset = super::file::HEADER_SIZE as usize + data_size;
+
+        let mut out = Vec::with_capacity(
+            info_table_offset + self.lumps.len() * super::file::DIRECTORY_ENTRY_SIZE as usize,
+        );
+        out.extend_from_slice(identification);
+        out.extend_from_slice(&(self.lumps.len() as i32).to_le_bytes());
+        out.extend_from_slice(&(info_table_offset as i32).to_le_bytes());
+
+        let mut directory = Vec::with_capacity(self.lumps.len());
+        for (name, data) in &self.lumps {
+            // Empty lumps conventionally point at the start of the data.
+            let position = if data.is_empty() { 0 } else { out.len() };
+            directory.push((position, data.len(), name));
+            out.extend_from_slice(data);
+        }
+
+        for (position, size, name) in directory {
+            out.extend_from_slice(&(position as i32).to_le_bytes());
+            out.extend_from_slice(&(size as i32).to_le_bytes());
+            out.extend_from_slice(name);
+        }
+
+        std::fs::File::create(path)
+            .and_then(|mut f| f.write_all(&out))
+            .map_err(io_error)
+    }
+}